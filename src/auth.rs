@@ -0,0 +1,536 @@
+//! # API Key Authentication
+//!
+//! Authentication of incoming requests against a key store, as an axum middleware:
+//! - Keys are sent as `Authorization: Bearer <key>` or `X-Api-Key: <key>`
+//! - Every key has a name, the scopes it grants (`pokemon:read`, `translation:read`,
+//!   `metrics:read`) and an optional quota, overriding the rate limit budgets
+//! - Requests without a key only go through routes whose scope is granted to
+//!   anonymous callers (all of them by default)
+//!
+//! Unknown keys get `401 Unauthorized`, keys lacking the route scope `403 Forbidden`.
+//! Evolution chains require `translation:read` when their descriptions are translated
+//! (`?translate=true`), `pokemon:read` otherwise.
+//! The caller (key name, or `anonymous`) is recorded on a `caller` tracing span and in the
+//! `api_key_requests_total` metric; rejections are counted in `auth_rejections_total`.
+//!
+//! The key store is a JSON file:
+//!
+//! ```json
+//! {
+//!   "keys": [
+//!     { "name": "team-a", "key": "s3cr3t", "scopes": ["pokemon:read"], "quota": 30 }
+//!   ]
+//! }
+//! ```
+
+use crate::{
+    constants::{
+        BATCH_ROUTE, DESCRIPTIONS_ROUTE, EVOLUTION_ROUTE, LIST_ROUTE, METRICS_ROUTE, POKEMON_ROUTE,
+        TRANSLATION_ROUTE,
+    },
+    metrics,
+    problem::{Problem, ProblemType},
+};
+use axum::{
+    extract::{Request, State},
+    http::{
+        HeaderMap, HeaderValue,
+        header::{AUTHORIZATION, WWW_AUTHENTICATE},
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::{collections::HashMap, path::Path, sync::Arc};
+use tracing::{Instrument, debug, info_span};
+
+/// Header carrying the client API key.
+pub const API_KEY_HEADER: &str = "x-api-key";
+/// Name of callers without an API key.
+const ANONYMOUS: &str = "anonymous";
+
+/// Permission granted by an API key (the API is read-only, so are its scopes).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum Scope {
+    /// `pokemon:read`
+    Pokemon,
+    /// `translation:read`
+    Translation,
+    /// `metrics:read`
+    Metrics,
+}
+
+impl Scope {
+    const ALL: [Self; 3] = [Self::Pokemon, Self::Translation, Self::Metrics];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pokemon => "pokemon:read",
+            Self::Translation => "translation:read",
+            Self::Metrics => "metrics:read",
+        }
+    }
+
+    /// Scope required by a normalized route and its query string, if any (health,
+    /// readiness and docs are public).
+    fn required_by(route: &str, query: Option<&str>) -> Option<Self> {
+        match route {
+            EVOLUTION_ROUTE if query.is_some_and(translates) => Some(Self::Translation),
+            LIST_ROUTE | POKEMON_ROUTE | BATCH_ROUTE | EVOLUTION_ROUTE | DESCRIPTIONS_ROUTE => {
+                Some(Self::Pokemon)
+            }
+            TRANSLATION_ROUTE => Some(Self::Translation),
+            METRICS_ROUTE => Some(Self::Metrics),
+            _ => None,
+        }
+    }
+}
+
+/// The `translate` flag of the evolution query.
+#[derive(Deserialize)]
+struct TranslateQuery {
+    #[serde(default)]
+    translate: bool,
+}
+
+/// Whether the query string asks for translated descriptions, decoded as the evolution handler
+/// decodes it. A query the handler rejects translates nothing.
+pub(crate) fn translates(query: &str) -> bool {
+    serde_urlencoded::from_str::<TranslateQuery>(query).is_ok_and(|query| query.translate)
+}
+
+impl TryFrom<String> for Scope {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|scope| scope.as_str() == value)
+            .ok_or_else(|| {
+                let known: Vec<&str> = Self::ALL.iter().map(Scope::as_str).collect();
+                format!(
+                    "unknown scope '{}' (expected one of {})",
+                    value,
+                    known.join(", ")
+                )
+            })
+    }
+}
+
+/// API key entry of the key store file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiKey {
+    /// Name identifying the caller in logs and metrics
+    pub name: String,
+    /// Secret sent by the caller
+    pub key: String,
+    /// Scopes granted to the key
+    pub scopes: Vec<Scope>,
+    /// Requests per minute allowed on each rate limited route, replacing the route budget
+    #[serde(default)]
+    pub quota: Option<usize>,
+}
+
+#[derive(Deserialize)]
+struct KeyStoreFile {
+    keys: Vec<ApiKey>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum KeyStoreError {
+    #[error("failed to read key store: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("invalid key store: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    #[error("invalid key store: {0}")]
+    Invalid(String),
+}
+
+/// Authenticated caller, added to the request extensions by [`authenticate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Caller {
+    /// Key name, or `anonymous`
+    pub name: String,
+    /// Requests per minute allowed on each rate limited route, if overridden
+    pub quota: Option<usize>,
+    /// Whether the caller sent a valid API key
+    pub authenticated: bool,
+}
+
+impl Caller {
+    fn anonymous() -> Self {
+        Self {
+            name: ANONYMOUS.to_string(),
+            quota: None,
+            authenticated: false,
+        }
+    }
+}
+
+/// Outcome of the authentication of a request.
+#[derive(Debug, PartialEq)]
+enum Verdict {
+    Allowed(Caller),
+    /// No valid key, while the route is not open to anonymous callers
+    Unauthorized(&'static str),
+    /// Valid key lacking the route scope
+    Forbidden(Caller),
+}
+
+/// Validates API keys and enforces the route scopes.
+pub struct Authenticator {
+    /// Keys by SHA-256 digest of their secret
+    keys: HashMap<[u8; 32], ApiKey>,
+    anonymous_scopes: Vec<Scope>,
+}
+
+impl Authenticator {
+    /// Creates a new authenticator.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - Known API keys
+    /// * `anonymous_scopes` - Scopes granted to callers without an API key
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if two keys share the same name or secret, or if a key has a zero
+    /// quota.
+    pub fn new(keys: Vec<ApiKey>, anonymous_scopes: Vec<Scope>) -> Result<Self, KeyStoreError> {
+        let mut by_digest = HashMap::with_capacity(keys.len());
+        for key in keys {
+            if key.quota == Some(0) {
+                return Err(KeyStoreError::Invalid(format!(
+                    "key '{}' has a zero quota",
+                    key.name
+                )));
+            }
+            if by_digest
+                .values()
+                .any(|known: &ApiKey| known.name == key.name)
+            {
+                return Err(KeyStoreError::Invalid(format!(
+                    "duplicate key name '{}'",
+                    key.name
+                )));
+            }
+            let name = key.name.clone();
+            if by_digest.insert(digest(&key.key), key).is_some() {
+                return Err(KeyStoreError::Invalid(format!(
+                    "key '{}' reuses the secret of another key",
+                    name
+                )));
+            }
+        }
+        Ok(Self {
+            keys: by_digest,
+            anonymous_scopes,
+        })
+    }
+
+    /// Creates a new authenticator from a JSON key store file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid key store.
+    pub fn load(path: &Path, anonymous_scopes: Vec<Scope>) -> Result<Self, KeyStoreError> {
+        let file: KeyStoreFile = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        Self::new(file.keys, anonymous_scopes)
+    }
+
+    /// Number of known API keys.
+    pub fn key_count(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Authenticates a request to `route`.
+    fn verify(&self, route: &str, query: Option<&str>, headers: &HeaderMap) -> Verdict {
+        let required = Scope::required_by(route, query);
+        let Some(secret) = credentials(headers) else {
+            return match required.is_none_or(|scope| self.anonymous_scopes.contains(&scope)) {
+                true => Verdict::Allowed(Caller::anonymous()),
+                false => Verdict::Unauthorized("missing_credentials"),
+            };
+        };
+        let Some(key) = self.keys.get(&digest(secret)) else {
+            return Verdict::Unauthorized("invalid_credentials");
+        };
+        let caller = Caller {
+            name: key.name.clone(),
+            quota: key.quota,
+            authenticated: true,
+        };
+        match required.is_none_or(|scope| key.scopes.contains(&scope)) {
+            true => Verdict::Allowed(caller),
+            false => Verdict::Forbidden(caller),
+        }
+    }
+}
+
+fn digest(secret: &str) -> [u8; 32] {
+    Sha256::digest(secret.as_bytes()).into()
+}
+
+/// Extracts the API key, from `Authorization: Bearer` first, then `X-Api-Key`.
+fn credentials(headers: &HeaderMap) -> Option<&str> {
+    let bearer = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split_once(' '))
+        .filter(|(scheme, _)| scheme.eq_ignore_ascii_case("bearer"))
+        .map(|(_, token)| token.trim());
+    bearer
+        .or_else(|| {
+            headers
+                .get(API_KEY_HEADER)
+                .and_then(|value| value.to_str().ok())
+        })
+        .filter(|key| !key.is_empty())
+}
+
+/// Middleware authenticating requests and enforcing the route scopes.
+pub async fn authenticate(
+    State(authenticator): State<Arc<Authenticator>>,
+    mut req: Request,
+    next: Next,
+) -> Response {
+    let route = metrics::normalize_path(req.uri().path());
+    let query = req.uri().query();
+    match authenticator.verify(&route, query, req.headers()) {
+        Verdict::Allowed(caller) => {
+            if Scope::required_by(&route, query).is_some() {
+                metrics::API_KEY_REQUESTS
+                    .with_label_values(&[&caller.name, &route])
+                    .inc();
+            }
+            let span = info_span!("caller", api_key = %caller.name);
+            req.extensions_mut().insert(caller);
+            next.run(req).instrument(span).await
+        }
+        Verdict::Unauthorized(reason) => {
+            metrics::AUTH_REJECTIONS
+                .with_label_values(&[&route, reason])
+                .inc();
+            debug!(route, reason, "Request not authenticated");
+            (
+                [(WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"))],
+                Problem::new(ProblemType::Unauthorized),
+            )
+                .into_response()
+        }
+        Verdict::Forbidden(caller) => {
+            metrics::AUTH_REJECTIONS
+                .with_label_values(&[&route, "insufficient_scope"])
+                .inc();
+            debug!(
+                route,
+                api_key = caller.name,
+                "API key lacks the route scope"
+            );
+            let mut problem = Problem::new(ProblemType::Forbidden);
+            if let Some(scope) = Scope::required_by(&route, query) {
+                problem =
+                    problem.with_detail(format!("{} requires the {} scope", route, scope.as_str()));
+            }
+            problem.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem::PROBLEM_JSON;
+    use axum::{
+        Router,
+        body::Body,
+        http::{StatusCode, header::CONTENT_TYPE},
+        middleware,
+        routing::get,
+    };
+    use tower::ServiceExt;
+
+    fn key(name: &str, secret: &str, scopes: &[Scope]) -> ApiKey {
+        ApiKey {
+            name: name.to_string(),
+            key: secret.to_string(),
+            scopes: scopes.to_vec(),
+            quota: None,
+        }
+    }
+
+    fn authenticator(anonymous_scopes: &[Scope]) -> Authenticator {
+        Authenticator::new(
+            vec![
+                key("team-a", "a-secret", &[Scope::Pokemon]),
+                key("team-b", "b-secret", &[Scope::Pokemon, Scope::Translation]),
+            ],
+            anonymous_scopes.to_vec(),
+        )
+        .unwrap()
+    }
+
+    fn headers(name: &'static str, value: &'static str) -> HeaderMap {
+        HeaderMap::from_iter([(
+            axum::http::HeaderName::from_static(name),
+            HeaderValue::from_static(value),
+        )])
+    }
+
+    #[test]
+    fn parses_the_key_store_file() {
+        let file: KeyStoreFile = serde_json::from_str(
+            r#"{"keys": [{"name": "team-a", "key": "s3cr3t", "scopes": ["pokemon:read", "metrics:read"], "quota": 30}]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(file.keys[0].name, "team-a");
+        assert_eq!(file.keys[0].scopes, vec![Scope::Pokemon, Scope::Metrics]);
+        assert_eq!(file.keys[0].quota, Some(30));
+
+        let unknown_scope = serde_json::from_str::<KeyStoreFile>(
+            r#"{"keys": [{"name": "a", "key": "b", "scopes": ["admin"]}]}"#,
+        );
+        assert!(unknown_scope.is_err());
+    }
+
+    #[test]
+    fn rejects_duplicate_names_and_secrets() {
+        let same_name = Authenticator::new(vec![key("a", "1", &[]), key("a", "2", &[])], vec![]);
+        let same_secret = Authenticator::new(vec![key("a", "1", &[]), key("b", "1", &[])], vec![]);
+
+        assert!(same_name.is_err());
+        assert!(same_secret.is_err());
+    }
+
+    #[test]
+    fn rejects_zero_quotas() {
+        let mut zero_quota = key("a", "1", &[]);
+        zero_quota.quota = Some(0);
+
+        let result = Authenticator::new(vec![zero_quota], vec![]);
+
+        assert!(
+            matches!(result, Err(KeyStoreError::Invalid(message)) if message.contains("zero quota"))
+        );
+    }
+
+    #[test]
+    fn accepts_bearer_tokens_and_api_key_headers() {
+        let authenticator = authenticator(&[]);
+
+        let bearer = authenticator.verify(
+            POKEMON_ROUTE,
+            None,
+            &headers("authorization", "Bearer a-secret"),
+        );
+        let api_key = authenticator.verify(POKEMON_ROUTE, None, &headers("x-api-key", "b-secret"));
+
+        assert!(matches!(bearer, Verdict::Allowed(caller) if caller.name == "team-a"));
+        assert!(matches!(api_key, Verdict::Allowed(caller) if caller.name == "team-b"));
+    }
+
+    #[test]
+    fn enforces_the_route_scope() {
+        let authenticator = authenticator(&[]);
+        let team_a = headers("x-api-key", "a-secret");
+
+        assert!(matches!(
+            authenticator.verify(TRANSLATION_ROUTE, None, &team_a),
+            Verdict::Forbidden(_)
+        ));
+        assert_eq!(
+            authenticator.verify(TRANSLATION_ROUTE, None, &headers("x-api-key", "wrong")),
+            Verdict::Unauthorized("invalid_credentials")
+        );
+        // public routes require no scope
+        assert!(matches!(
+            authenticator.verify("/health", None, &team_a),
+            Verdict::Allowed(_)
+        ));
+    }
+
+    #[test]
+    fn requires_the_translation_scope_for_translated_evolutions() {
+        let authenticator = authenticator(&[]);
+        let team_a = headers("x-api-key", "a-secret");
+
+        assert!(matches!(
+            authenticator.verify(EVOLUTION_ROUTE, Some("translate=false"), &team_a),
+            Verdict::Allowed(_)
+        ));
+        assert!(matches!(
+            authenticator.verify(EVOLUTION_ROUTE, Some("translate=true"), &team_a),
+            Verdict::Forbidden(_)
+        ));
+    }
+
+    #[test]
+    fn decodes_the_translate_flag_before_checking_scopes() {
+        let authenticator = authenticator(&[]);
+        let team_a = headers("x-api-key", "a-secret");
+
+        for query in ["translate=%74rue", "raw=true&translate=tr%75e"] {
+            assert!(matches!(
+                authenticator.verify(EVOLUTION_ROUTE, Some(query), &team_a),
+                Verdict::Forbidden(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn lets_anonymous_callers_through_open_routes_only() {
+        let authenticator = authenticator(&[Scope::Pokemon]);
+
+        assert_eq!(
+            authenticator.verify(POKEMON_ROUTE, None, &HeaderMap::new()),
+            Verdict::Allowed(Caller::anonymous())
+        );
+        assert_eq!(
+            authenticator.verify(TRANSLATION_ROUTE, None, &HeaderMap::new()),
+            Verdict::Unauthorized("missing_credentials")
+        );
+        assert_eq!(
+            authenticator.verify("/ready", None, &HeaderMap::new()),
+            Verdict::Allowed(Caller::anonymous())
+        );
+    }
+
+    #[tokio::test]
+    async fn middleware_rejects_and_exposes_the_caller() {
+        let authenticator = Arc::new(authenticator(&[]));
+        let app = Router::new()
+            .route(
+                "/pokemon/{name}",
+                get(|req: Request| async move {
+                    req.extensions().get::<Caller>().unwrap().name.clone()
+                }),
+            )
+            .layer(middleware::from_fn_with_state(authenticator, authenticate));
+        let request = |key: Option<&'static str>| {
+            let builder = Request::builder().uri("/pokemon/pikachu");
+            match key {
+                Some(key) => builder.header(API_KEY_HEADER, key),
+                None => builder,
+            }
+            .body(Body::empty())
+            .unwrap()
+        };
+
+        let anonymous = app.clone().oneshot(request(None)).await.unwrap();
+        assert_eq!(anonymous.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(anonymous.headers()[WWW_AUTHENTICATE], "Bearer");
+        assert_eq!(anonymous.headers()[CONTENT_TYPE], PROBLEM_JSON);
+
+        let authenticated = app.oneshot(request(Some("a-secret"))).await.unwrap();
+        assert_eq!(authenticated.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(authenticated.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body, "team-a");
+    }
+}
@@ -0,0 +1,280 @@
+//! # Batch Lookup
+//!
+//! Resolves several Pokémon in one request (`POST /pokemon/batch`):
+//! - Names are looked up concurrently through [`PokemonApi::get_pokemon`], at most
+//!   `concurrency` at a time, with the same language preferences for every entry
+//! - Results are returned in request order, each with its own status: a missing Pokémon
+//!   or an upstream failure does not fail the whole batch
+
+use crate::{
+    http::client::{ErrorKind, HttpClientError},
+    metrics,
+    pokemon_api::{
+        client::{DescriptionOptions, Pokemon, PokemonApi},
+        language::LanguagePreferences,
+    },
+};
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use utoipa::ToSchema;
+
+/// Body of a batch lookup request.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct BatchRequest {
+    /// Pokemon names to look up
+    pub names: Vec<String>,
+}
+
+/// Outcome of the lookup of a single Pokémon.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchItemStatus {
+    /// The Pokémon was found
+    Found,
+    /// The Pokémon does not exist
+    NotFound,
+    /// The Pokémon has no description in the accepted languages
+    NotAcceptable,
+    /// PokéAPI failed, timed out or is unavailable
+    UpstreamError,
+}
+
+/// Result of the lookup of a single Pokémon.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BatchItem {
+    /// Requested Pokemon name
+    pub name: String,
+    /// Lookup status
+    pub status: BatchItemStatus,
+    /// Language of the returned description, when found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Pokemon data, when found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pokemon: Option<Pokemon>,
+}
+
+/// Body of a batch lookup response.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BatchResponse {
+    /// Results, in request order
+    pub results: Vec<BatchItem>,
+}
+
+/// Looks up `names` concurrently, with at most `concurrency` lookups in flight.
+///
+/// # Arguments
+///
+/// * `pokemon_api` - Pokemon API client
+/// * `names` - Pokemon names to look up
+/// * `preferences` - Language preferences of every lookup
+/// * `concurrency` - Maximum number of concurrent lookups
+pub async fn resolve(
+    pokemon_api: &dyn PokemonApi,
+    names: Vec<String>,
+    preferences: &LanguagePreferences,
+    concurrency: usize,
+) -> BatchResponse {
+    let results = stream::iter(names)
+        .map(|name| async move {
+            metrics::POKEMON_REQUESTS_TOTAL.inc();
+            let result = match name.trim().is_empty() {
+                true => Err(ErrorKind::NotFound.into()),
+                false => {
+                    pokemon_api
+                        .get_pokemon(&name, preferences, &DescriptionOptions::default())
+                        .await
+                }
+            };
+            item(name, result)
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+    BatchResponse { results }
+}
+
+fn item(name: String, result: Result<(String, Pokemon), HttpClientError>) -> BatchItem {
+    let (status, found) = match result {
+        Ok(found) => {
+            metrics::POKEMON_REQUESTS_FOUND.inc();
+            (BatchItemStatus::Found, Some(found))
+        }
+        Err(e) => match e.kind() {
+            ErrorKind::NotFound | ErrorKind::NoDescription => {
+                metrics::POKEMON_REQUESTS_NOT_FOUND.inc();
+                (BatchItemStatus::NotFound, None)
+            }
+            ErrorKind::NotAcceptable(_) => (BatchItemStatus::NotAcceptable, None),
+            _ => {
+                debug!(pokemon = name, error = %e, "Batch lookup failed");
+                (BatchItemStatus::UpstreamError, None)
+            }
+        },
+    };
+    let (language, pokemon) = found.unzip();
+    BatchItem {
+        name,
+        status,
+        language,
+        pokemon,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pokemon_api::{
+        client::PokemonName, descriptions::PokemonDescriptions, evolution::Evolution,
+        version::VersionFilter,
+    };
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    /// Fake API knowing pikachu (English only) and failing on "missingno".
+    #[derive(Default)]
+    struct FakePokemonApi {
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl PokemonApi for FakePokemonApi {
+        async fn get_pokemon(
+            &self,
+            name: &str,
+            preferences: &LanguagePreferences,
+            _options: &DescriptionOptions,
+        ) -> Result<(String, Pokemon), HttpClientError> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            match name {
+                "pikachu"
+                    if preferences.has_wildcard
+                        || preferences.ranges.contains(&"en".to_string()) =>
+                {
+                    Ok((
+                        "en".to_string(),
+                        Pokemon {
+                            id: 25,
+                            name: "pikachu".to_string(),
+                            habitat: Some("forest".to_string()),
+                            is_legendary: false,
+                            description: Some("Electric mouse".to_string()),
+                            ..Default::default()
+                        },
+                    ))
+                }
+                "pikachu" => Err(ErrorKind::NotAcceptable(vec!["ja".to_string()]).into()),
+                "missingno" => Err(ErrorKind::ServiceUnavailable.into()),
+                _ => Err(ErrorKind::NotFound.into()),
+            }
+        }
+
+        async fn get_evolution(
+            &self,
+            _name: &str,
+            _preferences: &LanguagePreferences,
+            _options: &DescriptionOptions,
+        ) -> Result<Evolution, HttpClientError> {
+            Err(ErrorKind::NotFound.into())
+        }
+
+        async fn get_descriptions(
+            &self,
+            _name: &str,
+            _preferences: &LanguagePreferences,
+            _version: Option<&VersionFilter>,
+        ) -> Result<PokemonDescriptions, HttpClientError> {
+            Err(ErrorKind::NotFound.into())
+        }
+
+        async fn get_pokemon_names(&self) -> Result<Vec<PokemonName>, HttpClientError> {
+            Ok(vec![PokemonName {
+                name: "pikachu".to_string(),
+                species: true,
+            }])
+        }
+
+        async fn probe(&self) -> Result<(), HttpClientError> {
+            Ok(())
+        }
+    }
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn returns_a_status_per_item_in_request_order() {
+        let api = FakePokemonApi::default();
+
+        let response = resolve(
+            &api,
+            names(&["pikachu", "agumon", "missingno", " "]),
+            &LanguagePreferences::new(&["en"], false),
+            4,
+        )
+        .await;
+
+        let statuses: Vec<_> = response.results.iter().map(|r| r.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                BatchItemStatus::Found,
+                BatchItemStatus::NotFound,
+                BatchItemStatus::UpstreamError,
+                BatchItemStatus::NotFound,
+            ]
+        );
+        assert_eq!(response.results[0].language.as_deref(), Some("en"));
+        assert_eq!(response.results[0].pokemon.as_ref().unwrap().id, 25);
+        assert_eq!(response.results[1].name, "agumon");
+        assert!(response.results[1].pokemon.is_none());
+    }
+
+    #[tokio::test]
+    async fn applies_the_language_preferences_to_every_item() {
+        let api = FakePokemonApi::default();
+
+        let response = resolve(
+            &api,
+            names(&["pikachu", "pikachu"]),
+            &LanguagePreferences::new(&["fr"], false),
+            2,
+        )
+        .await;
+
+        assert!(
+            response
+                .results
+                .iter()
+                .all(|r| r.status == BatchItemStatus::NotAcceptable)
+        );
+    }
+
+    #[tokio::test]
+    async fn bounds_the_number_of_concurrent_lookups() {
+        let api = FakePokemonApi::default();
+
+        resolve(&api, names(&["pikachu"; 6]), &LanguagePreferences::any(), 2).await;
+
+        assert_eq!(api.max_in_flight.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn serializes_items_without_empty_fields() {
+        let item = item("agumon".to_string(), Err(ErrorKind::NotFound.into()));
+
+        assert_eq!(
+            serde_json::to_value(item).unwrap(),
+            serde_json::json!({"name": "agumon", "status": "not_found"})
+        );
+    }
+}
@@ -1,9 +1,40 @@
+use ipnet::IpNet;
 use once_cell::sync::Lazy;
 use regex::Regex;
-use std::env;
+use std::{env, net::IpAddr, path::PathBuf, time::Duration};
 use tracing_subscriber::EnvFilter;
 
-use crate::constants::{DEFAULT_PORT, DEFAULT_RUST_LOG};
+use crate::{
+    auth::Scope,
+    constants::{
+        DEFAULT_AUTH_ANONYMOUS_SCOPES, DEFAULT_BATCH_CONCURRENCY, DEFAULT_BATCH_MAX_SIZE,
+        DEFAULT_CIRCUIT_BREAKER_COOL_DOWN_SECS, DEFAULT_CIRCUIT_BREAKER_FAILURE_THRESHOLD,
+        DEFAULT_CIRCUIT_BREAKER_WINDOW_SECS, DEFAULT_DESCRIPTION_CASING_FIXES,
+        DEFAULT_FLAVOR_TEXT_VERSION_POLICY, DEFAULT_LANGUAGE, DEFAULT_LANGUAGE_FALLBACK_CHAINS,
+        DEFAULT_POKEAPI_CACHE_MAX_ENTRIES, DEFAULT_POKEAPI_CACHE_TTL_SECS,
+        DEFAULT_POKEAPI_LANGUAGE_MAP, DEFAULT_POKEMON_INDEX_REFRESH_INTERVAL_SECS, DEFAULT_PORT,
+        DEFAULT_RATE_LIMIT_POKEMON_PER_MINUTE, DEFAULT_RATE_LIMIT_TRANSLATION_PER_MINUTE,
+        DEFAULT_RATE_LIMIT_TRUSTED_PROXIES, DEFAULT_READINESS_CACHE_TTL_SECS,
+        DEFAULT_READINESS_PROBE_TIMEOUT_MS, DEFAULT_READINESS_REQUIRED_DEPENDENCIES,
+        DEFAULT_RETRY_BASE_DELAY_MS, DEFAULT_RETRY_JITTER, DEFAULT_RETRY_MAX_ATTEMPTS,
+        DEFAULT_RETRY_MAX_DELAY_MS, DEFAULT_RETRY_ON, DEFAULT_RUST_LOG,
+        DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS, DEFAULT_TRANSLATION_CACHE_MAX_ENTRIES,
+        DEFAULT_TRANSLATION_CACHE_PATH, DEFAULT_TRANSLATION_LOCAL_FALLBACK,
+        DEFAULT_TRANSLATOR_STYLES, DEFAULT_UPSTREAM_CONNECT_TIMEOUT_MS,
+        DEFAULT_UPSTREAM_HTTP2_PRIOR_KNOWLEDGE, DEFAULT_UPSTREAM_POOL_IDLE_TIMEOUT_SECS,
+        DEFAULT_UPSTREAM_POOL_MAX_IDLE_PER_HOST, DEFAULT_UPSTREAM_REQUEST_TIMEOUT_MS,
+        FUN_TRANSLATIONS_UPSTREAM, POKEAPI_UPSTREAM,
+    },
+    http::{
+        circuit_breaker::CircuitBreakerConfig,
+        client::{ErrorKind, TranslatorType, UpstreamClientConfig},
+        retry::RetryPolicy,
+    },
+    pokemon_api::{
+        language::{LanguageFallbacks, LanguageMap, is_tag},
+        version::VersionPolicy,
+    },
+};
 
 // NOTE: unwrap() is acceptable here because the regex pattern is a compile-time constant
 // and we assume it's correct.
@@ -72,14 +103,392 @@ impl ConfigDescriptor {
         mandatory: None,
         default_value: Some(DEFAULT_RUST_LOG),
     };
+    const POKEAPI_CACHE_TTL: Self = Self {
+        cli_arg_name: "--pokeapi-cache-ttl",
+        env_var_name: "POKEAPI_CACHE_TTL",
+        description: "time to live of cached PokéAPI responses, in seconds",
+        name: "pokeapi cache ttl",
+        mandatory: None,
+        default_value: Some(DEFAULT_POKEAPI_CACHE_TTL_SECS),
+    };
+    const POKEAPI_CACHE_MAX_ENTRIES: Self = Self {
+        cli_arg_name: "--pokeapi-cache-max-entries",
+        env_var_name: "POKEAPI_CACHE_MAX_ENTRIES",
+        description: "maximum number of cached PokéAPI responses per resource",
+        name: "pokeapi cache max entries",
+        mandatory: None,
+        default_value: Some(DEFAULT_POKEAPI_CACHE_MAX_ENTRIES),
+    };
+    const TRANSLATION_CACHE_PATH: Self = Self {
+        cli_arg_name: "--translation-cache-path",
+        env_var_name: "TRANSLATION_CACHE_PATH",
+        description: "file path of the persistent translation cache (SQLite database)",
+        name: "translation cache path",
+        mandatory: None,
+        default_value: Some(DEFAULT_TRANSLATION_CACHE_PATH),
+    };
+    const TRANSLATION_CACHE_MAX_ENTRIES: Self = Self {
+        cli_arg_name: "--translation-cache-max-entries",
+        env_var_name: "TRANSLATION_CACHE_MAX_ENTRIES",
+        description: "maximum number of stored translations (least recently used are evicted)",
+        name: "translation cache max entries",
+        mandatory: None,
+        default_value: Some(DEFAULT_TRANSLATION_CACHE_MAX_ENTRIES),
+    };
+    const RETRY_MAX_ATTEMPTS: Self = Self {
+        cli_arg_name: "--retry-max-attempts",
+        env_var_name: "RETRY_MAX_ATTEMPTS",
+        description: "maximum number of attempts of upstream requests, including the first one",
+        name: "retry max attempts",
+        mandatory: None,
+        default_value: Some(DEFAULT_RETRY_MAX_ATTEMPTS),
+    };
+    const RETRY_BASE_DELAY: Self = Self {
+        cli_arg_name: "--retry-base-delay",
+        env_var_name: "RETRY_BASE_DELAY",
+        description: "delay before the first retry of upstream requests, in milliseconds (doubled at each retry)",
+        name: "retry base delay",
+        mandatory: None,
+        default_value: Some(DEFAULT_RETRY_BASE_DELAY_MS),
+    };
+    const RETRY_MAX_DELAY: Self = Self {
+        cli_arg_name: "--retry-max-delay",
+        env_var_name: "RETRY_MAX_DELAY",
+        description: "maximum delay between attempts of upstream requests, in milliseconds",
+        name: "retry max delay",
+        mandatory: None,
+        default_value: Some(DEFAULT_RETRY_MAX_DELAY_MS),
+    };
+    const RETRY_JITTER: Self = Self {
+        cli_arg_name: "--retry-jitter",
+        env_var_name: "RETRY_JITTER",
+        description: "randomize delays between attempts of upstream requests (true/false)",
+        name: "retry jitter",
+        mandatory: None,
+        default_value: Some(DEFAULT_RETRY_JITTER),
+    };
+    const RETRY_ON: Self = Self {
+        cli_arg_name: "--retry-on",
+        env_var_name: "RETRY_ON",
+        description: "comma separated upstream errors worth a retry (request-failed, rate-limited, service-unavailable, server-error, timeout)",
+        name: "retry on",
+        mandatory: None,
+        default_value: Some(DEFAULT_RETRY_ON),
+    };
+    const CIRCUIT_BREAKER_FAILURE_THRESHOLD: Self = Self {
+        cli_arg_name: "--circuit-breaker-failure-threshold",
+        env_var_name: "CIRCUIT_BREAKER_FAILURE_THRESHOLD",
+        description: "upstream failures within the rolling window opening the circuit",
+        name: "circuit breaker failure threshold",
+        mandatory: None,
+        default_value: Some(DEFAULT_CIRCUIT_BREAKER_FAILURE_THRESHOLD),
+    };
+    const CIRCUIT_BREAKER_WINDOW: Self = Self {
+        cli_arg_name: "--circuit-breaker-window",
+        env_var_name: "CIRCUIT_BREAKER_WINDOW",
+        description: "rolling window upstream failures are counted over, in seconds",
+        name: "circuit breaker window",
+        mandatory: None,
+        default_value: Some(DEFAULT_CIRCUIT_BREAKER_WINDOW_SECS),
+    };
+    const CIRCUIT_BREAKER_COOL_DOWN: Self = Self {
+        cli_arg_name: "--circuit-breaker-cool-down",
+        env_var_name: "CIRCUIT_BREAKER_COOL_DOWN",
+        description: "time an open circuit waits before letting a trial request through, in seconds",
+        name: "circuit breaker cool down",
+        mandatory: None,
+        default_value: Some(DEFAULT_CIRCUIT_BREAKER_COOL_DOWN_SECS),
+    };
+    const POKEAPI_CONNECT_TIMEOUT: Self = Self {
+        cli_arg_name: "--pokeapi-connect-timeout",
+        env_var_name: "POKEAPI_CONNECT_TIMEOUT",
+        description: "maximum time to connect to PokéAPI, in milliseconds",
+        name: "pokeapi connect timeout",
+        mandatory: None,
+        default_value: Some(DEFAULT_UPSTREAM_CONNECT_TIMEOUT_MS),
+    };
+    const POKEAPI_REQUEST_TIMEOUT: Self = Self {
+        cli_arg_name: "--pokeapi-request-timeout",
+        env_var_name: "POKEAPI_REQUEST_TIMEOUT",
+        description: "maximum time of a whole PokéAPI request, in milliseconds",
+        name: "pokeapi request timeout",
+        mandatory: None,
+        default_value: Some(DEFAULT_UPSTREAM_REQUEST_TIMEOUT_MS),
+    };
+    const POKEAPI_POOL_IDLE_TIMEOUT: Self = Self {
+        cli_arg_name: "--pokeapi-pool-idle-timeout",
+        env_var_name: "POKEAPI_POOL_IDLE_TIMEOUT",
+        description: "time idle PokéAPI connections are kept open, in seconds",
+        name: "pokeapi pool idle timeout",
+        mandatory: None,
+        default_value: Some(DEFAULT_UPSTREAM_POOL_IDLE_TIMEOUT_SECS),
+    };
+    const POKEAPI_POOL_MAX_IDLE_PER_HOST: Self = Self {
+        cli_arg_name: "--pokeapi-pool-max-idle-per-host",
+        env_var_name: "POKEAPI_POOL_MAX_IDLE_PER_HOST",
+        description: "maximum number of idle PokéAPI connections per host",
+        name: "pokeapi pool max idle per host",
+        mandatory: None,
+        default_value: Some(DEFAULT_UPSTREAM_POOL_MAX_IDLE_PER_HOST),
+    };
+    const POKEAPI_HTTP2_PRIOR_KNOWLEDGE: Self = Self {
+        cli_arg_name: "--pokeapi-http2-prior-knowledge",
+        env_var_name: "POKEAPI_HTTP2_PRIOR_KNOWLEDGE",
+        description: "speak HTTP/2 to PokéAPI without negotiating it first (true/false)",
+        name: "pokeapi http2 prior knowledge",
+        mandatory: None,
+        default_value: Some(DEFAULT_UPSTREAM_HTTP2_PRIOR_KNOWLEDGE),
+    };
+    const FUN_TRANSLATIONS_CONNECT_TIMEOUT: Self = Self {
+        cli_arg_name: "--fun-translations-connect-timeout",
+        env_var_name: "FUN_TRANSLATIONS_CONNECT_TIMEOUT",
+        description: "maximum time to connect to Fun Translations, in milliseconds",
+        name: "fun translations connect timeout",
+        mandatory: None,
+        default_value: Some(DEFAULT_UPSTREAM_CONNECT_TIMEOUT_MS),
+    };
+    const FUN_TRANSLATIONS_REQUEST_TIMEOUT: Self = Self {
+        cli_arg_name: "--fun-translations-request-timeout",
+        env_var_name: "FUN_TRANSLATIONS_REQUEST_TIMEOUT",
+        description: "maximum time of a whole Fun Translations request, in milliseconds",
+        name: "fun translations request timeout",
+        mandatory: None,
+        default_value: Some(DEFAULT_UPSTREAM_REQUEST_TIMEOUT_MS),
+    };
+    const FUN_TRANSLATIONS_POOL_IDLE_TIMEOUT: Self = Self {
+        cli_arg_name: "--fun-translations-pool-idle-timeout",
+        env_var_name: "FUN_TRANSLATIONS_POOL_IDLE_TIMEOUT",
+        description: "time idle Fun Translations connections are kept open, in seconds",
+        name: "fun translations pool idle timeout",
+        mandatory: None,
+        default_value: Some(DEFAULT_UPSTREAM_POOL_IDLE_TIMEOUT_SECS),
+    };
+    const FUN_TRANSLATIONS_POOL_MAX_IDLE_PER_HOST: Self = Self {
+        cli_arg_name: "--fun-translations-pool-max-idle-per-host",
+        env_var_name: "FUN_TRANSLATIONS_POOL_MAX_IDLE_PER_HOST",
+        description: "maximum number of idle Fun Translations connections per host",
+        name: "fun translations pool max idle per host",
+        mandatory: None,
+        default_value: Some(DEFAULT_UPSTREAM_POOL_MAX_IDLE_PER_HOST),
+    };
+    const FUN_TRANSLATIONS_HTTP2_PRIOR_KNOWLEDGE: Self = Self {
+        cli_arg_name: "--fun-translations-http2-prior-knowledge",
+        env_var_name: "FUN_TRANSLATIONS_HTTP2_PRIOR_KNOWLEDGE",
+        description: "speak HTTP/2 to Fun Translations without negotiating it first (true/false)",
+        name: "fun translations http2 prior knowledge",
+        mandatory: None,
+        default_value: Some(DEFAULT_UPSTREAM_HTTP2_PRIOR_KNOWLEDGE),
+    };
+    const READINESS_REQUIRED_DEPENDENCIES: Self = Self {
+        cli_arg_name: "--readiness-required-dependencies",
+        env_var_name: "READINESS_REQUIRED_DEPENDENCIES",
+        description: "comma separated upstreams (pokeapi, funtranslations) that must be up for the service to be ready",
+        name: "readiness required dependencies",
+        mandatory: None,
+        default_value: Some(DEFAULT_READINESS_REQUIRED_DEPENDENCIES),
+    };
+    const READINESS_CACHE_TTL: Self = Self {
+        cli_arg_name: "--readiness-cache-ttl",
+        env_var_name: "READINESS_CACHE_TTL",
+        description: "time readiness probe results are reused, in seconds",
+        name: "readiness cache ttl",
+        mandatory: None,
+        default_value: Some(DEFAULT_READINESS_CACHE_TTL_SECS),
+    };
+    const READINESS_PROBE_TIMEOUT: Self = Self {
+        cli_arg_name: "--readiness-probe-timeout",
+        env_var_name: "READINESS_PROBE_TIMEOUT",
+        description: "maximum duration of a single readiness probe, in milliseconds",
+        name: "readiness probe timeout",
+        mandatory: None,
+        default_value: Some(DEFAULT_READINESS_PROBE_TIMEOUT_MS),
+    };
+    const SHUTDOWN_GRACE_PERIOD: Self = Self {
+        cli_arg_name: "--shutdown-grace-period",
+        env_var_name: "SHUTDOWN_GRACE_PERIOD",
+        description: "time given to in-flight requests to complete on shutdown, in seconds",
+        name: "shutdown grace period",
+        mandatory: None,
+        default_value: Some(DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS),
+    };
+    const RATE_LIMIT_POKEMON: Self = Self {
+        cli_arg_name: "--rate-limit-pokemon",
+        env_var_name: "RATE_LIMIT_POKEMON",
+        description: "requests per minute a client may send to /pokemon/{name} (a /pokemon/batch request counts one per name)",
+        name: "rate limit pokemon",
+        mandatory: None,
+        default_value: Some(DEFAULT_RATE_LIMIT_POKEMON_PER_MINUTE),
+    };
+    const RATE_LIMIT_TRANSLATION: Self = Self {
+        cli_arg_name: "--rate-limit-translation",
+        env_var_name: "RATE_LIMIT_TRANSLATION",
+        description: "requests per minute a client may send to /pokemon/{name}/translation/ and /pokemon/{name}/evolution?translate=true",
+        name: "rate limit translation",
+        mandatory: None,
+        default_value: Some(DEFAULT_RATE_LIMIT_TRANSLATION_PER_MINUTE),
+    };
+    const RATE_LIMIT_TRUSTED_PROXIES: Self = Self {
+        cli_arg_name: "--rate-limit-trusted-proxies",
+        env_var_name: "RATE_LIMIT_TRUSTED_PROXIES",
+        description: "comma separated addresses or networks (CIDR) of proxies whose X-Forwarded-For is trusted",
+        name: "rate limit trusted proxies",
+        mandatory: None,
+        default_value: Some(DEFAULT_RATE_LIMIT_TRUSTED_PROXIES),
+    };
+    const AUTH_KEYS_FILE: Self = Self {
+        cli_arg_name: "--auth-keys-file",
+        env_var_name: "AUTH_KEYS_FILE",
+        description: "file path of the API key store (JSON); no keys are accepted when unset",
+        name: "auth keys file",
+        mandatory: None,
+        default_value: None,
+    };
+    const AUTH_ANONYMOUS_SCOPES: Self = Self {
+        cli_arg_name: "--auth-anonymous-scopes",
+        env_var_name: "AUTH_ANONYMOUS_SCOPES",
+        description: "comma separated scopes (pokemon:read, translation:read, metrics:read) granted to requests without API key, or \"none\"",
+        name: "auth anonymous scopes",
+        mandatory: None,
+        default_value: Some(DEFAULT_AUTH_ANONYMOUS_SCOPES),
+    };
+    const BATCH_MAX_SIZE: Self = Self {
+        cli_arg_name: "--batch-max-size",
+        env_var_name: "BATCH_MAX_SIZE",
+        description: "maximum number of names of a batch lookup",
+        name: "batch max size",
+        mandatory: None,
+        default_value: Some(DEFAULT_BATCH_MAX_SIZE),
+    };
+    const BATCH_CONCURRENCY: Self = Self {
+        cli_arg_name: "--batch-concurrency",
+        env_var_name: "BATCH_CONCURRENCY",
+        description: "maximum number of concurrent PokéAPI lookups of a batch lookup",
+        name: "batch concurrency",
+        mandatory: None,
+        default_value: Some(DEFAULT_BATCH_CONCURRENCY),
+    };
+    const POKEMON_INDEX_REFRESH_INTERVAL: Self = Self {
+        cli_arg_name: "--pokemon-index-refresh-interval",
+        env_var_name: "POKEMON_INDEX_REFRESH_INTERVAL",
+        description: "interval between refreshes of the Pokémon name index used by listings and searches, in seconds",
+        name: "pokemon index refresh interval",
+        mandatory: None,
+        default_value: Some(DEFAULT_POKEMON_INDEX_REFRESH_INTERVAL_SECS),
+    };
+    const TRANSLATOR_RULES_FILE: Self = Self {
+        cli_arg_name: "--translator-rules-file",
+        env_var_name: "TRANSLATOR_RULES_FILE",
+        description: "file path of the translator selection rules (JSON); legendary and cave Pokémon get Yoda, others Shakespeare when unset",
+        name: "translator rules file",
+        mandatory: None,
+        default_value: None,
+    };
+    const TRANSLATOR_STYLES: Self = Self {
+        cli_arg_name: "--translator-styles",
+        env_var_name: "TRANSLATOR_STYLES",
+        description: "comma separated Fun Translations styles served by the API (e.g., yoda, pirate, klingon)",
+        name: "translator styles",
+        mandatory: None,
+        default_value: Some(DEFAULT_TRANSLATOR_STYLES),
+    };
+    const TRANSLATION_LOCAL_FALLBACK: Self = Self {
+        cli_arg_name: "--translation-local-fallback",
+        env_var_name: "TRANSLATION_LOCAL_FALLBACK",
+        description: "translate Yoda and Shakespeare locally when the Fun Translations API fails (true/false)",
+        name: "translation local fallback",
+        mandatory: None,
+        default_value: Some(DEFAULT_TRANSLATION_LOCAL_FALLBACK),
+    };
+    const DESCRIPTION_CASING_FIXES: Self = Self {
+        cli_arg_name: "--description-casing-fixes",
+        env_var_name: "DESCRIPTION_CASING_FIXES",
+        description: "comma separated spelling=replacement casing fixes applied to Pokémon descriptions, in order, or none",
+        name: "description casing fixes",
+        mandatory: None,
+        default_value: Some(DEFAULT_DESCRIPTION_CASING_FIXES),
+    };
+    const FLAVOR_TEXT_VERSION_POLICY: Self = Self {
+        cli_arg_name: "--flavor-text-version-policy",
+        env_var_name: "FLAVOR_TEXT_VERSION_POLICY",
+        description: "game version of the Pokémon descriptions: latest, earliest, or comma separated preferred versions (e.g., sword,x,red)",
+        name: "flavor text version policy",
+        mandatory: None,
+        default_value: Some(DEFAULT_FLAVOR_TEXT_VERSION_POLICY),
+    };
+    const POKEAPI_LANGUAGE_MAP: Self = Self {
+        cli_arg_name: "--pokeapi-language-map",
+        env_var_name: "POKEAPI_LANGUAGE_MAP",
+        description: "comma separated tag=language mappings of Accept-Language tags to PokéAPI languages (e.g., zh-TW=zh-Hant), or none",
+        name: "pokeapi language map",
+        mandatory: None,
+        default_value: Some(DEFAULT_POKEAPI_LANGUAGE_MAP),
+    };
+    const LANGUAGE_FALLBACK_CHAINS: Self = Self {
+        cli_arg_name: "--language-fallback-chains",
+        env_var_name: "LANGUAGE_FALLBACK_CHAINS",
+        description: "comma separated language=fallback>fallback chains tried when no requested language has a description (e.g., ca=es>fr), or none",
+        name: "language fallback chains",
+        mandatory: None,
+        default_value: Some(DEFAULT_LANGUAGE_FALLBACK_CHAINS),
+    };
+    const DEFAULT_LANGUAGE: Self = Self {
+        cli_arg_name: "--default-language",
+        env_var_name: "DEFAULT_LANGUAGE",
+        description: "language of the descriptions after the fallback chains, when no requested language has a description",
+        name: "default language",
+        mandatory: None,
+        default_value: Some(DEFAULT_LANGUAGE),
+    };
 
-    const ALL: [Self; 6] = [
+    const ALL: [Self; 48] = [
         Self::POKEAPI_HOST,
         Self::FUN_TRANSLATIONS_HOST,
         Self::PORT,
         Self::POKEAPI_SECURE,
         Self::FUN_TRANSLATIONS_SECURE,
         Self::RUST_LOG,
+        Self::POKEAPI_CACHE_TTL,
+        Self::POKEAPI_CACHE_MAX_ENTRIES,
+        Self::TRANSLATION_CACHE_PATH,
+        Self::TRANSLATION_CACHE_MAX_ENTRIES,
+        Self::RETRY_MAX_ATTEMPTS,
+        Self::RETRY_BASE_DELAY,
+        Self::RETRY_MAX_DELAY,
+        Self::RETRY_JITTER,
+        Self::RETRY_ON,
+        Self::CIRCUIT_BREAKER_FAILURE_THRESHOLD,
+        Self::CIRCUIT_BREAKER_WINDOW,
+        Self::CIRCUIT_BREAKER_COOL_DOWN,
+        Self::POKEAPI_CONNECT_TIMEOUT,
+        Self::POKEAPI_REQUEST_TIMEOUT,
+        Self::POKEAPI_POOL_IDLE_TIMEOUT,
+        Self::POKEAPI_POOL_MAX_IDLE_PER_HOST,
+        Self::POKEAPI_HTTP2_PRIOR_KNOWLEDGE,
+        Self::FUN_TRANSLATIONS_CONNECT_TIMEOUT,
+        Self::FUN_TRANSLATIONS_REQUEST_TIMEOUT,
+        Self::FUN_TRANSLATIONS_POOL_IDLE_TIMEOUT,
+        Self::FUN_TRANSLATIONS_POOL_MAX_IDLE_PER_HOST,
+        Self::FUN_TRANSLATIONS_HTTP2_PRIOR_KNOWLEDGE,
+        Self::READINESS_REQUIRED_DEPENDENCIES,
+        Self::READINESS_CACHE_TTL,
+        Self::READINESS_PROBE_TIMEOUT,
+        Self::SHUTDOWN_GRACE_PERIOD,
+        Self::RATE_LIMIT_POKEMON,
+        Self::RATE_LIMIT_TRANSLATION,
+        Self::RATE_LIMIT_TRUSTED_PROXIES,
+        Self::AUTH_KEYS_FILE,
+        Self::AUTH_ANONYMOUS_SCOPES,
+        Self::BATCH_MAX_SIZE,
+        Self::BATCH_CONCURRENCY,
+        Self::POKEMON_INDEX_REFRESH_INTERVAL,
+        Self::TRANSLATOR_RULES_FILE,
+        Self::TRANSLATOR_STYLES,
+        Self::TRANSLATION_LOCAL_FALLBACK,
+        Self::DESCRIPTION_CASING_FIXES,
+        Self::FLAVOR_TEXT_VERSION_POLICY,
+        Self::POKEAPI_LANGUAGE_MAP,
+        Self::LANGUAGE_FALLBACK_CHAINS,
+        Self::DEFAULT_LANGUAGE,
     ];
 
     pub fn print_usage() {
@@ -144,6 +553,48 @@ pub struct AppConfig {
     pub fun_translations_secure: bool,
     pub port: u16,
     pub rust_log: String,
+    pub pokeapi_cache_ttl: Duration,
+    pub pokeapi_cache_max_entries: usize,
+    pub translation_cache_path: PathBuf,
+    pub translation_cache_max_entries: usize,
+    pub retry_max_attempts: usize,
+    pub retry_base_delay: Duration,
+    pub retry_max_delay: Duration,
+    pub retry_jitter: bool,
+    pub retry_on: Vec<ErrorKind>,
+    pub circuit_breaker_failure_threshold: usize,
+    pub circuit_breaker_window: Duration,
+    pub circuit_breaker_cool_down: Duration,
+    pub pokeapi_connect_timeout: Duration,
+    pub pokeapi_request_timeout: Duration,
+    pub pokeapi_pool_idle_timeout: Duration,
+    pub pokeapi_pool_max_idle_per_host: usize,
+    pub pokeapi_http2_prior_knowledge: bool,
+    pub fun_translations_connect_timeout: Duration,
+    pub fun_translations_request_timeout: Duration,
+    pub fun_translations_pool_idle_timeout: Duration,
+    pub fun_translations_pool_max_idle_per_host: usize,
+    pub fun_translations_http2_prior_knowledge: bool,
+    pub readiness_required_dependencies: Vec<String>,
+    pub readiness_cache_ttl: Duration,
+    pub readiness_probe_timeout: Duration,
+    pub shutdown_grace_period: Duration,
+    pub rate_limit_pokemon: usize,
+    pub rate_limit_translation: usize,
+    pub rate_limit_trusted_proxies: Vec<IpNet>,
+    pub auth_keys_file: Option<PathBuf>,
+    pub auth_anonymous_scopes: Vec<Scope>,
+    pub batch_max_size: usize,
+    pub batch_concurrency: usize,
+    pub pokemon_index_refresh_interval: Duration,
+    pub translator_rules_file: Option<PathBuf>,
+    pub translator_styles: Vec<TranslatorType>,
+    pub translation_local_fallback: bool,
+    pub description_casing_fixes: Vec<(String, String)>,
+    pub flavor_text_version_policy: VersionPolicy,
+    pub pokeapi_language_map: LanguageMap,
+    pub language_fallback_chains: Vec<(String, Vec<String>)>,
+    pub default_language: String,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -173,6 +624,50 @@ impl AppConfig {
         format!("{}://{}/translate", scheme, self.fun_translations_host)
     }
 
+    pub fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: self.retry_max_attempts,
+            base_delay: self.retry_base_delay,
+            max_delay: self.retry_max_delay,
+            jitter: self.retry_jitter,
+            retryable: self.retry_on.clone(),
+        }
+    }
+
+    pub fn pokeapi_client_config(&self) -> UpstreamClientConfig {
+        UpstreamClientConfig {
+            connect_timeout: self.pokeapi_connect_timeout,
+            request_timeout: self.pokeapi_request_timeout,
+            pool_idle_timeout: self.pokeapi_pool_idle_timeout,
+            pool_max_idle_per_host: self.pokeapi_pool_max_idle_per_host,
+            http2_prior_knowledge: self.pokeapi_http2_prior_knowledge,
+        }
+    }
+
+    pub fn fun_translations_client_config(&self) -> UpstreamClientConfig {
+        UpstreamClientConfig {
+            connect_timeout: self.fun_translations_connect_timeout,
+            request_timeout: self.fun_translations_request_timeout,
+            pool_idle_timeout: self.fun_translations_pool_idle_timeout,
+            pool_max_idle_per_host: self.fun_translations_pool_max_idle_per_host,
+            http2_prior_knowledge: self.fun_translations_http2_prior_knowledge,
+        }
+    }
+
+    pub fn is_required_dependency(&self, name: &str) -> bool {
+        self.readiness_required_dependencies
+            .iter()
+            .any(|dependency| dependency == name)
+    }
+
+    pub fn circuit_breaker_config(&self) -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_threshold: self.circuit_breaker_failure_threshold,
+            window: self.circuit_breaker_window,
+            cool_down: self.circuit_breaker_cool_down,
+        }
+    }
+
     pub fn load() -> Result<Self, ConfigError> {
         let cli_parser = CliParser::new();
         let env_parser = EnvParser;
@@ -181,6 +676,11 @@ impl AppConfig {
                 .parse(descriptor)
                 .or_else(|| env_parser.parse(descriptor))
         };
+        // falls back to the descriptor default value when the setting is not provided
+        let parse_or_default = |descriptor: &ConfigDescriptor| {
+            parse(descriptor)
+                .unwrap_or_else(|| descriptor.default_value.unwrap_or_default().to_string())
+        };
         let pokeapi_host = {
             let desc = &ConfigDescriptor::POKEAPI_HOST;
             parse(desc)
@@ -221,43 +721,263 @@ impl AppConfig {
                 Some(s) => parse_rust_log_config(&s),
             }
         };
-        match (
-            &pokeapi_host,
-            &fun_translations_host,
-            &pokeapi_secure,
-            &fun_translations_secure,
-            &port,
-            &rust_log,
-        ) {
-            (
-                Ok(pokeapi_host),
-                Ok(fun_translations_host),
-                Ok(pokeapi_secure),
-                Ok(fun_translations_secure),
-                Ok(port),
-                Ok(rust_log),
-            ) => Ok(AppConfig {
-                pokeapi_host: pokeapi_host.clone(),
-                fun_translations_host: fun_translations_host.clone(),
-                pokeapi_secure: *pokeapi_secure,
-                fun_translations_secure: *fun_translations_secure,
-                port: *port,
-                rust_log: rust_log.clone(),
-            }),
-            _ => {
-                let errors = [
-                    pokeapi_host.err(),
-                    fun_translations_host.err(),
-                    pokeapi_secure.err(),
-                    fun_translations_secure.err(),
-                    port.err(),
-                    rust_log.err(),
-                ]
-                .into_iter()
-                .flatten()
-                .collect::<Vec<_>>();
-                Err(ConfigError::Multiple(errors))
+        let pokeapi_cache_ttl = {
+            let desc = &ConfigDescriptor::POKEAPI_CACHE_TTL;
+            parse_secs_config(&parse_or_default(desc), desc.name)
+        };
+        let pokeapi_cache_max_entries = {
+            let desc = &ConfigDescriptor::POKEAPI_CACHE_MAX_ENTRIES;
+            parse_count_config(&parse_or_default(desc), desc.name)
+        };
+        let translation_cache_path = {
+            let desc = &ConfigDescriptor::TRANSLATION_CACHE_PATH;
+            parse_path_config(&parse_or_default(desc), desc.name)
+        };
+        let translation_cache_max_entries = {
+            let desc = &ConfigDescriptor::TRANSLATION_CACHE_MAX_ENTRIES;
+            parse_count_config(&parse_or_default(desc), desc.name)
+        };
+        let retry_max_attempts = {
+            let desc = &ConfigDescriptor::RETRY_MAX_ATTEMPTS;
+            parse_count_config(&parse_or_default(desc), desc.name)
+        };
+        let retry_base_delay = {
+            let desc = &ConfigDescriptor::RETRY_BASE_DELAY;
+            parse_millis_config(&parse_or_default(desc), desc.name)
+        };
+        let retry_max_delay = {
+            let desc = &ConfigDescriptor::RETRY_MAX_DELAY;
+            parse_millis_config(&parse_or_default(desc), desc.name)
+        };
+        let retry_jitter = {
+            let desc = &ConfigDescriptor::RETRY_JITTER;
+            parse_bool_config(&parse_or_default(desc), desc.name)
+        };
+        let retry_on = {
+            let desc = &ConfigDescriptor::RETRY_ON;
+            parse_retryable_errors_config(&parse_or_default(desc), desc.name)
+        };
+        let circuit_breaker_failure_threshold = {
+            let desc = &ConfigDescriptor::CIRCUIT_BREAKER_FAILURE_THRESHOLD;
+            parse_count_config(&parse_or_default(desc), desc.name)
+        };
+        let circuit_breaker_window = {
+            let desc = &ConfigDescriptor::CIRCUIT_BREAKER_WINDOW;
+            parse_secs_config(&parse_or_default(desc), desc.name)
+        };
+        let circuit_breaker_cool_down = {
+            let desc = &ConfigDescriptor::CIRCUIT_BREAKER_COOL_DOWN;
+            parse_secs_config(&parse_or_default(desc), desc.name)
+        };
+        let pokeapi_connect_timeout = {
+            let desc = &ConfigDescriptor::POKEAPI_CONNECT_TIMEOUT;
+            parse_timeout_config(&parse_or_default(desc), desc.name)
+        };
+        let pokeapi_request_timeout = {
+            let desc = &ConfigDescriptor::POKEAPI_REQUEST_TIMEOUT;
+            parse_timeout_config(&parse_or_default(desc), desc.name)
+        };
+        let pokeapi_pool_idle_timeout = {
+            let desc = &ConfigDescriptor::POKEAPI_POOL_IDLE_TIMEOUT;
+            parse_secs_config(&parse_or_default(desc), desc.name)
+        };
+        let pokeapi_pool_max_idle_per_host = {
+            let desc = &ConfigDescriptor::POKEAPI_POOL_MAX_IDLE_PER_HOST;
+            parse_count_config(&parse_or_default(desc), desc.name)
+        };
+        let pokeapi_http2_prior_knowledge = {
+            let desc = &ConfigDescriptor::POKEAPI_HTTP2_PRIOR_KNOWLEDGE;
+            parse_bool_config(&parse_or_default(desc), desc.name)
+        };
+        let fun_translations_connect_timeout = {
+            let desc = &ConfigDescriptor::FUN_TRANSLATIONS_CONNECT_TIMEOUT;
+            parse_timeout_config(&parse_or_default(desc), desc.name)
+        };
+        let fun_translations_request_timeout = {
+            let desc = &ConfigDescriptor::FUN_TRANSLATIONS_REQUEST_TIMEOUT;
+            parse_timeout_config(&parse_or_default(desc), desc.name)
+        };
+        let fun_translations_pool_idle_timeout = {
+            let desc = &ConfigDescriptor::FUN_TRANSLATIONS_POOL_IDLE_TIMEOUT;
+            parse_secs_config(&parse_or_default(desc), desc.name)
+        };
+        let fun_translations_pool_max_idle_per_host = {
+            let desc = &ConfigDescriptor::FUN_TRANSLATIONS_POOL_MAX_IDLE_PER_HOST;
+            parse_count_config(&parse_or_default(desc), desc.name)
+        };
+        let fun_translations_http2_prior_knowledge = {
+            let desc = &ConfigDescriptor::FUN_TRANSLATIONS_HTTP2_PRIOR_KNOWLEDGE;
+            parse_bool_config(&parse_or_default(desc), desc.name)
+        };
+        let readiness_required_dependencies = {
+            let desc = &ConfigDescriptor::READINESS_REQUIRED_DEPENDENCIES;
+            parse_dependencies_config(&parse_or_default(desc), desc.name)
+        };
+        let readiness_cache_ttl = {
+            let desc = &ConfigDescriptor::READINESS_CACHE_TTL;
+            parse_secs_config(&parse_or_default(desc), desc.name)
+        };
+        let readiness_probe_timeout = {
+            let desc = &ConfigDescriptor::READINESS_PROBE_TIMEOUT;
+            parse_timeout_config(&parse_or_default(desc), desc.name)
+        };
+        let shutdown_grace_period = {
+            let desc = &ConfigDescriptor::SHUTDOWN_GRACE_PERIOD;
+            parse_secs_config(&parse_or_default(desc), desc.name)
+        };
+        let rate_limit_pokemon = {
+            let desc = &ConfigDescriptor::RATE_LIMIT_POKEMON;
+            parse_count_config(&parse_or_default(desc), desc.name)
+        };
+        let rate_limit_translation = {
+            let desc = &ConfigDescriptor::RATE_LIMIT_TRANSLATION;
+            parse_count_config(&parse_or_default(desc), desc.name)
+        };
+        let rate_limit_trusted_proxies = {
+            let desc = &ConfigDescriptor::RATE_LIMIT_TRUSTED_PROXIES;
+            parse_networks_config(&parse_or_default(desc), desc.name)
+        };
+        let auth_keys_file = {
+            let desc = &ConfigDescriptor::AUTH_KEYS_FILE;
+            parse(desc)
+                .map(|path| parse_path_config(&path, desc.name))
+                .transpose()
+        };
+        let auth_anonymous_scopes = {
+            let desc = &ConfigDescriptor::AUTH_ANONYMOUS_SCOPES;
+            parse_scopes_config(&parse_or_default(desc), desc.name)
+        };
+        let batch_max_size = {
+            let desc = &ConfigDescriptor::BATCH_MAX_SIZE;
+            parse_count_config(&parse_or_default(desc), desc.name)
+        };
+        let batch_concurrency = {
+            let desc = &ConfigDescriptor::BATCH_CONCURRENCY;
+            parse_count_config(&parse_or_default(desc), desc.name)
+        };
+        let pokemon_index_refresh_interval = {
+            let desc = &ConfigDescriptor::POKEMON_INDEX_REFRESH_INTERVAL;
+            parse_secs_config(&parse_or_default(desc), desc.name)
+        };
+        let translator_rules_file = {
+            let desc = &ConfigDescriptor::TRANSLATOR_RULES_FILE;
+            parse(desc)
+                .map(|path| parse_path_config(&path, desc.name))
+                .transpose()
+        };
+        let translator_styles = {
+            let desc = &ConfigDescriptor::TRANSLATOR_STYLES;
+            parse_styles_config(&parse_or_default(desc), desc.name)
+        };
+        let translation_local_fallback = {
+            let desc = &ConfigDescriptor::TRANSLATION_LOCAL_FALLBACK;
+            parse_bool_config(&parse_or_default(desc), desc.name)
+        };
+        let description_casing_fixes = {
+            let desc = &ConfigDescriptor::DESCRIPTION_CASING_FIXES;
+            parse_casing_fixes_config(&parse_or_default(desc), desc.name)
+        };
+        let flavor_text_version_policy = {
+            let desc = &ConfigDescriptor::FLAVOR_TEXT_VERSION_POLICY;
+            VersionPolicy::parse(&parse_or_default(desc))
+                .map_err(|e| ConfigError::InvalidFormat(format!("invalid {}: {}", desc.name, e)))
+        };
+        let pokeapi_language_map = {
+            let desc = &ConfigDescriptor::POKEAPI_LANGUAGE_MAP;
+            LanguageMap::parse(&parse_or_default(desc))
+                .map_err(|e| ConfigError::InvalidFormat(format!("invalid {}: {}", desc.name, e)))
+        };
+        let language_fallback_chains = {
+            let desc = &ConfigDescriptor::LANGUAGE_FALLBACK_CHAINS;
+            LanguageFallbacks::parse_chains(&parse_or_default(desc))
+                .map_err(|e| ConfigError::InvalidFormat(format!("invalid {}: {}", desc.name, e)))
+        };
+        let default_language = {
+            let desc = &ConfigDescriptor::DEFAULT_LANGUAGE;
+            let value = parse_or_default(desc).trim().to_string();
+            match is_tag(&value) {
+                true => Ok(value),
+                false => Err(ConfigError::InvalidFormat(format!(
+                    "invalid {}: '{}' is not a language tag",
+                    desc.name, value
+                ))),
             }
+        };
+
+        // NOTE: every setting is validated before giving up, so that all the
+        // configuration errors are reported at once.
+        let mut errors = Vec::new();
+        let config = AppConfig {
+            pokeapi_host: collect(pokeapi_host, &mut errors),
+            fun_translations_host: collect(fun_translations_host, &mut errors),
+            pokeapi_secure: collect(pokeapi_secure, &mut errors),
+            fun_translations_secure: collect(fun_translations_secure, &mut errors),
+            port: collect(port, &mut errors),
+            rust_log: collect(rust_log, &mut errors),
+            pokeapi_cache_ttl: collect(pokeapi_cache_ttl, &mut errors),
+            pokeapi_cache_max_entries: collect(pokeapi_cache_max_entries, &mut errors),
+            translation_cache_path: collect(translation_cache_path, &mut errors),
+            translation_cache_max_entries: collect(translation_cache_max_entries, &mut errors),
+            retry_max_attempts: collect(retry_max_attempts, &mut errors),
+            retry_base_delay: collect(retry_base_delay, &mut errors),
+            retry_max_delay: collect(retry_max_delay, &mut errors),
+            retry_jitter: collect(retry_jitter, &mut errors),
+            retry_on: collect(retry_on, &mut errors),
+            circuit_breaker_failure_threshold: collect(
+                circuit_breaker_failure_threshold,
+                &mut errors,
+            ),
+            circuit_breaker_window: collect(circuit_breaker_window, &mut errors),
+            circuit_breaker_cool_down: collect(circuit_breaker_cool_down, &mut errors),
+            pokeapi_connect_timeout: collect(pokeapi_connect_timeout, &mut errors),
+            pokeapi_request_timeout: collect(pokeapi_request_timeout, &mut errors),
+            pokeapi_pool_idle_timeout: collect(pokeapi_pool_idle_timeout, &mut errors),
+            pokeapi_pool_max_idle_per_host: collect(pokeapi_pool_max_idle_per_host, &mut errors),
+            pokeapi_http2_prior_knowledge: collect(pokeapi_http2_prior_knowledge, &mut errors),
+            fun_translations_connect_timeout: collect(
+                fun_translations_connect_timeout,
+                &mut errors,
+            ),
+            fun_translations_request_timeout: collect(
+                fun_translations_request_timeout,
+                &mut errors,
+            ),
+            fun_translations_pool_idle_timeout: collect(
+                fun_translations_pool_idle_timeout,
+                &mut errors,
+            ),
+            fun_translations_pool_max_idle_per_host: collect(
+                fun_translations_pool_max_idle_per_host,
+                &mut errors,
+            ),
+            fun_translations_http2_prior_knowledge: collect(
+                fun_translations_http2_prior_knowledge,
+                &mut errors,
+            ),
+            readiness_required_dependencies: collect(readiness_required_dependencies, &mut errors),
+            readiness_cache_ttl: collect(readiness_cache_ttl, &mut errors),
+            readiness_probe_timeout: collect(readiness_probe_timeout, &mut errors),
+            shutdown_grace_period: collect(shutdown_grace_period, &mut errors),
+            rate_limit_pokemon: collect(rate_limit_pokemon, &mut errors),
+            rate_limit_translation: collect(rate_limit_translation, &mut errors),
+            rate_limit_trusted_proxies: collect(rate_limit_trusted_proxies, &mut errors),
+            auth_keys_file: collect(auth_keys_file, &mut errors),
+            auth_anonymous_scopes: collect(auth_anonymous_scopes, &mut errors),
+            batch_max_size: collect(batch_max_size, &mut errors),
+            batch_concurrency: collect(batch_concurrency, &mut errors),
+            pokemon_index_refresh_interval: collect(pokemon_index_refresh_interval, &mut errors),
+            translator_rules_file: collect(translator_rules_file, &mut errors),
+            translator_styles: collect(translator_styles, &mut errors),
+            translation_local_fallback: collect(translation_local_fallback, &mut errors),
+            description_casing_fixes: collect(description_casing_fixes, &mut errors),
+            flavor_text_version_policy: collect(flavor_text_version_policy, &mut errors),
+            pokeapi_language_map: collect(pokeapi_language_map, &mut errors),
+            language_fallback_chains: collect(language_fallback_chains, &mut errors),
+            default_language: collect(default_language, &mut errors),
+        };
+        match errors.is_empty() {
+            true => Ok(config),
+            false => Err(ConfigError::Multiple(errors)),
         }
     }
 
@@ -272,6 +992,17 @@ impl AppConfig {
     }
 }
 
+/// Unwraps a parsed configuration value, recording the error (if any).
+///
+/// Returns the default value of `T` on error: the resulting configuration is
+/// discarded anyway as soon as at least one error has been recorded.
+fn collect<T: Default>(result: Result<T, ConfigError>, errors: &mut Vec<ConfigError>) -> T {
+    result.unwrap_or_else(|e| {
+        errors.push(e);
+        T::default()
+    })
+}
+
 /// Parses a boolean configuration value (case-insensitive "true" or "false").
 ///
 /// # Arguments
@@ -319,6 +1050,295 @@ fn parse_port_config(value: &str, name: &'static str) -> Result<u16, ConfigError
     }
 }
 
+/// Parses a duration configuration value expressed in whole seconds.
+///
+/// # Arguments
+///
+/// * `value` - The string value to parse
+/// * `name` - The configuration name for error messages
+///
+/// # Returns
+///
+/// Returns `Ok(Duration)` on success, or `ConfigError::InvalidFormat` if the value
+/// is not a positive integer
+fn parse_secs_config(value: &str, name: &'static str) -> Result<Duration, ConfigError> {
+    match value.parse::<u64>() {
+        Ok(secs) if secs > 0 => Ok(Duration::from_secs(secs)),
+        _ => Err(ConfigError::InvalidFormat(format!(
+            "invalid {} value: '{}' (expected a positive number of seconds)",
+            name, value
+        ))),
+    }
+}
+
+/// Parses a duration configuration value expressed in milliseconds.
+///
+/// # Arguments
+///
+/// * `value` - The string value to parse
+/// * `name` - The configuration name for error messages
+///
+/// # Returns
+///
+/// Returns `Ok(Duration)` on success, or `ConfigError::InvalidFormat` if the value
+/// is not a non-negative integer
+fn parse_millis_config(value: &str, name: &'static str) -> Result<Duration, ConfigError> {
+    value
+        .parse::<u64>()
+        .map(Duration::from_millis)
+        .map_err(|_| {
+            ConfigError::InvalidFormat(format!(
+                "invalid {} value: '{}' (expected a number of milliseconds)",
+                name, value
+            ))
+        })
+}
+
+/// Parses a timeout configuration value expressed in milliseconds.
+///
+/// # Arguments
+///
+/// * `value` - The string value to parse
+/// * `name` - The configuration name for error messages
+///
+/// # Returns
+///
+/// Returns `Ok(Duration)` on success, or `ConfigError::InvalidFormat` if the value
+/// is not a positive number of milliseconds
+fn parse_timeout_config(value: &str, name: &'static str) -> Result<Duration, ConfigError> {
+    match value.parse::<u64>() {
+        Ok(millis) if millis > 0 => Ok(Duration::from_millis(millis)),
+        _ => Err(ConfigError::InvalidFormat(format!(
+            "invalid {} value: '{}' (expected a positive number of milliseconds)",
+            name, value
+        ))),
+    }
+}
+
+/// Parses a comma separated list of retryable upstream errors.
+///
+/// # Arguments
+///
+/// * `value` - The string value to parse (e.g., "request-failed,server-error")
+/// * `name` - The configuration name for error messages
+///
+/// # Returns
+///
+/// Returns `Ok(Vec<ErrorKind>)` on success (empty for an empty list), or
+/// `ConfigError::InvalidFormat` on unknown error names
+fn parse_retryable_errors_config(
+    value: &str,
+    name: &'static str,
+) -> Result<Vec<ErrorKind>, ConfigError> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| match s.to_lowercase().as_str() {
+            "request-failed" => Ok(ErrorKind::RequestFailed),
+            "rate-limited" => Ok(ErrorKind::RateLimited),
+            "service-unavailable" => Ok(ErrorKind::ServiceUnavailable),
+            "server-error" => Ok(ErrorKind::ServerError),
+            "timeout" => Ok(ErrorKind::Timeout),
+            _ => Err(ConfigError::InvalidFormat(format!(
+                "invalid {} value: '{}' (expected request-failed, rate-limited, service-unavailable, server-error or timeout)",
+                name, s
+            ))),
+        })
+        .collect()
+}
+
+/// Parses a comma separated list of upstream dependency names.
+///
+/// # Arguments
+///
+/// * `value` - The string value to parse
+/// * `name` - The configuration name for error messages
+///
+/// # Returns
+///
+/// Returns `Ok(Vec<String>)` on success (possibly empty), or `ConfigError::InvalidFormat`
+/// if a name is not a known upstream
+fn parse_dependencies_config(value: &str, name: &'static str) -> Result<Vec<String>, ConfigError> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| match s.to_lowercase().as_str() {
+            dependency @ (POKEAPI_UPSTREAM | FUN_TRANSLATIONS_UPSTREAM) => {
+                Ok(dependency.to_string())
+            }
+            _ => Err(ConfigError::InvalidFormat(format!(
+                "invalid {} value: '{}' (expected {} or {})",
+                name, s, POKEAPI_UPSTREAM, FUN_TRANSLATIONS_UPSTREAM
+            ))),
+        })
+        .collect()
+}
+
+/// Parses a comma separated list of IP addresses or networks (CIDR notation).
+///
+/// # Arguments
+///
+/// * `value` - The string value to parse
+/// * `name` - The configuration name for error messages
+///
+/// # Returns
+///
+/// Returns `Ok(Vec<IpNet>)` on success (a single address is a network of its own),
+/// or `ConfigError::InvalidFormat` if an entry is neither an address nor a network
+fn parse_networks_config(value: &str, name: &'static str) -> Result<Vec<IpNet>, ConfigError> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse::<IpNet>()
+                .or_else(|_| s.parse::<IpAddr>().map(IpNet::from))
+                .map_err(|_| {
+                    ConfigError::InvalidFormat(format!(
+                        "invalid {} value: '{}' (expected an IP address or network)",
+                        name, s
+                    ))
+                })
+        })
+        .collect()
+}
+
+/// Parses a comma separated list of API key scopes.
+///
+/// # Arguments
+///
+/// * `value` - The string value to parse
+/// * `name` - The configuration name for error messages
+///
+/// # Returns
+///
+/// Returns `Ok(Vec<Scope>)` on success (empty for "none"), or `ConfigError::InvalidFormat`
+/// if a scope is unknown
+fn parse_scopes_config(value: &str, name: &'static str) -> Result<Vec<Scope>, ConfigError> {
+    if value.trim().eq_ignore_ascii_case("none") {
+        return Ok(vec![]);
+    }
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            Scope::try_from(s.to_lowercase())
+                .map_err(|e| ConfigError::InvalidFormat(format!("invalid {}: {}", name, e)))
+        })
+        .collect()
+}
+
+/// Parses a comma separated list of translator styles.
+///
+/// # Arguments
+///
+/// * `value` - The string value to parse
+/// * `name` - The configuration name for error messages
+///
+/// # Returns
+///
+/// Returns `Ok(Vec<TranslatorType>)` on success, or `ConfigError::InvalidFormat` if a
+/// style is not a valid identifier or if no style is listed
+fn parse_styles_config(
+    value: &str,
+    name: &'static str,
+) -> Result<Vec<TranslatorType>, ConfigError> {
+    let styles = value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            TranslatorType::try_from(s.to_string())
+                .map_err(|e| ConfigError::InvalidFormat(format!("invalid {}: {}", name, e)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match styles.is_empty() {
+        true => Err(ConfigError::InvalidFormat(format!(
+            "invalid {}: no style listed",
+            name
+        ))),
+        false => Ok(styles),
+    }
+}
+
+/// Parses a comma separated list of `spelling=replacement` casing fixes.
+///
+/// # Arguments
+///
+/// * `value` - The string value to parse
+/// * `name` - The configuration name for error messages
+///
+/// # Returns
+///
+/// Returns `Ok(Vec<(String, String)>)` on success (empty for `none`), or
+/// `ConfigError::InvalidFormat` if a fix has no `=` or an empty spelling
+fn parse_casing_fixes_config(
+    value: &str,
+    name: &'static str,
+) -> Result<Vec<(String, String)>, ConfigError> {
+    if value.trim().eq_ignore_ascii_case("none") {
+        return Ok(vec![]);
+    }
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| match s.split_once('=') {
+            Some((from, to)) if !from.trim().is_empty() => {
+                Ok((from.trim().to_string(), to.trim().to_string()))
+            }
+            _ => Err(ConfigError::InvalidFormat(format!(
+                "invalid {}: '{}' is not a spelling=replacement pair",
+                name, s
+            ))),
+        })
+        .collect()
+}
+
+/// Parses a count configuration value (e.g. a maximum number of entries).
+///
+/// # Arguments
+///
+/// * `value` - The string value to parse
+/// * `name` - The configuration name for error messages
+///
+/// # Returns
+///
+/// Returns `Ok(usize)` on success, or `ConfigError::InvalidFormat` if the value
+/// is not a positive integer
+fn parse_count_config(value: &str, name: &'static str) -> Result<usize, ConfigError> {
+    match value.parse::<usize>() {
+        Ok(count) if count > 0 => Ok(count),
+        _ => Err(ConfigError::InvalidFormat(format!(
+            "invalid {} value: '{}' (expected a positive integer)",
+            name, value
+        ))),
+    }
+}
+
+/// Parses a file path configuration value.
+///
+/// # Arguments
+///
+/// * `value` - The string value to parse
+/// * `name` - The configuration name for error messages
+///
+/// # Returns
+///
+/// Returns `Ok(PathBuf)` on success, or `ConfigError::InvalidFormat` if the value is blank
+fn parse_path_config(value: &str, name: &'static str) -> Result<PathBuf, ConfigError> {
+    match value.trim() {
+        "" => Err(ConfigError::InvalidFormat(format!(
+            "{} cannot be empty",
+            name
+        ))),
+        path => Ok(PathBuf::from(path)),
+    }
+}
+
 /// Parses a Rust log level configuration value.
 ///
 /// # Arguments
@@ -517,6 +1537,232 @@ mod tests {
         assert_eq!(result.unwrap(), hostname);
     }
 
+    // Duration and Count Configuration Tests
+    #[test]
+    fn parse_secs_config_accepts_positive_values() {
+        assert_eq!(
+            parse_secs_config("60", "test").unwrap(),
+            Duration::from_secs(60)
+        );
+        assert_eq!(
+            parse_secs_config("1", "test").unwrap(),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn parse_secs_config_rejects_invalid_values() {
+        assert!(parse_secs_config("0", "test").is_err());
+        assert!(parse_secs_config("-1", "test").is_err());
+        assert!(parse_secs_config("1.5", "test").is_err());
+        assert!(parse_secs_config("abc", "test").is_err());
+        assert!(parse_secs_config("", "test").is_err());
+    }
+
+    #[test]
+    fn parse_count_config_accepts_positive_values() {
+        assert_eq!(parse_count_config("1", "test").unwrap(), 1);
+        assert_eq!(parse_count_config("2048", "test").unwrap(), 2048);
+    }
+
+    #[test]
+    fn parse_count_config_rejects_invalid_values() {
+        assert!(parse_count_config("0", "test").is_err());
+        assert!(parse_count_config("-5", "test").is_err());
+        assert!(parse_count_config("ten", "test").is_err());
+        let err_msg = parse_count_config("ten", "test_field")
+            .unwrap_err()
+            .to_string();
+        assert!(err_msg.contains("ten"));
+        assert!(err_msg.contains("test_field"));
+    }
+
+    #[test]
+    fn parse_millis_config_accepts_non_negative_values() {
+        assert_eq!(
+            parse_millis_config("250", "test").unwrap(),
+            Duration::from_millis(250)
+        );
+        assert_eq!(parse_millis_config("0", "test").unwrap(), Duration::ZERO);
+        assert!(parse_millis_config("-1", "test").is_err());
+        assert!(parse_millis_config("1s", "test").is_err());
+    }
+
+    #[test]
+    fn parse_timeout_config_accepts_positive_values() {
+        assert_eq!(
+            parse_timeout_config("1500", "test").unwrap(),
+            Duration::from_millis(1500)
+        );
+        assert!(parse_timeout_config("0", "test").is_err());
+        assert!(parse_timeout_config("-1", "test").is_err());
+        assert!(parse_timeout_config("2s", "test").is_err());
+    }
+
+    #[test]
+    fn parse_dependencies_config_accepts_known_upstreams() {
+        assert_eq!(
+            parse_dependencies_config("pokeapi, FunTranslations", "test").unwrap(),
+            vec!["pokeapi".to_string(), "funtranslations".to_string()]
+        );
+        assert!(parse_dependencies_config("", "test").unwrap().is_empty());
+        let result = parse_dependencies_config("pokeapi,database", "test");
+        assert!(result.unwrap_err().to_string().contains("database"));
+    }
+
+    #[test]
+    fn is_required_dependency_checks_configured_dependencies() {
+        let config = config_for_test("pokeapi.co", true, "api.funtranslations.com", true);
+        assert!(config.is_required_dependency(POKEAPI_UPSTREAM));
+        assert!(!config.is_required_dependency(FUN_TRANSLATIONS_UPSTREAM));
+    }
+
+    #[test]
+    fn parse_networks_config_accepts_addresses_and_networks() {
+        let networks = parse_networks_config("10.0.0.0/8, 127.0.0.1, ::1", "test").unwrap();
+        assert_eq!(
+            networks,
+            vec![
+                "10.0.0.0/8".parse::<IpNet>().unwrap(),
+                "127.0.0.1/32".parse().unwrap(),
+                "::1/128".parse().unwrap(),
+            ]
+        );
+        assert!(parse_networks_config("", "test").unwrap().is_empty());
+        assert!(parse_networks_config("10.0.0.0/33", "test").is_err());
+        assert!(parse_networks_config("nginx", "test").is_err());
+    }
+
+    #[test]
+    fn parse_scopes_config_accepts_known_scopes_or_none() {
+        assert_eq!(
+            parse_scopes_config(DEFAULT_AUTH_ANONYMOUS_SCOPES, "test").unwrap(),
+            vec![Scope::Pokemon, Scope::Translation, Scope::Metrics]
+        );
+        assert!(parse_scopes_config("None", "test").unwrap().is_empty());
+        let result = parse_scopes_config("pokemon:read,pokemon:write", "test");
+        assert!(result.unwrap_err().to_string().contains("pokemon:write"));
+    }
+
+    #[test]
+    fn parse_casing_fixes_config_accepts_pairs_or_none() {
+        let fixes = parse_casing_fixes_config(DEFAULT_DESCRIPTION_CASING_FIXES, "test").unwrap();
+        assert_eq!(
+            fixes.first(),
+            Some(&("POKéMON".to_string(), "Pokémon".to_string()))
+        );
+        assert!(fixes.contains(&("POKé BALL".to_string(), "Poké Ball".to_string())));
+        assert!(
+            parse_casing_fixes_config("none", "test")
+                .unwrap()
+                .is_empty()
+        );
+        let result = parse_casing_fixes_config("POKéMON=Pokémon,POKéDEX", "test");
+        assert!(result.unwrap_err().to_string().contains("POKéDEX"));
+    }
+
+    #[test]
+    fn parse_styles_config_accepts_style_identifiers() {
+        let styles = parse_styles_config(DEFAULT_TRANSLATOR_STYLES, "test").unwrap();
+        assert!(styles.contains(&TranslatorType::YODA));
+        assert!(styles.iter().any(|s| s.as_str() == "pig-latin"));
+        assert_eq!(
+            parse_styles_config(" Yoda ,", "test").unwrap(),
+            vec![TranslatorType::YODA]
+        );
+        let result = parse_styles_config("yoda,pig latin", "test");
+        assert!(result.unwrap_err().to_string().contains("pig latin"));
+        assert!(parse_styles_config(" , ", "test").is_err());
+    }
+
+    // Retryable Errors Configuration Tests
+    #[test]
+    fn parse_retryable_errors_config_accepts_known_errors() {
+        assert_eq!(
+            parse_retryable_errors_config("request-failed, Server-Error", "test").unwrap(),
+            vec![ErrorKind::RequestFailed, ErrorKind::ServerError]
+        );
+        let defaults = parse_retryable_errors_config(DEFAULT_RETRY_ON, "test").unwrap();
+        assert_eq!(defaults.len(), 4);
+        assert!(!defaults.contains(&ErrorKind::RateLimited));
+        assert!(
+            parse_retryable_errors_config("", "test")
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn parse_retryable_errors_config_rejects_unknown_errors() {
+        let result = parse_retryable_errors_config("request-failed,not-found", "test");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("not-found"));
+    }
+
+    #[test]
+    fn retry_policy_is_built_from_configuration() {
+        let config = config_for_test("pokeapi.co", true, "api.funtranslations.com", true);
+        let policy = config.retry_policy();
+        assert_eq!(policy.max_attempts, 3);
+        assert_eq!(policy.base_delay, Duration::from_millis(100));
+        assert_eq!(policy.max_delay, Duration::from_millis(2000));
+        assert!(policy.jitter);
+        assert_eq!(policy.retryable, config.retry_on);
+    }
+
+    #[test]
+    fn circuit_breaker_config_is_built_from_configuration() {
+        let config = config_for_test("pokeapi.co", true, "api.funtranslations.com", true);
+        assert_eq!(
+            config.circuit_breaker_config(),
+            CircuitBreakerConfig {
+                failure_threshold: 5,
+                window: Duration::from_secs(30),
+                cool_down: Duration::from_secs(30),
+            }
+        );
+    }
+
+    #[test]
+    fn upstream_client_configs_are_built_from_configuration() {
+        let config = AppConfig {
+            fun_translations_request_timeout: Duration::from_millis(500),
+            fun_translations_http2_prior_knowledge: true,
+            ..config_for_test("pokeapi.co", true, "api.funtranslations.com", true)
+        };
+        assert_eq!(
+            config.pokeapi_client_config(),
+            UpstreamClientConfig {
+                connect_timeout: Duration::from_millis(2000),
+                request_timeout: Duration::from_millis(10000),
+                pool_idle_timeout: Duration::from_secs(90),
+                pool_max_idle_per_host: 32,
+                http2_prior_knowledge: false,
+            }
+        );
+        let fun_translations = config.fun_translations_client_config();
+        assert_eq!(fun_translations.request_timeout, Duration::from_millis(500));
+        assert!(fun_translations.http2_prior_knowledge);
+    }
+
+    #[test]
+    fn parse_path_config_accepts_paths() {
+        assert_eq!(
+            parse_path_config("/data/translations.db", "test").unwrap(),
+            PathBuf::from("/data/translations.db")
+        );
+        assert_eq!(
+            parse_path_config(" cache.db ", "test").unwrap(),
+            PathBuf::from("cache.db")
+        );
+    }
+
+    #[test]
+    fn parse_path_config_rejects_blank_values() {
+        assert!(parse_path_config("", "test").is_err());
+        assert!(parse_path_config("   ", "test").is_err());
+    }
+
     // Rust Log Configuration Tests
     #[test]
     fn parse_rust_log_config_accepts_valid_levels() {
@@ -564,40 +1810,19 @@ mod tests {
     // URL Generation Tests
     #[test]
     fn pokeapi_base_url_uses_https_when_secure() {
-        let config = AppConfig {
-            pokeapi_host: "pokeapi.co".to_string(),
-            pokeapi_secure: true,
-            fun_translations_host: "api.funtranslations.com".to_string(),
-            fun_translations_secure: true,
-            port: 5000,
-            rust_log: "info".to_string(),
-        };
+        let config = config_for_test("pokeapi.co", true, "api.funtranslations.com", true);
         assert_eq!(config.pokeapi_base_url(), "https://pokeapi.co/api/v2");
     }
 
     #[test]
     fn pokeapi_base_url_uses_http_when_not_secure() {
-        let config = AppConfig {
-            pokeapi_host: "localhost".to_string(),
-            pokeapi_secure: false,
-            fun_translations_host: "localhost".to_string(),
-            fun_translations_secure: false,
-            port: 5000,
-            rust_log: "info".to_string(),
-        };
+        let config = config_for_test("localhost", false, "localhost", false);
         assert_eq!(config.pokeapi_base_url(), "http://localhost/api/v2");
     }
 
     #[test]
     fn fun_translations_base_url_uses_https_when_secure() {
-        let config = AppConfig {
-            pokeapi_host: "pokeapi.co".to_string(),
-            pokeapi_secure: true,
-            fun_translations_host: "api.funtranslations.com".to_string(),
-            fun_translations_secure: true,
-            port: 5000,
-            rust_log: "info".to_string(),
-        };
+        let config = config_for_test("pokeapi.co", true, "api.funtranslations.com", true);
         assert_eq!(
             config.fun_translations_base_url(),
             "https://api.funtranslations.com/translate"
@@ -606,14 +1831,7 @@ mod tests {
 
     #[test]
     fn fun_translations_base_url_uses_http_when_not_secure() {
-        let config = AppConfig {
-            pokeapi_host: "localhost".to_string(),
-            pokeapi_secure: false,
-            fun_translations_host: "localhost".to_string(),
-            fun_translations_secure: false,
-            port: 5000,
-            rust_log: "info".to_string(),
-        };
+        let config = config_for_test("localhost", false, "localhost", false);
         assert_eq!(
             config.fun_translations_base_url(),
             "http://localhost/translate"
@@ -624,7 +1842,7 @@ mod tests {
     #[test]
     fn config_descriptor_all_array_contains_all_fields() {
         let all = ConfigDescriptor::ALL;
-        assert_eq!(all.len(), 6);
+        assert_eq!(all.len(), 48);
 
         let names: Vec<&str> = all.iter().map(|d| d.name).collect();
         assert!(names.contains(&"pokeapi host"));
@@ -633,6 +1851,48 @@ mod tests {
         assert!(names.contains(&"fun translations secure"));
         assert!(names.contains(&"port"));
         assert!(names.contains(&"rust log"));
+        assert!(names.contains(&"pokeapi cache ttl"));
+        assert!(names.contains(&"pokeapi cache max entries"));
+        assert!(names.contains(&"translation cache path"));
+        assert!(names.contains(&"translation cache max entries"));
+        assert!(names.contains(&"retry max attempts"));
+        assert!(names.contains(&"retry base delay"));
+        assert!(names.contains(&"retry max delay"));
+        assert!(names.contains(&"retry jitter"));
+        assert!(names.contains(&"retry on"));
+        assert!(names.contains(&"circuit breaker failure threshold"));
+        assert!(names.contains(&"circuit breaker window"));
+        assert!(names.contains(&"circuit breaker cool down"));
+        assert!(names.contains(&"pokeapi connect timeout"));
+        assert!(names.contains(&"pokeapi request timeout"));
+        assert!(names.contains(&"pokeapi pool idle timeout"));
+        assert!(names.contains(&"pokeapi pool max idle per host"));
+        assert!(names.contains(&"pokeapi http2 prior knowledge"));
+        assert!(names.contains(&"fun translations connect timeout"));
+        assert!(names.contains(&"fun translations request timeout"));
+        assert!(names.contains(&"fun translations pool idle timeout"));
+        assert!(names.contains(&"fun translations pool max idle per host"));
+        assert!(names.contains(&"fun translations http2 prior knowledge"));
+        assert!(names.contains(&"readiness required dependencies"));
+        assert!(names.contains(&"readiness cache ttl"));
+        assert!(names.contains(&"readiness probe timeout"));
+        assert!(names.contains(&"shutdown grace period"));
+        assert!(names.contains(&"rate limit pokemon"));
+        assert!(names.contains(&"rate limit translation"));
+        assert!(names.contains(&"rate limit trusted proxies"));
+        assert!(names.contains(&"auth keys file"));
+        assert!(names.contains(&"auth anonymous scopes"));
+        assert!(names.contains(&"batch max size"));
+        assert!(names.contains(&"batch concurrency"));
+        assert!(names.contains(&"pokemon index refresh interval"));
+        assert!(names.contains(&"translator rules file"));
+        assert!(names.contains(&"translator styles"));
+        assert!(names.contains(&"translation local fallback"));
+        assert!(names.contains(&"description casing fixes"));
+        assert!(names.contains(&"flavor text version policy"));
+        assert!(names.contains(&"pokeapi language map"));
+        assert!(names.contains(&"language fallback chains"));
+        assert!(names.contains(&"default language"));
     }
 
     #[test]
@@ -656,6 +1916,12 @@ mod tests {
         );
         assert!(ConfigDescriptor::PORT.default_value.is_some());
         assert!(ConfigDescriptor::RUST_LOG.default_value.is_some());
+        assert!(ConfigDescriptor::POKEAPI_CACHE_TTL.default_value.is_some());
+        assert!(
+            ConfigDescriptor::POKEAPI_CACHE_MAX_ENTRIES
+                .default_value
+                .is_some()
+        );
     }
 
     #[test]
@@ -768,6 +2034,65 @@ mod tests {
         assert_eq!(result, None);
     }
 
+    // Helper function building a configuration with default optional settings
+    fn config_for_test(
+        pokeapi_host: &str,
+        pokeapi_secure: bool,
+        fun_translations_host: &str,
+        fun_translations_secure: bool,
+    ) -> AppConfig {
+        AppConfig {
+            pokeapi_host: pokeapi_host.to_string(),
+            pokeapi_secure,
+            fun_translations_host: fun_translations_host.to_string(),
+            fun_translations_secure,
+            port: 5000,
+            rust_log: "info".to_string(),
+            pokeapi_cache_ttl: Duration::from_secs(3600),
+            pokeapi_cache_max_entries: 2048,
+            translation_cache_path: PathBuf::from("translations.db"),
+            translation_cache_max_entries: 10000,
+            retry_max_attempts: 3,
+            retry_base_delay: Duration::from_millis(100),
+            retry_max_delay: Duration::from_millis(2000),
+            retry_jitter: true,
+            retry_on: parse_retryable_errors_config(DEFAULT_RETRY_ON, "retry on").unwrap(),
+            circuit_breaker_failure_threshold: 5,
+            circuit_breaker_window: Duration::from_secs(30),
+            circuit_breaker_cool_down: Duration::from_secs(30),
+            pokeapi_connect_timeout: Duration::from_millis(2000),
+            pokeapi_request_timeout: Duration::from_millis(10000),
+            pokeapi_pool_idle_timeout: Duration::from_secs(90),
+            pokeapi_pool_max_idle_per_host: 32,
+            pokeapi_http2_prior_knowledge: false,
+            fun_translations_connect_timeout: Duration::from_millis(2000),
+            fun_translations_request_timeout: Duration::from_millis(10000),
+            fun_translations_pool_idle_timeout: Duration::from_secs(90),
+            fun_translations_pool_max_idle_per_host: 32,
+            fun_translations_http2_prior_knowledge: false,
+            readiness_required_dependencies: vec![POKEAPI_UPSTREAM.to_string()],
+            readiness_cache_ttl: Duration::from_secs(5),
+            readiness_probe_timeout: Duration::from_millis(2000),
+            shutdown_grace_period: Duration::from_secs(30),
+            rate_limit_pokemon: 120,
+            rate_limit_translation: 10,
+            rate_limit_trusted_proxies: vec![],
+            auth_keys_file: None,
+            auth_anonymous_scopes: vec![Scope::Pokemon, Scope::Translation, Scope::Metrics],
+            batch_max_size: 20,
+            batch_concurrency: 6,
+            pokemon_index_refresh_interval: Duration::from_secs(3600),
+            translator_rules_file: None,
+            translator_styles: vec![TranslatorType::SHAKESPEARE, TranslatorType::YODA],
+            translation_local_fallback: true,
+            description_casing_fixes: vec![],
+            flavor_text_version_policy: VersionPolicy::Latest,
+            pokeapi_language_map: LanguageMap::default(),
+            language_fallback_chains: vec![],
+            default_language: DEFAULT_LANGUAGE.to_string(),
+        }
+    }
+
     // Helper function for hostname validation tests
     fn validate_hostname_for_test(host: &str) -> Result<String, ConfigError> {
         AppConfig::validate_host(host.to_string(), "test")
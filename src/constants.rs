@@ -1,3 +1,50 @@
+pub const POKEAPI_UPSTREAM: &str = "pokeapi";
+pub const FUN_TRANSLATIONS_UPSTREAM: &str = "funtranslations";
 pub const DEFAULT_LANGUAGE: &str = "en";
 pub const DEFAULT_PORT: &str = "5000";
 pub const DEFAULT_RUST_LOG: &str = "info";
+pub const DEFAULT_POKEAPI_CACHE_TTL_SECS: &str = "3600";
+pub const DEFAULT_POKEAPI_CACHE_MAX_ENTRIES: &str = "2048";
+pub const DEFAULT_TRANSLATION_CACHE_PATH: &str = "translations.db";
+pub const DEFAULT_TRANSLATION_CACHE_MAX_ENTRIES: &str = "10000";
+pub const DEFAULT_RETRY_MAX_ATTEMPTS: &str = "3";
+pub const DEFAULT_RETRY_BASE_DELAY_MS: &str = "100";
+pub const DEFAULT_RETRY_MAX_DELAY_MS: &str = "2000";
+pub const DEFAULT_RETRY_JITTER: &str = "true";
+/// Rate limited requests are not retried: Fun Translations' free quota is 5 requests an hour.
+pub const DEFAULT_RETRY_ON: &str = "request-failed,service-unavailable,server-error,timeout";
+pub const DEFAULT_CIRCUIT_BREAKER_FAILURE_THRESHOLD: &str = "5";
+pub const DEFAULT_CIRCUIT_BREAKER_WINDOW_SECS: &str = "30";
+pub const DEFAULT_CIRCUIT_BREAKER_COOL_DOWN_SECS: &str = "30";
+pub const DEFAULT_UPSTREAM_CONNECT_TIMEOUT_MS: &str = "2000";
+pub const DEFAULT_UPSTREAM_REQUEST_TIMEOUT_MS: &str = "10000";
+pub const DEFAULT_UPSTREAM_POOL_IDLE_TIMEOUT_SECS: &str = "90";
+pub const DEFAULT_UPSTREAM_POOL_MAX_IDLE_PER_HOST: &str = "32";
+pub const DEFAULT_UPSTREAM_HTTP2_PRIOR_KNOWLEDGE: &str = "false";
+pub const DEFAULT_READINESS_REQUIRED_DEPENDENCIES: &str = "pokeapi";
+pub const DEFAULT_READINESS_CACHE_TTL_SECS: &str = "5";
+pub const DEFAULT_READINESS_PROBE_TIMEOUT_MS: &str = "2000";
+pub const DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS: &str = "30";
+pub const DEFAULT_RATE_LIMIT_POKEMON_PER_MINUTE: &str = "120";
+pub const DEFAULT_RATE_LIMIT_TRANSLATION_PER_MINUTE: &str = "10";
+pub const DEFAULT_RATE_LIMIT_TRUSTED_PROXIES: &str = "127.0.0.1/32,::1/128";
+pub const DEFAULT_AUTH_ANONYMOUS_SCOPES: &str = "pokemon:read,translation:read,metrics:read";
+pub const DEFAULT_BATCH_MAX_SIZE: &str = "20";
+pub const DEFAULT_BATCH_CONCURRENCY: &str = "6";
+pub const DEFAULT_POKEMON_INDEX_REFRESH_INTERVAL_SECS: &str = "3600";
+pub const DEFAULT_TRANSLATION_LOCAL_FALLBACK: &str = "true";
+pub const DEFAULT_DESCRIPTION_CASING_FIXES: &str =
+    "POKéMON=Pokémon,POKé BALL=Poké Ball,POKéDEX=Pokédex,POKé=Poké";
+pub const DEFAULT_FLAVOR_TEXT_VERSION_POLICY: &str = "latest";
+pub const DEFAULT_LANGUAGE_FALLBACK_CHAINS: &str = "pt=es,ca=es>fr,gl=es>pt";
+pub const DEFAULT_POKEAPI_LANGUAGE_MAP: &str =
+    "zh-TW=zh-Hant,zh-HK=zh-Hant,zh-MO=zh-Hant,zh=zh-Hans,ja=ja-Hrkt,ja-Latn=roomaji,pt=pt-BR";
+pub const DEFAULT_TRANSLATOR_STYLES: &str = "shakespeare,yoda,pirate,minion,klingon,valyrian,dothraki,sith,gungan,huttese,mandalorian,vulcan,pig-latin,morse";
+
+pub const LIST_ROUTE: &str = "/pokemon";
+pub const POKEMON_ROUTE: &str = "/pokemon/{name}";
+pub const BATCH_ROUTE: &str = "/pokemon/batch";
+pub const TRANSLATION_ROUTE: &str = "/pokemon/{name}/translation/";
+pub const EVOLUTION_ROUTE: &str = "/pokemon/{name}/evolution";
+pub const DESCRIPTIONS_ROUTE: &str = "/pokemon/{name}/descriptions";
+pub const METRICS_ROUTE: &str = "/metrics";
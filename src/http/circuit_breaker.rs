@@ -0,0 +1,383 @@
+//! # Upstream Circuit Breaker
+//!
+//! Classic three-state circuit breaker guarding an upstream:
+//! - **Closed**: calls flow through; failures are tracked over a rolling window
+//! - **Open**: once `failure_threshold` failures happen within `window`, calls fail fast
+//!   with `ErrorKind::ServiceUnavailable` until `cool_down` has elapsed
+//! - **Half-open**: after the cool-down a single trial call is let through; its success
+//!   closes the circuit, its failure opens it again
+//!
+//! Only errors hinting at an unhealthy upstream count as failures (transport errors,
+//! timeouts, 5xx responses): `NotFound`, `RateLimited` and friends are legitimate answers.
+//!
+//! The state of every breaker is exported by the `circuit_breaker_state` gauge
+//! (0 = closed, 1 = open, 2 = half-open), labelled by upstream.
+
+use crate::{
+    http::client::{ErrorKind, HttpClientError},
+    metrics,
+};
+use serde::Serialize;
+use std::{
+    collections::VecDeque,
+    future::Future,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
+use utoipa::ToSchema;
+
+/// State of a circuit breaker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+impl CircuitState {
+    fn gauge_value(self) -> i64 {
+        match self {
+            CircuitState::Closed => 0,
+            CircuitState::Open => 1,
+            CircuitState::HalfOpen => 2,
+        }
+    }
+}
+
+/// Circuit breaker settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircuitBreakerConfig {
+    /// Failures within `window` tripping the circuit
+    pub failure_threshold: usize,
+    /// Rolling window failures are counted over
+    pub window: Duration,
+    /// Time the circuit stays open before letting a trial call through
+    pub cool_down: Duration,
+}
+
+struct BreakerState {
+    state: CircuitState,
+    failures: VecDeque<Instant>,
+    opened_at: Option<Instant>,
+    trial_in_flight: bool,
+}
+
+/// Circuit breaker for a single upstream.
+pub struct CircuitBreaker {
+    upstream: &'static str,
+    config: CircuitBreakerConfig,
+    inner: Mutex<BreakerState>,
+}
+
+/// Admission ticket for a call; releases the half-open trial slot if the call is dropped
+/// before its outcome is recorded.
+struct Permit<'a> {
+    breaker: &'a CircuitBreaker,
+    trial: bool,
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if self.trial {
+            self.breaker.lock().trial_in_flight = false;
+        }
+    }
+}
+
+impl CircuitBreaker {
+    /// Creates a closed circuit breaker.
+    ///
+    /// # Arguments
+    ///
+    /// * `upstream` - Upstream name used in logs and metrics
+    /// * `config` - Failure threshold, rolling window and cool-down
+    pub fn new(upstream: &'static str, config: CircuitBreakerConfig) -> Self {
+        metrics::CIRCUIT_BREAKER_STATE
+            .with_label_values(&[upstream])
+            .set(CircuitState::Closed.gauge_value());
+        Self {
+            upstream,
+            config,
+            inner: Mutex::new(BreakerState {
+                state: CircuitState::Closed,
+                failures: VecDeque::new(),
+                opened_at: None,
+                trial_in_flight: false,
+            }),
+        }
+    }
+
+    /// Returns the current state, reporting an open circuit whose cool-down elapsed as half-open.
+    pub fn state(&self) -> CircuitState {
+        let inner = self.lock();
+        match (inner.state, inner.opened_at) {
+            (CircuitState::Open, Some(at)) if at.elapsed() >= self.config.cool_down => {
+                CircuitState::HalfOpen
+            }
+            (state, _) => state,
+        }
+    }
+
+    /// Runs `call` through the breaker.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServiceUnavailable` without running `call` while the circuit is open,
+    /// otherwise the outcome of `call`.
+    pub async fn call<T, F, Fut>(&self, call: F) -> Result<T, HttpClientError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, HttpClientError>>,
+    {
+        let mut permit = self.acquire()?;
+        let result = call().await;
+        let trial = std::mem::take(&mut permit.trial);
+        self.record(result.as_ref().err(), trial);
+        result
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BreakerState> {
+        self.inner.lock().expect("circuit breaker lock poisoned")
+    }
+
+    fn acquire(&self) -> Result<Permit<'_>, HttpClientError> {
+        let mut inner = self.lock();
+        match inner.state {
+            CircuitState::Closed => Ok(Permit {
+                breaker: self,
+                trial: false,
+            }),
+            CircuitState::Open
+                if inner
+                    .opened_at
+                    .is_some_and(|at| at.elapsed() >= self.config.cool_down) =>
+            {
+                self.transition(&mut inner, CircuitState::HalfOpen);
+                inner.trial_in_flight = true;
+                Ok(Permit {
+                    breaker: self,
+                    trial: true,
+                })
+            }
+            CircuitState::HalfOpen if !inner.trial_in_flight => {
+                inner.trial_in_flight = true;
+                Ok(Permit {
+                    breaker: self,
+                    trial: true,
+                })
+            }
+            _ => {
+                metrics::CIRCUIT_BREAKER_REJECTIONS
+                    .with_label_values(&[self.upstream])
+                    .inc();
+                Err(ErrorKind::ServiceUnavailable.into())
+            }
+        }
+    }
+
+    /// Records the outcome of a call; only the trial call decides on a half-open circuit,
+    /// calls admitted before it opened may still complete.
+    fn record(&self, error: Option<&HttpClientError>, trial: bool) {
+        let mut inner = self.lock();
+        let now = Instant::now();
+        match (inner.state, error.is_some_and(is_failure)) {
+            (CircuitState::HalfOpen, _) if !trial => {}
+            (CircuitState::HalfOpen, false) => {
+                inner.trial_in_flight = false;
+                inner.failures.clear();
+                self.transition(&mut inner, CircuitState::Closed);
+            }
+            (CircuitState::HalfOpen, true) => {
+                inner.trial_in_flight = false;
+                inner.opened_at = Some(now);
+                self.transition(&mut inner, CircuitState::Open);
+            }
+            (CircuitState::Closed, true) => {
+                inner.failures.push_back(now);
+                while inner
+                    .failures
+                    .front()
+                    .is_some_and(|at| now.duration_since(*at) > self.config.window)
+                {
+                    inner.failures.pop_front();
+                }
+                if inner.failures.len() >= self.config.failure_threshold {
+                    inner.failures.clear();
+                    inner.opened_at = Some(now);
+                    self.transition(&mut inner, CircuitState::Open);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn transition(&self, inner: &mut BreakerState, state: CircuitState) {
+        match state {
+            CircuitState::Open => warn!(upstream = self.upstream, "Circuit breaker opened"),
+            _ => info!(upstream = self.upstream, state = ?state, "Circuit breaker state changed"),
+        }
+        inner.state = state;
+        metrics::CIRCUIT_BREAKER_STATE
+            .with_label_values(&[self.upstream])
+            .set(state.gauge_value());
+    }
+}
+
+/// Whether an error hints at an unhealthy upstream.
+fn is_failure(error: &HttpClientError) -> bool {
+    matches!(
+        error.kind(),
+        ErrorKind::RequestFailed
+            | ErrorKind::ServiceUnavailable
+            | ErrorKind::ServerError
+            | ErrorKind::Timeout
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(cool_down: Duration) -> CircuitBreaker {
+        CircuitBreaker::new(
+            "test",
+            CircuitBreakerConfig {
+                failure_threshold: 2,
+                window: Duration::from_secs(60),
+                cool_down,
+            },
+        )
+    }
+
+    async fn fail(breaker: &CircuitBreaker, kind: ErrorKind) -> Result<(), HttpClientError> {
+        breaker.call(|| async { Err::<(), _>(kind.into()) }).await
+    }
+
+    async fn succeed(breaker: &CircuitBreaker) -> Result<(), HttpClientError> {
+        breaker.call(|| async { Ok(()) }).await
+    }
+
+    #[tokio::test]
+    async fn opens_after_threshold_failures_and_fails_fast() {
+        let breaker = breaker(Duration::from_secs(60));
+
+        assert!(fail(&breaker, ErrorKind::ServerError).await.is_err());
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(fail(&breaker, ErrorKind::RequestFailed).await.is_err());
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        let called = std::cell::Cell::new(false);
+        let result = breaker
+            .call(|| async {
+                called.set(true);
+                Ok(())
+            })
+            .await;
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::ServiceUnavailable);
+        assert!(!called.get());
+    }
+
+    #[tokio::test]
+    async fn ignores_errors_not_caused_by_upstream_health() {
+        let breaker = breaker(Duration::from_secs(60));
+
+        for _ in 0..5 {
+            assert!(fail(&breaker, ErrorKind::NotFound).await.is_err());
+            assert!(fail(&breaker, ErrorKind::RateLimited).await.is_err());
+        }
+
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn forgets_failures_outside_the_window() {
+        let breaker = CircuitBreaker::new(
+            "test",
+            CircuitBreakerConfig {
+                failure_threshold: 2,
+                window: Duration::from_millis(10),
+                cool_down: Duration::from_secs(60),
+            },
+        );
+
+        assert!(fail(&breaker, ErrorKind::ServerError).await.is_err());
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(fail(&breaker, ErrorKind::ServerError).await.is_err());
+
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn closes_after_successful_trial() {
+        let breaker = breaker(Duration::from_millis(10));
+        for _ in 0..2 {
+            assert!(fail(&breaker, ErrorKind::ServerError).await.is_err());
+        }
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(succeed(&breaker).await.is_ok());
+
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn reopens_after_failed_trial() {
+        let breaker = breaker(Duration::from_millis(10));
+        for _ in 0..2 {
+            assert!(fail(&breaker, ErrorKind::ServerError).await.is_err());
+        }
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(fail(&breaker, ErrorKind::ServerError).await.is_err());
+
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert_eq!(
+            succeed(&breaker).await.unwrap_err().kind(),
+            &ErrorKind::ServiceUnavailable
+        );
+    }
+
+    #[tokio::test]
+    async fn lets_a_single_trial_through_when_half_open() {
+        let breaker = breaker(Duration::from_millis(10));
+        for _ in 0..2 {
+            assert!(fail(&breaker, ErrorKind::ServerError).await.is_err());
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let permit = breaker.acquire();
+        assert!(permit.is_ok());
+        assert!(
+            breaker
+                .acquire()
+                .is_err_and(|e| e.kind() == &ErrorKind::ServiceUnavailable)
+        );
+
+        // a dropped trial releases its slot
+        drop(permit);
+        assert!(breaker.acquire().is_ok());
+    }
+
+    #[tokio::test]
+    async fn ignores_calls_admitted_before_opening_when_half_open() {
+        let breaker = breaker(Duration::from_millis(10));
+        let late = breaker.acquire().unwrap();
+        for _ in 0..2 {
+            assert!(fail(&breaker, ErrorKind::ServerError).await.is_err());
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let trial = breaker.acquire().unwrap();
+
+        breaker.record(None, late.trial);
+
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.acquire().is_err());
+        // the next trial still decides
+        drop(trial);
+        assert!(succeed(&breaker).await.is_ok());
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
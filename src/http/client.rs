@@ -1,43 +1,427 @@
-use std::fmt::{self, Formatter};
+use serde::{Deserialize, de::DeserializeOwned};
+use std::{
+    borrow::Cow,
+    fmt::{self, Formatter},
+    sync::Arc,
+    time::Duration,
+};
+use tracing::{debug, warn};
 
-#[derive(Debug, PartialEq)]
-pub enum TranslatorType {
-    Shakespeare,
-    Yoda,
+/// Fun Translations style (e.g., `yoda`), naming its `/translate/{style}.json` endpoint.
+///
+/// Styles are lowercase identifiers made of ASCII letters, digits and `-`; the styles the
+/// API serves are listed in the [`TranslatorRegistry`].
+///
+/// [`TranslatorRegistry`]: crate::translator::registry::TranslatorRegistry
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(try_from = "String")]
+pub struct TranslatorType(Cow<'static, str>);
+
+impl TranslatorType {
+    pub const SHAKESPEARE: Self = Self(Cow::Borrowed("shakespeare"));
+    pub const YODA: Self = Self(Cow::Borrowed("yoda"));
+
+    /// Longest style identifier.
+    const MAX_LEN: usize = 64;
+
+    /// Style identifier (e.g., `yoda`).
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for TranslatorType {
+    type Error = String;
+
+    /// Validates a style identifier, case-insensitively.
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let style = value.trim().to_ascii_lowercase();
+        let valid = (1..=Self::MAX_LEN).contains(&style.len())
+            && style
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+            && !style.starts_with('-')
+            && !style.ends_with('-');
+        match valid {
+            true => Ok(Self(Cow::Owned(style))),
+            false => Err(format!(
+                "invalid translator style '{}' (expected lowercase letters, digits and '-')",
+                value
+            )),
+        }
+    }
 }
 
 impl fmt::Display for TranslatorType {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        match self {
-            TranslatorType::Shakespeare => write!(f, "shakespeare"),
-            TranslatorType::Yoda => write!(f, "yoda"),
-        }
+        f.write_str(&self.0)
     }
 }
 
-#[derive(Debug, PartialEq)]
-pub enum HttpClientError {
-    NotAcceptable,
+/// Longest response body excerpt kept in errors, in characters.
+const BODY_SNIPPET_LEN: usize = 256;
+
+/// Kind of an [`HttpClientError`], driving the HTTP status of the API response and the
+/// retry and circuit breaker decisions.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ErrorKind {
+    /// No description in an acceptable language, with the languages that are available
+    #[error("no description in the requested languages (available: {})", .0.join(", "))]
+    NotAcceptable(Vec<String>),
+    #[error("resource not found")]
     NotFound,
+    #[error("no description available")]
+    NoDescription,
+    #[error("rate limited by the server")]
     RateLimited,
+    #[error("request failed")]
     RequestFailed,
+    #[error("failed to parse response")]
     ParseError,
+    #[error("service unavailable")]
     ServiceUnavailable,
+    #[error("internal server error")]
     ServerError,
+    #[error("request timed out")]
+    Timeout,
 }
 
-impl std::fmt::Display for HttpClientError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            HttpClientError::NotAcceptable => write!(f, "not acceptable"),
-            HttpClientError::NotFound => write!(f, "resource not found"),
-            HttpClientError::RequestFailed => write!(f, "request failed"),
-            HttpClientError::ParseError => write!(f, "failed to parse response"),
-            HttpClientError::RateLimited => write!(f, "rate limited by the server"),
-            HttpClientError::ServiceUnavailable => write!(f, "service unavailable"),
-            HttpClientError::ServerError => write!(f, "internal server error"),
+impl ErrorKind {
+    /// Maps a transport error, telling timeouts apart from other request failures.
+    pub fn of_transport(error: &reqwest::Error) -> Self {
+        match error.is_timeout() {
+            true => ErrorKind::Timeout,
+            false => ErrorKind::RequestFailed,
         }
     }
+
+    /// Maps an error raised while reading a response body, telling timeouts apart from
+    /// malformed bodies.
+    pub fn of_body(error: &reqwest::Error) -> Self {
+        match error.is_timeout() {
+            true => ErrorKind::Timeout,
+            false => ErrorKind::ParseError,
+        }
+    }
+}
+
+/// Upstream request an error comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpstreamContext {
+    /// Upstream name (e.g., `pokeapi`)
+    pub upstream: &'static str,
+    /// Requested URL
+    pub url: String,
+    /// HTTP status of the response, if any was received
+    pub status: Option<u16>,
+    /// Beginning of the response body, if any was read
+    pub body: Option<String>,
 }
 
-impl std::error::Error for HttpClientError {}
+impl fmt::Display for UpstreamContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.upstream, self.url)?;
+        if let Some(status) = self.status {
+            write!(f, ", status {}", status)?;
+        }
+        if let Some(body) = &self.body {
+            write!(f, ", body {:?}", body)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error of an upstream request, or of the processing of its response.
+///
+/// Errors raised by the upstream clients carry the [`UpstreamContext`] of the failed
+/// request and the underlying reqwest or serde error as their source; errors raised
+/// locally (e.g., `NoDescription`) only have a kind.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("{kind}{}", .context.as_ref().map_or_else(String::new, |c| format!(" ({})", c)))]
+pub struct HttpClientError {
+    kind: ErrorKind,
+    context: Option<Box<UpstreamContext>>,
+    #[source]
+    source: Option<Arc<dyn std::error::Error + Send + Sync>>,
+}
+
+impl From<ErrorKind> for HttpClientError {
+    fn from(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            context: None,
+            source: None,
+        }
+    }
+}
+
+impl HttpClientError {
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    pub fn context(&self) -> Option<&UpstreamContext> {
+        self.context.as_deref()
+    }
+
+    /// Maps a transport error of a request to `upstream`.
+    pub fn from_transport(upstream: &'static str, error: reqwest::Error) -> Self {
+        let url = error.url().map(ToString::to_string).unwrap_or_default();
+        Self::upstream(ErrorKind::of_transport(&error), upstream, url)
+            .with_source(error)
+            .traced()
+    }
+
+    /// Maps an error response of `upstream` to `kind`, keeping the beginning of its body.
+    pub async fn from_response(
+        upstream: &'static str,
+        kind: ErrorKind,
+        response: reqwest::Response,
+    ) -> Self {
+        let url = response.url().to_string();
+        let status = response.status().as_u16();
+        let body = response.text().await.ok();
+        Self {
+            context: Some(Box::new(UpstreamContext {
+                upstream,
+                url,
+                status: Some(status),
+                body: body.as_deref().map(snippet),
+            })),
+            ..kind.into()
+        }
+        .traced()
+    }
+
+    /// Reads and deserializes the JSON body of a successful `upstream` response.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Timeout` if the body is not received in time, `ParseError` if it cannot
+    /// be read or is not the expected JSON document (keeping its beginning).
+    pub async fn read_json<T: DeserializeOwned>(
+        upstream: &'static str,
+        response: reqwest::Response,
+    ) -> Result<T, Self> {
+        let url = response.url().to_string();
+        let status = Some(response.status().as_u16());
+        let bytes = match response.bytes().await {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                let mut error = Self::upstream(ErrorKind::of_body(&e), upstream, url);
+                if let Some(context) = error.context.as_mut() {
+                    context.status = status;
+                }
+                return Err(error.with_source(e).traced());
+            }
+        };
+        serde_json::from_slice(&bytes).map_err(|e| {
+            Self {
+                context: Some(Box::new(UpstreamContext {
+                    upstream,
+                    url,
+                    status,
+                    body: Some(snippet(&String::from_utf8_lossy(&bytes))),
+                })),
+                ..ErrorKind::ParseError.into()
+            }
+            .with_source(e)
+            .traced()
+        })
+    }
+
+    fn upstream(kind: ErrorKind, upstream: &'static str, url: String) -> Self {
+        Self {
+            context: Some(Box::new(UpstreamContext {
+                upstream,
+                url,
+                status: None,
+                body: None,
+            })),
+            ..kind.into()
+        }
+    }
+
+    fn with_source(mut self, source: impl std::error::Error + Send + Sync + 'static) -> Self {
+        self.source = Some(Arc::new(source));
+        self
+    }
+
+    /// Logs the failure with its context as structured fields.
+    fn traced(self) -> Self {
+        if let Some(context) = &self.context {
+            let source = self.source.as_ref().map(ToString::to_string);
+            match self.kind {
+                ErrorKind::NotFound => debug!(
+                    upstream = context.upstream,
+                    url = %context.url,
+                    status = context.status,
+                    "Upstream resource not found"
+                ),
+                _ => warn!(
+                    upstream = context.upstream,
+                    url = %context.url,
+                    status = context.status,
+                    body = context.body.as_deref(),
+                    kind = %self.kind,
+                    source = source.as_deref(),
+                    "Upstream request failed"
+                ),
+            }
+        }
+        self
+    }
+}
+
+/// Truncates a response body to [`BODY_SNIPPET_LEN`] characters.
+fn snippet(body: &str) -> String {
+    match body.char_indices().nth(BODY_SNIPPET_LEN) {
+        Some((end, _)) => format!("{}...", &body[..end]),
+        None => body.to_string(),
+    }
+}
+
+/// Connection settings of the HTTP client used for a single upstream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpstreamClientConfig {
+    /// Maximum time to establish a connection
+    pub connect_timeout: Duration,
+    /// Maximum time for a whole request, from sending it to reading the response body
+    pub request_timeout: Duration,
+    /// How long idle pooled connections are kept open
+    pub pool_idle_timeout: Duration,
+    /// Maximum number of idle pooled connections per host
+    pub pool_max_idle_per_host: usize,
+    /// Whether to speak HTTP/2 without negotiating it first
+    pub http2_prior_knowledge: bool,
+}
+
+impl UpstreamClientConfig {
+    /// Builds a [`reqwest::Client`] honoring these settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the TLS backend cannot be initialized.
+    pub fn build(&self) -> reqwest::Result<reqwest::Client> {
+        let builder = reqwest::Client::builder()
+            .connect_timeout(self.connect_timeout)
+            .timeout(self.request_timeout)
+            .pool_idle_timeout(self.pool_idle_timeout)
+            .pool_max_idle_per_host(self.pool_max_idle_per_host);
+        match self.http2_prior_knowledge {
+            true => builder.http2_prior_knowledge(),
+            false => builder,
+        }
+        .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client_config(request_timeout: Duration) -> UpstreamClientConfig {
+        UpstreamClientConfig {
+            connect_timeout: Duration::from_secs(1),
+            request_timeout,
+            pool_idle_timeout: Duration::from_secs(90),
+            pool_max_idle_per_host: 8,
+            http2_prior_knowledge: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn maps_request_timeouts_to_timeout() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        // accept connections without ever answering
+        let server = tokio::spawn(async move {
+            let mut connections = vec![];
+            while let Ok((socket, _)) = listener.accept().await {
+                connections.push(socket);
+            }
+        });
+        let client = client_config(Duration::from_millis(50)).build().unwrap();
+
+        let error = client
+            .get(format!("http://{address}/slow"))
+            .send()
+            .await
+            .unwrap_err();
+
+        let error = HttpClientError::from_transport("test", error);
+        assert_eq!(error.kind(), &ErrorKind::Timeout);
+        assert_eq!(
+            error.context().unwrap().url,
+            format!("http://{address}/slow")
+        );
+        assert!(std::error::Error::source(&error).is_some());
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn maps_connection_failures_to_request_failed() {
+        let client = client_config(Duration::from_secs(1)).build().unwrap();
+
+        // nothing listens on the discard port
+        let error = client
+            .get("http://127.0.0.1:9/resource")
+            .send()
+            .await
+            .unwrap_err();
+
+        assert_eq!(ErrorKind::of_transport(&error), ErrorKind::RequestFailed);
+    }
+
+    #[tokio::test]
+    async fn keeps_the_status_and_body_of_error_responses() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/resource")
+            .with_status(503)
+            .with_body("x".repeat(300))
+            .create_async()
+            .await;
+        let response = reqwest::get(format!("{}/resource", server.url()))
+            .await
+            .unwrap();
+
+        let error =
+            HttpClientError::from_response("test", ErrorKind::ServiceUnavailable, response).await;
+
+        let context = error.context().unwrap();
+        assert_eq!(context.upstream, "test");
+        assert_eq!(context.status, Some(503));
+        assert_eq!(
+            context.body.as_deref(),
+            Some(format!("{}...", "x".repeat(BODY_SNIPPET_LEN)).as_str())
+        );
+        assert!(error.to_string().starts_with("service unavailable (test "));
+    }
+
+    #[tokio::test]
+    async fn keeps_the_serde_error_of_malformed_bodies() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/resource")
+            .with_body(r#"{"name": 42}"#)
+            .create_async()
+            .await;
+        let response = reqwest::get(format!("{}/resource", server.url()))
+            .await
+            .unwrap();
+
+        let error = HttpClientError::read_json::<Vec<String>>("test", response)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.kind(), &ErrorKind::ParseError);
+        assert_eq!(error.context().unwrap().status, Some(200));
+        assert_eq!(
+            error.context().unwrap().body.as_deref(),
+            Some(r#"{"name": 42}"#)
+        );
+        let source = std::error::Error::source(&error).unwrap();
+        assert!(source.to_string().contains("invalid type"));
+    }
+}
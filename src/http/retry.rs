@@ -0,0 +1,359 @@
+//! # Upstream Retry Policy
+//!
+//! Reusable retry policy for upstream HTTP calls:
+//! - Exponential backoff (`base_delay * 2^(attempt - 1)`, capped at `max_delay`)
+//! - Optional full jitter, spreading retries of concurrent requests over time
+//! - Configurable set of retryable [`ErrorKind`]s
+//! - `Retry-After` honored on 429 and 503 responses (delta-seconds or HTTP-date);
+//!   a server asking to wait longer than `max_delay` is not retried at all
+//!
+//! Every retry is logged as a tracing event and counted in the
+//! `upstream_retries_total` metric, labelled by upstream.
+
+use crate::{
+    http::client::{ErrorKind, HttpClientError},
+    metrics,
+};
+use reqwest::{RequestBuilder, Response, StatusCode, header::RETRY_AFTER};
+use std::time::{Duration, SystemTime};
+use tracing::warn;
+
+/// Retry policy applied to upstream HTTP requests.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one
+    pub max_attempts: usize,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Upper bound of any delay between attempts
+    pub max_delay: Duration,
+    /// Whether to randomize delays between zero and the computed backoff
+    pub jitter: bool,
+    /// Errors worth another attempt
+    pub retryable: Vec<ErrorKind>,
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+            jitter: false,
+            retryable: vec![],
+        }
+    }
+
+    /// Sends the request built by `request`, retrying according to the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `upstream` - Upstream name used in logs and metrics (e.g., `pokeapi`)
+    /// * `request` - Builds the request for each attempt
+    ///
+    /// # Returns
+    ///
+    /// Returns the last response received, so that callers can keep mapping status codes
+    /// to [`ErrorKind`]s as usual.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Timeout` if the last attempt timed out, `RequestFailed` if it failed
+    /// otherwise at transport level.
+    pub async fn send<F>(
+        &self,
+        upstream: &'static str,
+        request: F,
+    ) -> Result<Response, HttpClientError>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        let mut attempt = 1;
+        loop {
+            let outcome = request().send().await;
+            let (error, retry_after) = match &outcome {
+                Ok(response) => match status_error(response.status()) {
+                    Some(error) => (error, retry_after(response)),
+                    None => {
+                        break outcome.map_err(|e| HttpClientError::from_transport(upstream, e));
+                    }
+                },
+                Err(e) => (ErrorKind::of_transport(e), None),
+            };
+            let Some(delay) = self.next_delay(attempt, &error, retry_after) else {
+                break outcome.map_err(|e| HttpClientError::from_transport(upstream, e));
+            };
+            warn!(
+                upstream,
+                attempt,
+                max_attempts = self.max_attempts,
+                delay_ms = delay.as_millis() as u64,
+                reason = %error,
+                "Retrying upstream request"
+            );
+            metrics::UPSTREAM_RETRIES
+                .with_label_values(&[upstream])
+                .inc();
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Returns the delay before the next attempt, or `None` if the request must not be retried.
+    fn next_delay(
+        &self,
+        attempt: usize,
+        error: &ErrorKind,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.retryable.contains(error) {
+            return None;
+        }
+        match retry_after {
+            Some(delay) if delay > self.max_delay => None,
+            Some(delay) => Some(delay),
+            None => Some(self.backoff(attempt)),
+        }
+    }
+
+    /// Computes the exponential backoff after the given (1-based) attempt.
+    fn backoff(&self, attempt: usize) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31) as u32;
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(exponent))
+            .min(self.max_delay);
+        match self.jitter {
+            true => delay.mul_f64(fastrand::f64()),
+            false => delay,
+        }
+    }
+}
+
+/// Maps upstream status codes to the errors the retry policy reasons about.
+fn status_error(status: StatusCode) -> Option<ErrorKind> {
+    match status {
+        StatusCode::TOO_MANY_REQUESTS => Some(ErrorKind::RateLimited),
+        StatusCode::SERVICE_UNAVAILABLE => Some(ErrorKind::ServiceUnavailable),
+        s if s.is_server_error() => Some(ErrorKind::ServerError),
+        _ => None,
+    }
+}
+
+/// Reads the `Retry-After` header of 429 and 503 responses.
+fn retry_after(response: &Response) -> Option<Duration> {
+    match response.status() {
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE => response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after),
+        _ => None,
+    }
+}
+
+/// Parses a `Retry-After` value, either delta-seconds or an HTTP-date (RFC 9110).
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    value
+        .parse::<u64>()
+        .map(Duration::from_secs)
+        .ok()
+        .or_else(|| {
+            httpdate::parse_http_date(value)
+                .ok()
+                .map(|date| date.duration_since(SystemTime::now()).unwrap_or_default())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(max_attempts: usize) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(10),
+            jitter: false,
+            retryable: vec![
+                ErrorKind::RequestFailed,
+                ErrorKind::RateLimited,
+                ErrorKind::ServiceUnavailable,
+                ErrorKind::ServerError,
+                ErrorKind::Timeout,
+            ],
+        }
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_max_delay() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+            ..policy(10)
+        };
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(5), Duration::from_millis(1000));
+        assert_eq!(policy.backoff(64), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn backoff_with_jitter_stays_within_bounds() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+            jitter: true,
+            ..policy(10)
+        };
+
+        for attempt in 1..10 {
+            assert!(policy.backoff(attempt) <= Duration::from_millis(1000));
+        }
+    }
+
+    #[test]
+    fn next_delay_stops_at_max_attempts_and_non_retryable_errors() {
+        let policy = policy(3);
+
+        assert!(
+            policy
+                .next_delay(1, &ErrorKind::ServerError, None)
+                .is_some()
+        );
+        assert!(
+            policy
+                .next_delay(3, &ErrorKind::ServerError, None)
+                .is_none()
+        );
+        assert!(policy.next_delay(1, &ErrorKind::NotFound, None).is_none());
+    }
+
+    #[test]
+    fn next_delay_honors_retry_after_within_max_delay() {
+        let policy = policy(3);
+
+        assert_eq!(
+            policy.next_delay(1, &ErrorKind::RateLimited, Some(Duration::from_millis(5))),
+            Some(Duration::from_millis(5))
+        );
+        assert_eq!(
+            policy.next_delay(1, &ErrorKind::RateLimited, Some(Duration::from_secs(3600))),
+            None
+        );
+    }
+
+    #[test]
+    fn parses_retry_after_values() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_retry_after(" 0 "), Some(Duration::ZERO));
+        // dates in the past mean "retry now"
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        let future = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(60));
+        assert!(parse_retry_after(&future).is_some_and(|d| d > Duration::from_secs(50)));
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+
+    #[tokio::test]
+    async fn retries_until_success() {
+        let mut server = mockito::Server::new_async().await;
+        let unavailable = server
+            .mock("GET", "/resource")
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("GET", "/resource")
+            .with_status(200)
+            .expect(1)
+            .create_async()
+            .await;
+        let client = reqwest::Client::new();
+        let url = format!("{}/resource", server.url());
+
+        let response = policy(3).send("test", || client.get(&url)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        unavailable.assert_async().await;
+        ok.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn returns_last_response_when_attempts_are_exhausted() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/resource")
+            .with_status(500)
+            .expect(3)
+            .create_async()
+            .await;
+        let client = reqwest::Client::new();
+        let url = format!("{}/resource", server.url());
+
+        let response = policy(3).send("test", || client.get(&url)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_non_retryable_responses() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/resource")
+            .with_status(429)
+            .expect(1)
+            .create_async()
+            .await;
+        let client = reqwest::Client::new();
+        let url = format!("{}/resource", server.url());
+        let policy = RetryPolicy {
+            retryable: vec![ErrorKind::ServiceUnavailable],
+            ..policy(3)
+        };
+
+        let response = policy.send("test", || client.get(&url)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_when_retry_after_exceeds_max_delay() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/resource")
+            .with_status(429)
+            .with_header("retry-after", "3600")
+            .expect(1)
+            .create_async()
+            .await;
+        let client = reqwest::Client::new();
+        let url = format!("{}/resource", server.url());
+
+        let response = policy(3).send("test", || client.get(&url)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn returns_request_failed_on_transport_errors() {
+        let client = reqwest::Client::new();
+
+        // nothing listens on the discard port
+        let result = policy(2)
+            .send("test", || client.get("http://127.0.0.1:9/resource"))
+            .await;
+
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::RequestFailed);
+    }
+}
@@ -5,18 +5,58 @@
 //!
 //! ## Features
 //!
-//! - **Content Negotiation**: Supports HTTP `Accept-Language` header for multi-language descriptions
+//! - **Content Negotiation**: Supports HTTP `Accept-Language` header for multi-language descriptions,
+//!   with BCP 47 matching and configurable fallback chains
+//! - **Listing and Search**: Paginated Pokémon names with prefix, substring and fuzzy search
+//! - **Problem Details**: RFC 7807 error bodies with the request ID and, for 406, the available languages
+//! - **Name Suggestions**: 404 problem details suggesting the closest names of unknown Pokémon
+//! - **Batch Lookups**: Several Pokémon resolved concurrently in a single request
+//! - **Sparse Fieldsets**: `fields=` query parameter selecting the returned Pokémon fields
+//! - **Description Normalization**: Cleaned up flavor texts, with `raw=true` to opt out
+//! - **Description Versions**: Deterministic game version of the descriptions, or `version=`
+//! - **Description History**: Every description of a Pokémon, by language and game version
+//! - **Evolution Chains**: Evolution stages and triggers, with optionally translated descriptions
+//! - **Translator Rules**: Configurable rules selecting the translator of each Pokémon
+//! - **Translator Styles**: Configurable Fun Translations styles, requested with `style=`
+//! - **Local Translation Fallback**: Built-in Yoda and Shakespeare translators used when Fun Translations fails
+//! - **Translation Status**: `X-Translation-Status` header and JSON bodies telling translations from fallbacks
 //! - **OpenAPI Integration**: Auto-generated API documentation with Swagger UI
 //! - **Prometheus Metrics**: Built-in metrics endpoint for monitoring
 //! - **Distributed Tracing**: Structured logging with tracing spans for observability
 //! - **Health Checks**: Dedicated `/health` endpoint for service availability checks
+//! - **Readiness Checks**: `/ready` endpoint probing upstream dependencies
+//! - **Graceful Shutdown**: In-flight requests are drained on SIGTERM/SIGINT
+//! - **Rate Limiting**: Per client token buckets on the Pokémon endpoints
+//! - **Authentication**: Scoped API keys (`Authorization: Bearer` or `X-Api-Key`)
 //!
 //! ## Architecture
 //!
 //! The application uses a layered architecture:
 //! - **HTTP Layer** (`http::client`): HTTP client wrapper for external APIs
+//! - **Retry Layer** (`http::retry`): Exponential backoff with jitter for upstream requests
+//! - **Circuit Breaker Layer** (`http::circuit_breaker`): Fails fast while an upstream is unhealthy
 //! - **Pokemon API Layer** (`pokemon_api::client`): PokéAPI integration with language negotiation
+//! - **Cache Layer** (`pokemon_api::cache`): In-process TTL cache for PokéAPI responses
+//! - **Evolution Layer** (`pokemon_api::evolution`): Flattening of PokéAPI evolution chains
+//! - **Descriptions Layer** (`pokemon_api::descriptions`): Descriptions by language and game version
+//! - **Language Layer** (`pokemon_api::language`): BCP 47 matching and fallback chains of languages
+//! - **Text Layer** (`pokemon_api::text`): Normalization of PokéAPI flavor texts
+//! - **Version Layer** (`pokemon_api::version`): Game version of the returned descriptions
+//! - **Name Index Layer** (`pokemon_api::index`): Periodically refreshed index of the Pokémon names
+//! - **Problem Layer** (`problem`): RFC 7807 problem details of error responses
+//! - **Request ID Layer** (`request_id`): `X-Request-Id` assignment and propagation
+//! - **Batch Layer** (`batch`): Concurrent lookup of several Pokémon with per item status
 //! - **Translator Layer** (`translator::client`): Fun Translations API integration
+//! - **Local Translator Layer** (`translator::local`): Built-in Yoda and Shakespeare translators
+//! - **Translation Fallback Layer** (`translator::fallback`): Local translation when Fun Translations fails
+//! - **Translation Status Layer** (`translator::status`): Outcome of the translation of descriptions
+//! - **Translator Rules Layer** (`translator::rules`): Rule based selection of the translator
+//! - **Translator Registry Layer** (`translator::registry`): Translator styles served by the API
+//! - **Translation Cache Layer** (`translator::cache`): Persistent (SQLite) cache of translated texts
+//! - **Readiness Layer** (`readiness`): Dependency probes backing the `/ready` endpoint
+//! - **Authentication Layer** (`auth`): API key validation and route scopes
+//! - **Rate Limiting Layer** (`rate_limit`): Inbound token-bucket rate limiting per client
+//! - **Shutdown Layer** (`shutdown`): Signal handling and connection draining
 //! - **Metrics Layer** (`metrics`): Prometheus metrics collection
 //! - **Configuration Layer** (`config`): CLI/env configuration management
 //!
@@ -25,60 +65,105 @@
 //! 1. Client sends request to `/pokemon/{name}` with optional `Accept-Language` header
 //! 2. Handler creates a tracing span for request tracking
 //! 3. Pokemon API client fetches base data and species information
-//! 4. Language negotiation selects best available language
-//! 5. Description is returned with `Content-Language` header
+//! 4. Language negotiation selects best available language, falling back on the configured chains
+//! 5. Description is returned with `Content-Language` and `X-Language-Fallback` headers
 //! 6. Metrics are incremented for monitoring
 
-use accept_language::parse;
 use axum::{
-    extract::{Path, State},
+    extract::{
+        Path, Query, State,
+        rejection::{JsonRejection, QueryRejection},
+    },
     http::StatusCode,
     middleware,
     response::{AppendHeaders, IntoResponse, Json, Response},
 };
-use hyper::{HeaderMap, header::CONTENT_LANGUAGE};
+use futures::future::join_all;
+use hyper::{
+    HeaderMap,
+    header::{ACCEPT, CONTENT_LANGUAGE, LINK},
+};
 use std::{process::exit, sync::Arc};
-use tracing::{debug, info, warn};
-use utoipa::OpenApi;
+use tracing::{Instrument, debug, info, warn};
+use utoipa::{
+    Modify, OpenApi,
+    openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme},
+};
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
+mod auth;
+mod batch;
 mod config;
 mod constants;
 mod http;
 mod metrics;
 mod pokemon_api;
+mod problem;
+mod rate_limit;
+mod readiness;
+mod request_id;
+mod shutdown;
 mod translator;
 
-use pokemon_api::client::{
-    PokeApiClient, Pokemon, PokemonApi, PokemonApiProxy, PokemonApiProxyClient,
+use batch::{BatchItem, BatchItemStatus, BatchRequest, BatchResponse};
+use pokemon_api::{
+    cache::CachingPokemonApiProxy,
+    circuit_breaker::CircuitBreakingPokemonApiProxy,
+    client::{
+        DescriptionOptions, PokeApiClient, Pokemon, PokemonAbility, PokemonApi, PokemonApiProxy,
+        PokemonApiProxyClient, PokemonSprites, PokemonStat, PokemonType,
+    },
+    descriptions::{DescriptionEntry, LanguageDescriptions, PokemonDescriptions},
+    evolution::{Evolution, EvolutionStage, EvolutionTrigger},
+    fields::FieldSelection,
+    index::{DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT, NameIndex, PokemonListItem, PokemonPage},
+    language::{LANGUAGE_FALLBACK_HEADER, LanguageFallbacks, LanguagePreferences},
+    text::TextNormalizer,
+    version::VersionFilter,
+};
+use problem::{Problem, ProblemType};
+use readiness::{
+    Dependency, DependencyReport, DependencyStatus, Readiness, ReadinessReport, ReadinessStatus,
+};
+use translator::{
+    cache::{CachingTranslator, TranslationStore},
+    circuit_breaker::CircuitBreakingTranslator,
+    client::{FunTranslator, TRANSLATION_ENGINE_HEADER, Translator, UNTRANSLATED_ENGINE},
+    fallback::FallbackTranslator,
+    local::LocalTranslator,
+    registry::{TranslatorInfo, TranslatorList, TranslatorRegistry},
+    rules::{RuleSubject, TRANSLATOR_RULE_HEADER, TranslatorRules},
+    status::{TRANSLATION_STATUS_HEADER, TranslatedDescription, TranslationStatus, prefers_json},
 };
-use translator::client::{FunTranslator, Translator};
 
-use crate::{config::ConfigDescriptor, constants::DEFAULT_LANGUAGE, http::client::HttpClientError};
+use crate::{
+    config::ConfigDescriptor,
+    constants::{
+        BATCH_ROUTE, DEFAULT_LANGUAGE, DESCRIPTIONS_ROUTE, EVOLUTION_ROUTE,
+        FUN_TRANSLATIONS_UPSTREAM, LIST_ROUTE, POKEAPI_UPSTREAM, POKEMON_ROUTE, TRANSLATION_ROUTE,
+    },
+    http::{
+        circuit_breaker::{CircuitBreaker, CircuitState},
+        client::{ErrorKind, HttpClientError},
+    },
+};
 
 /// Extension trait for parsing `Accept-Language` HTTP headers with quality values.
 ///
-/// Supports RFC 7231 language tags with quality preferences and wildcard matching.
-/// Example: `"es;q=0.9,en;q=0.8,*"` returns `(["es", "en"], true)`
+/// Supports RFC 7231 language tags with quality preferences, exclusions and wildcard matching.
+/// Example: `"es;q=0.9,en;q=0.8,fr;q=0,*"` returns the ranges `["es", "en"]`, excluding `fr`,
+/// with the wildcard.
 trait AcceptLanguageExt {
-    /// Parses the `Accept-Language` header into a tuple of (languages, has_wildcard).
+    /// Parses the `Accept-Language` header into [`LanguagePreferences`].
     ///
-    /// Returns empty language list with wildcard=true if no header is present.
-    fn parse_accept_language(&self) -> (Vec<String>, bool);
+    /// Returns an empty range list with the wildcard if no header is present.
+    fn parse_accept_language(&self) -> LanguagePreferences;
 }
 
 impl AcceptLanguageExt for HeaderMap {
-    fn parse_accept_language(&self) -> (Vec<String>, bool) {
-        self.get("accept-language")
-            .and_then(|h| h.to_str().ok())
-            .map(|header_value| {
-                let langs = parse(header_value);
-                let has_wildcard: bool = langs.iter().any(|l| l == "*");
-                let filtered_langs: Vec<String> = langs.into_iter().filter(|l| l != "*").collect();
-                (filtered_langs, has_wildcard)
-            })
-            .unwrap_or_else(|| (vec![], true))
+    fn parse_accept_language(&self) -> LanguagePreferences {
+        LanguagePreferences::parse(self.get("accept-language").and_then(|h| h.to_str().ok()))
     }
 }
 
@@ -88,16 +173,52 @@ impl AcceptLanguageExt for HeaderMap {
 #[derive(OpenApi)]
 #[openapi(
     paths(
+        list_pokemon,
         get_pokemon,
+        get_pokemon_batch,
         get_pokemon_translation,
+        get_pokemon_evolution,
+        get_pokemon_descriptions,
+        list_translators,
         health,
+        ready,
         metrics_endpoint
     ),
     components(
-        schemas(Pokemon)
+        schemas(
+            Pokemon,
+            PokemonType,
+            PokemonAbility,
+            PokemonStat,
+            PokemonSprites,
+            PokemonPage,
+            PokemonListItem,
+            BatchRequest,
+            BatchResponse,
+            BatchItem,
+            BatchItemStatus,
+            Evolution,
+            EvolutionStage,
+            EvolutionTrigger,
+            PokemonDescriptions,
+            LanguageDescriptions,
+            DescriptionEntry,
+            TranslatorList,
+            TranslatorInfo,
+            TranslatedDescription,
+            TranslationStatus,
+            Problem,
+            ReadinessReport,
+            DependencyReport,
+            DependencyStatus,
+            ReadinessStatus,
+            CircuitState
+        )
     ),
+    modifiers(&SecurityAddon),
     tags(
         (name = "pokemon", description = "Pokemon API endpoints"),
+        (name = "translators", description = "Translation styles"),
         (name = "system", description = "Service health and metrics endpoints")
     ),
     info(
@@ -107,29 +228,54 @@ impl AcceptLanguageExt for HeaderMap {
 )]
 struct ApiDoc;
 
+/// Declares the API key security schemes (see [`auth`]).
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new(auth::API_KEY_HEADER))),
+        );
+        components.add_security_scheme(
+            "bearer",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+    }
+}
+
 /// Application state containing shared dependencies.
 ///
 /// This is passed to all request handlers and contains:
 /// - `pokemon_api`: Client for fetching Pokémon data with language negotiation
 /// - `fun_translator`: Client for translating descriptions via Fun Translations API
+/// - `readiness`: Readiness reporter probing the upstream dependencies
+/// - `name_index`: Index of the Pokémon names backing the listing and search
+/// - `translator_rules`: Rules selecting the translator of each Pokémon
+/// - `translator_registry`: Translator styles served by the API
+/// - `batch_max_size` / `batch_concurrency`: Limits of the batch lookups
 #[derive(Clone)]
 struct AppState {
     pokemon_api: std::sync::Arc<dyn PokemonApi>,
     fun_translator: std::sync::Arc<dyn Translator>,
+    readiness: std::sync::Arc<Readiness>,
+    name_index: std::sync::Arc<NameIndex>,
+    translator_rules: std::sync::Arc<TranslatorRules>,
+    translator_registry: std::sync::Arc<TranslatorRegistry>,
+    batch_max_size: usize,
+    batch_concurrency: usize,
 }
 
 /// HTTP response enum supporting multiple content types and language headers.
 ///
 /// Variants:
 /// - `Success(lang, T)`: 200 OK with Content-Language header
-/// - `NotFound`: 404 Not Found
-/// - `InternalError`: 500 Internal Server Error
+/// - `Problem(problem)`: RFC 7807 problem details, with the status of the problem type
+///   (see [`ProblemType`])
 enum HttpResponse<T> {
     Success(String, T),
-    NotFound,
-    NotAcceptable,
-    InternalError,
-    ServiceUnavailable,
+    Problem(Problem),
 }
 
 struct JsonResponse<T>(T);
@@ -145,10 +291,7 @@ impl<T: serde::Serialize> IntoResponse for HttpResponse<JsonResponse<T>> {
                 Json(data),
             )
                 .into_response(),
-            HttpResponse::NotFound => StatusCode::NOT_FOUND.into_response(),
-            HttpResponse::NotAcceptable => StatusCode::NOT_ACCEPTABLE.into_response(),
-            HttpResponse::InternalError => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
-            HttpResponse::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE.into_response(),
+            HttpResponse::Problem(problem) => problem.into_response(),
         }
     }
 }
@@ -164,23 +307,20 @@ impl IntoResponse for HttpResponse<String> {
                 data,
             )
                 .into_response(),
-            HttpResponse::NotFound => StatusCode::NOT_FOUND.into_response(),
-            HttpResponse::NotAcceptable => StatusCode::NOT_ACCEPTABLE.into_response(),
-            HttpResponse::InternalError => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
-            HttpResponse::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE.into_response(),
+            HttpResponse::Problem(problem) => problem.into_response(),
         }
     }
 }
 
 impl<T> From<HttpClientError> for HttpResponse<T> {
     fn from(error: HttpClientError) -> Self {
-        match error {
-            HttpClientError::NotFound => HttpResponse::NotFound,
-            HttpClientError::NotAcceptable => HttpResponse::NotAcceptable,
-            HttpClientError::RateLimited => HttpResponse::ServiceUnavailable,
-            HttpClientError::ServiceUnavailable => HttpResponse::ServiceUnavailable,
-            _ => HttpResponse::InternalError,
-        }
+        HttpResponse::Problem(error.into())
+    }
+}
+
+impl<T> From<Problem> for HttpResponse<T> {
+    fn from(problem: Problem) -> Self {
+        HttpResponse::Problem(problem)
     }
 }
 
@@ -222,28 +362,150 @@ async fn main() -> anyhow::Result<()> {
 
     metrics::init();
 
-    // Create a shared HTTP client for all external API calls
-    // This enables connection pooling and reduces resource usage
-    let http_client = reqwest::Client::new();
+    // Create one HTTP client per upstream, each with its own timeouts and connection pool
+    let pokeapi_http_client = config.pokeapi_client_config().build()?;
+    let fun_translations_http_client = config.fun_translations_client_config().build()?;
+
+    let pokeapi_breaker = Arc::new(CircuitBreaker::new(
+        POKEAPI_UPSTREAM,
+        config.circuit_breaker_config(),
+    ));
+    let fun_translations_breaker = Arc::new(CircuitBreaker::new(
+        FUN_TRANSLATIONS_UPSTREAM,
+        config.circuit_breaker_config(),
+    ));
 
-    let pokeapi_base_client = Box::new(PokemonApiProxyClient::new(
-        http_client.clone(),
-        config.pokeapi_base_url(),
+    let pokeapi_base_client = Box::new(CachingPokemonApiProxy::new(
+        Box::new(CircuitBreakingPokemonApiProxy::new(
+            Box::new(
+                PokemonApiProxyClient::new(pokeapi_http_client, config.pokeapi_base_url())
+                    .with_retry_policy(config.retry_policy()),
+            ),
+            pokeapi_breaker.clone(),
+        )),
+        config.pokeapi_cache_ttl,
+        config.pokeapi_cache_max_entries,
     )) as Box<dyn PokemonApiProxy + Send + Sync>;
-    let pokemon_api = Arc::new(PokeApiClient::new(pokeapi_base_client)) as Arc<dyn PokemonApi>;
-    let fun_translator = Arc::new(FunTranslator::new(
-        http_client.clone(),
-        config.fun_translations_base_url(),
-    )) as Arc<dyn Translator>;
+    let pokemon_api = Arc::new(
+        PokeApiClient::new(pokeapi_base_client)
+            .with_text_normalizer(TextNormalizer::new(config.description_casing_fixes.clone()))
+            .with_version_policy(config.flavor_text_version_policy.clone())
+            .with_language_map(config.pokeapi_language_map.clone())
+            .with_language_fallbacks(LanguageFallbacks::new(
+                config.language_fallback_chains.clone(),
+                &config.default_language,
+            )),
+    ) as Arc<dyn PokemonApi>;
+    let translation_store = Arc::new(TranslationStore::open(
+        &config.translation_cache_path,
+        config.translation_cache_max_entries,
+    )?);
+    info!(
+        path = %config.translation_cache_path.display(),
+        "Opened translation cache"
+    );
+    let fun_translator = Box::new(CachingTranslator::new(
+        Box::new(CircuitBreakingTranslator::new(
+            Box::new(
+                FunTranslator::new(
+                    fun_translations_http_client,
+                    config.fun_translations_base_url(),
+                )
+                .with_retry_policy(config.retry_policy()),
+            ),
+            fun_translations_breaker.clone(),
+        )),
+        translation_store.clone(),
+    )) as Box<dyn Translator>;
+    let fun_translator = match config.translation_local_fallback {
+        true => Arc::new(FallbackTranslator::new(
+            fun_translator,
+            Box::new(LocalTranslator::new()),
+        )) as Arc<dyn Translator>,
+        false => Arc::from(fun_translator),
+    };
+    let readiness = Arc::new(Readiness::new(
+        vec![
+            Dependency::new(
+                POKEAPI_UPSTREAM,
+                config.is_required_dependency(POKEAPI_UPSTREAM),
+                Box::new(pokemon_api.clone()),
+                pokeapi_breaker,
+            ),
+            Dependency::new(
+                FUN_TRANSLATIONS_UPSTREAM,
+                config.is_required_dependency(FUN_TRANSLATIONS_UPSTREAM),
+                Box::new(fun_translator.clone()),
+                fun_translations_breaker,
+            ),
+        ],
+        config.readiness_cache_ttl,
+        config.readiness_probe_timeout,
+    ));
+    let name_index = Arc::new(NameIndex::new(pokemon_api.clone()));
+    tokio::spawn(
+        name_index
+            .clone()
+            .run(config.pokemon_index_refresh_interval),
+    );
+    let translator_registry = Arc::new(TranslatorRegistry::new(config.translator_styles.clone()));
+    let translator_rules = Arc::new(match &config.translator_rules_file {
+        Some(path) => TranslatorRules::load(path)?,
+        None => TranslatorRules::default(),
+    });
+    translator_rules.check_styles(&translator_registry)?;
+    info!(
+        rules = translator_rules.rule_count(),
+        styles = translator_registry.styles().len(),
+        "Loaded translator rules"
+    );
     let state = AppState {
         pokemon_api,
         fun_translator,
+        readiness: readiness.clone(),
+        name_index,
+        translator_rules,
+        translator_registry,
+        batch_max_size: config.batch_max_size,
+        batch_concurrency: config.batch_concurrency,
     };
+    let in_flight = shutdown::InFlightRequests::default();
+    let rate_limiter = Arc::new(rate_limit::RateLimiter::new(
+        vec![
+            (LIST_ROUTE, config.rate_limit_pokemon),
+            (POKEMON_ROUTE, config.rate_limit_pokemon),
+            (BATCH_ROUTE, config.rate_limit_pokemon),
+            (TRANSLATION_ROUTE, config.rate_limit_translation),
+            (EVOLUTION_ROUTE, config.rate_limit_pokemon),
+            (DESCRIPTIONS_ROUTE, config.rate_limit_pokemon),
+        ],
+        config.rate_limit_trusted_proxies.clone(),
+    ));
+
+    let authenticator = Arc::new(match &config.auth_keys_file {
+        Some(path) => auth::Authenticator::load(path, config.auth_anonymous_scopes.clone())?,
+        None => auth::Authenticator::new(vec![], config.auth_anonymous_scopes.clone())?,
+    });
+    info!(
+        keys = authenticator.key_count(),
+        anonymous_scopes = ?config
+            .auth_anonymous_scopes
+            .iter()
+            .map(auth::Scope::as_str)
+            .collect::<Vec<_>>(),
+        "Loaded API keys"
+    );
 
     let (router, api) = OpenApiRouter::with_openapi(ApiDoc::openapi())
+        .routes(routes!(list_pokemon))
         .routes(routes!(get_pokemon))
+        .routes(routes!(get_pokemon_batch))
         .routes(routes!(get_pokemon_translation))
+        .routes(routes!(get_pokemon_evolution))
+        .routes(routes!(get_pokemon_descriptions))
+        .routes(routes!(list_translators))
         .routes(routes!(health))
+        .routes(routes!(ready))
         .routes(routes!(metrics_endpoint))
         .split_for_parts();
 
@@ -258,34 +520,186 @@ async fn main() -> anyhow::Result<()> {
                 )
             }),
         )
+        .layer(middleware::from_fn_with_state(
+            rate_limiter,
+            rate_limit::limit,
+        ))
+        .layer(middleware::from_fn_with_state(
+            authenticator,
+            auth::authenticate,
+        ))
         .layer(middleware::from_fn(metrics::track_metrics))
+        .layer(middleware::from_fn_with_state(
+            in_flight.clone(),
+            shutdown::track_in_flight,
+        ))
+        .layer(middleware::from_fn(request_id::propagate))
         .with_state(state);
 
     let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", config.port)).await?;
     info!("Server listening on 0.0.0.0:{}", config.port);
-    axum::serve(listener, app).await?;
+    let summary = shutdown::serve(
+        listener,
+        app,
+        in_flight,
+        shutdown::signal(),
+        config.shutdown_grace_period,
+        || readiness.shut_down(),
+    )
+    .await?;
+
+    let flushed = match translation_store.flush() {
+        Ok(()) => true,
+        Err(e) => {
+            warn!(error = %e, "Failed to flush translation cache");
+            false
+        }
+    };
+    info!(
+        in_flight_at_signal = summary.in_flight_at_signal,
+        abandoned = summary.abandoned,
+        drain_ms = summary.drain_duration.as_millis() as u64,
+        translation_cache_flushed = flushed,
+        "Shutdown complete"
+    );
     Ok(())
 }
 
+/// Query parameters of `/pokemon`.
+#[derive(serde::Deserialize)]
+struct ListQuery {
+    /// Position of the first returned Pokemon
+    #[serde(default)]
+    offset: usize,
+    /// Maximum number of returned Pokemon
+    limit: Option<usize>,
+    /// Name search
+    q: Option<String>,
+}
+
+/// Lists Pokémon names, optionally matching a search.
+///
+/// # Arguments
+///
+/// * `state` - Application state containing the name index
+/// * `query` - Query parameters, with the page `offset` and `limit` and the `q` search
+///
+/// # Returns
+///
+/// Returns 200 OK with a page of names, the total number of matches and a `Link` header
+/// to the previous and next pages, or 400 Bad Request if the limit is 0 or above
+/// the maximum page size.
+///
+/// # Search
+///
+/// Names equal to `q` come first, then names starting with it, names containing it, and
+/// names within a small edit distance of it (e.g., `q=pikahcu` finds pikachu).
+///
+/// # Tracing
+///
+/// Creates a distributed tracing span `list_pokemon` with the search query.
+#[utoipa::path(
+    get,
+    path = "/pokemon",
+    tag = "pokemon",
+    security((), ("api_key" = ["pokemon:read"]), ("bearer" = ["pokemon:read"])),
+    description = "Lists Pokemon names, optionally matching a search",
+    params(
+        ("offset" = Option<usize>, Query, description = "Position of the first returned Pokemon (default 0)"),
+        ("limit" = Option<usize>, Query, description = "Maximum number of returned Pokemon (default 20, at most 100)"),
+        ("q" = Option<String>, Query, description = "Name search: prefix, substring or approximate (typo tolerant) match")
+    ),
+    responses(
+        (status = 200, description = "Page of Pokemon names", body = PokemonPage, headers(
+            ("Link" = String, description = "RFC 8288 links to the previous and next pages")
+        )),
+        (status = 400, description = "Invalid offset or limit", body = Problem, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid API key", body = Problem, content_type = "application/problem+json"),
+        (status = 403, description = "API key lacks the required scope", body = Problem, content_type = "application/problem+json"),
+        (status = 429, description = "Rate limit exceeded", body = Problem, content_type = "application/problem+json", headers(
+            ("Retry-After" = u64, description = "Seconds to wait before retrying")
+        )),
+        (status = 503, description = "Service unavailable", body = Problem, content_type = "application/problem+json"),
+        (status = 504, description = "Upstream request timed out", body = Problem, content_type = "application/problem+json")
+    )
+)]
+async fn list_pokemon(
+    State(state): State<AppState>,
+    query: Result<Query<ListQuery>, QueryRejection>,
+) -> Response {
+    let Query(query) = match query {
+        Ok(query) => query,
+        Err(rejection) => return Problem::from(rejection).into_response(),
+    };
+    let span = tracing::info_span!("list_pokemon", q = query.q.as_deref());
+
+    let limit = query.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+    if limit == 0 || limit > MAX_PAGE_LIMIT {
+        span.in_scope(|| warn!(limit, "Invalid pokemon page limit"));
+        return Problem::new(ProblemType::InvalidRequest)
+            .with_detail(format!("limit must be between 1 and {}", MAX_PAGE_LIMIT))
+            .into_response();
+    }
+
+    match state
+        .name_index
+        .search(query.q.as_deref(), query.offset, limit)
+        .instrument(span.clone())
+        .await
+    {
+        Ok(page) => {
+            span.in_scope(|| debug!(count = page.count, "Listed pokemon"));
+            match page.links(query.q.as_deref()) {
+                Some(links) => ([(LINK, links)], Json(page)).into_response(),
+                None => Json(page).into_response(),
+            }
+        }
+        Err(e) => {
+            span.in_scope(|| warn!(error = %e, "Failed to list pokemon"));
+            Problem::from(e).into_response()
+        }
+    }
+}
+
+/// Query parameters of `/pokemon/{name}`.
+#[derive(serde::Deserialize)]
+struct PokemonQuery {
+    /// Comma separated Pokemon fields to return (all of them when absent)
+    fields: Option<String>,
+    /// Whether to return the description as PokéAPI returns it, not normalized
+    #[serde(default)]
+    raw: bool,
+    /// Game version of the description (e.g., red)
+    version: Option<String>,
+    /// Version group of the description (e.g., red-blue)
+    #[serde(rename = "version-group")]
+    version_group: Option<String>,
+}
+
 /// Fetches Pokémon information with language negotiation.
 ///
 /// # Arguments
 ///
 /// * `state` - Application state containing Pokemon API client and translator
 /// * `name` - Pokémon name to fetch
+/// * `query` - Query parameters, with the optional `fields` selection
 /// * `headers` - HTTP headers including optional `Accept-Language`
 ///
 /// # Returns
 ///
-/// Returns 200 OK with Pokemon data and Content-Language header on success,
-/// 404 Not Found if the Pokémon doesn't exist or name is empty,
+/// Returns 200 OK with Pokemon data (restricted to the selected fields, if any) and
+/// Content-Language header on success, 400 Bad Request if a selected field is unknown,
+/// 404 Not Found (problem details with the closest known names) if the Pokémon doesn't exist or name is empty,
 /// or 500 Internal Server Error on unexpected failures.
 ///
 /// # Language Negotiation
 ///
 /// Respects the `Accept-Language` header (RFC 7231) for selecting response language.
-/// Falls back to English if requested language is unavailable and wildcard is present,
-/// or returns 406 Not Acceptable if no suitable language is found.
+/// The description is in the first available requested language, otherwise in the first
+/// available language of the configured fallback chains of the requested languages, otherwise
+/// in the configured default language (even without a wildcard, unless excluded), otherwise
+/// in any available language if the wildcard is present. The step taken is reported in the
+/// `X-Language-Fallback` header. Returns 406 Not Acceptable if no suitable language is found.
 ///
 /// # Tracing
 ///
@@ -295,44 +709,90 @@ async fn main() -> anyhow::Result<()> {
     get,
     path = "/pokemon/{name}",
     tag = "pokemon",
-    description = "Fetches Pokemon information with language negotiation",
+    security((), ("api_key" = ["pokemon:read"]), ("bearer" = ["pokemon:read"])),
+    description = "Fetches Pokemon information with language negotiation: the description is in the first available requested language, then in the configured fallback chains of the requested languages, then in the configured default language (even without a wildcard, unless excluded), then in any available language if the wildcard is present",
     params(
         ("name" = String, Path, description = "Pokemon name"),
+        ("fields" = Option<String>, Query, description = "Comma separated Pokemon fields to return (e.g., 'name,types,stats'); all of them when absent"),
+        ("raw" = Option<bool>, Query, description = "Return the description as PokéAPI returns it, without normalizing line breaks, hyphenation and casing"),
+        ("version" = Option<String>, Query, description = "Game version of the description (e.g., 'red', 'sword'); the configured version policy applies when absent"),
+        ("version-group" = Option<String>, Query, description = "Version group of the description (e.g., 'red-blue', 'sword-shield'); exclusive with `version`"),
         ("accept-language" = Option<String>, Header, description = "Preferred language(s) for Pokemon description (e.g., 'en', 'es', 'fr'). Supports multiple languages with quality values (e.g., 'es;q=0.9,en;q=0.8'). Use '*' to accept any available language.")
     ),
     responses(
-        (status = 200, description = "Pokemon found", body = Pokemon, headers(
-            ("Content-Language" = String, description = "Language of the returned Pokemon description")
+        (status = 200, description = "Pokemon found, restricted to the selected fields", body = Pokemon, headers(
+            ("Content-Language" = String, description = "Language of the returned Pokemon description"),
+            ("X-Language-Fallback" = String, description = "Fallback step of the description language: none (a requested language), chain, default or wildcard")
         )),
-        (status = 404, description = "Pokemon not found"),
-        (status = 406, description = "No acceptable language found for Pokemon description"),
-        (status = 503, description = "Service unavailable"),
-        (status = 500, description = "Internal server error")
+        (status = 400, description = "Unknown field selected, unknown version group, or invalid query parameters", body = Problem, content_type = "application/problem+json"),
+        (status = 404, description = "Pokemon not found, with the closest known names, or no description in the requested game version", body = Problem, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid API key", body = Problem, content_type = "application/problem+json"),
+        (status = 403, description = "API key lacks the required scope", body = Problem, content_type = "application/problem+json"),
+        (status = 406, description = "No acceptable language found for Pokemon description, with the available languages", body = Problem, content_type = "application/problem+json"),
+        (status = 429, description = "Rate limit exceeded", body = Problem, content_type = "application/problem+json", headers(
+            ("Retry-After" = u64, description = "Seconds to wait before retrying")
+        )),
+        (status = 503, description = "Service unavailable", body = Problem, content_type = "application/problem+json"),
+        (status = 500, description = "Internal server error", body = Problem, content_type = "application/problem+json"),
+        (status = 504, description = "Upstream request timed out", body = Problem, content_type = "application/problem+json")
     )
 )]
 async fn get_pokemon(
     State(state): State<AppState>,
     Path(name): Path<String>,
+    query: Result<Query<PokemonQuery>, QueryRejection>,
     headers: HeaderMap,
-) -> HttpResponse<JsonResponse<Pokemon>> {
+) -> Response {
     let span = tracing::info_span!("get_pokemon", pokemon_name = %name);
     let _guard = span.enter();
 
     if name.trim().is_empty() {
         warn!("Empty pokemon name requested");
-        return HttpResponse::NotFound;
+        return empty_name().into_response();
     }
+    let Query(query) = match query {
+        Ok(query) => query,
+        Err(rejection) => return Problem::from(rejection).into_response(),
+    };
+    let fields = match query.fields.as_deref().map(FieldSelection::parse) {
+        None => FieldSelection::default(),
+        Some(Ok(fields)) => fields,
+        Some(Err(message)) => {
+            debug!(message, "Invalid field selection");
+            return Problem::new(ProblemType::InvalidRequest)
+                .with_detail(message)
+                .into_response();
+        }
+    };
+
+    let options =
+        match VersionFilter::from_query(query.version.as_deref(), query.version_group.as_deref()) {
+            Ok(version) => DescriptionOptions {
+                raw: query.raw,
+                version,
+            },
+            Err(message) => {
+                debug!(message, "Invalid version selection");
+                return Problem::new(ProblemType::InvalidRequest)
+                    .with_detail(message)
+                    .into_response();
+            }
+        };
 
     debug!("Fetching pokemon: {}", name);
     metrics::POKEMON_REQUESTS_TOTAL.inc();
 
-    let (languages, has_wildcard) = headers.parse_accept_language();
+    let preferences = headers.parse_accept_language();
+    let mut fallback = None;
     let result = state
         .pokemon_api
-        .get_pokemon(&name, &languages, has_wildcard)
+        .get_pokemon(&name, &preferences, &options)
         .await
-        .map(|(lang, p)| HttpResponse::Success(lang, JsonResponse(p)))
-        .unwrap_or_else(Into::into);
+        .map(|(lang, p)| {
+            fallback = Some(p.language_fallback);
+            HttpResponse::Success(lang, JsonResponse(fields.apply(&p)))
+        })
+        .unwrap_or_else(|e| pokemon_error(&state.name_index, &name, e));
 
     match &result {
         HttpResponse::Success(lang, _) => {
@@ -340,25 +800,123 @@ async fn get_pokemon(
             info!(
                 pokemon = name,
                 language = lang,
+                fallback = fallback.map(|f| f.as_str()),
                 "Successfully fetched pokemon"
             );
         }
-        HttpResponse::NotFound => {
-            metrics::POKEMON_REQUESTS_NOT_FOUND.inc();
-            debug!(pokemon = name, "Pokemon not found");
-        }
-        HttpResponse::ServiceUnavailable => {
-            metrics::SERVICE_UNAVAILABLE_ERRORS.inc();
-            warn!(pokemon = name, "Pokemon service unavailable");
-        }
-        HttpResponse::InternalError => {
-            metrics::TRANSLATIONS_FAILED.inc();
-            warn!(pokemon = name, "Internal error during translation");
-        }
-        _ => {}
+        HttpResponse::Problem(problem) => match problem.kind {
+            ProblemType::PokemonNotFound | ProblemType::DescriptionNotFound => {
+                metrics::POKEMON_REQUESTS_NOT_FOUND.inc();
+                debug!(pokemon = name, "Pokemon not found");
+            }
+            ProblemType::UpstreamUnavailable => {
+                metrics::SERVICE_UNAVAILABLE_ERRORS.inc();
+                warn!(pokemon = name, "Pokemon service unavailable");
+            }
+            ProblemType::UpstreamTimeout => {
+                metrics::GATEWAY_TIMEOUT_ERRORS.inc();
+                warn!(pokemon = name, "Pokemon service timed out");
+            }
+            ProblemType::UpstreamError => {
+                metrics::TRANSLATIONS_FAILED.inc();
+                warn!(pokemon = name, "Internal error during translation");
+            }
+            _ => {}
+        },
     }
+    (
+        AppendHeaders(fallback.map(|f| (LANGUAGE_FALLBACK_HEADER, f.as_str()))),
+        result,
+    )
+        .into_response()
+}
 
-    result
+/// Fetches several Pokémon at once with language negotiation.
+///
+/// # Arguments
+///
+/// * `state` - Application state containing Pokemon API client and batch limits
+/// * `headers` - HTTP headers including optional `Accept-Language`, applied to every name
+/// * `request` - Names to look up
+///
+/// # Returns
+///
+/// Returns 200 OK with a result per name, in request order, each with its own status
+/// (`found`, `not_found`, `not_acceptable`, `upstream_error`), or 400 Bad Request if
+/// the batch is empty or larger than the configured maximum size.
+///
+/// # Tracing
+///
+/// Creates a distributed tracing span `get_pokemon_batch` with the batch size.
+#[utoipa::path(
+    post,
+    path = "/pokemon/batch",
+    tag = "pokemon",
+    security((), ("api_key" = ["pokemon:read"]), ("bearer" = ["pokemon:read"])),
+    description = "Fetches several Pokemon at once with language negotiation",
+    request_body = BatchRequest,
+    params(
+        ("accept-language" = Option<String>, Header, description = "Preferred language(s) for the descriptions of all the Pokemon, as for `GET /pokemon/{name}`")
+    ),
+    responses(
+        (status = 200, description = "Lookup result of every Pokemon", body = BatchResponse),
+        (status = 400, description = "Malformed body, empty batch, or more names than allowed", body = Problem, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid API key", body = Problem, content_type = "application/problem+json"),
+        (status = 403, description = "API key lacks the required scope", body = Problem, content_type = "application/problem+json"),
+        (status = 429, description = "Rate limit exceeded: every name of the batch counts as one lookup", body = Problem, content_type = "application/problem+json", headers(
+            ("Retry-After" = u64, description = "Seconds to wait before retrying")
+        ))
+    )
+)]
+async fn get_pokemon_batch(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    request: Result<Json<BatchRequest>, JsonRejection>,
+) -> Response {
+    let Json(request) = match request {
+        Ok(request) => request,
+        Err(rejection) => return Problem::from(rejection).into_response(),
+    };
+    let size = request.names.len();
+    let span = tracing::info_span!("get_pokemon_batch", size);
+
+    if size == 0 || size > state.batch_max_size {
+        warn!(size, "Invalid pokemon batch size");
+        return Problem::new(ProblemType::InvalidRequest)
+            .with_detail(format!(
+                "a batch must contain between 1 and {} names",
+                state.batch_max_size
+            ))
+            .into_response();
+    }
+
+    let preferences = headers.parse_accept_language();
+    let response = batch::resolve(
+        state.pokemon_api.as_ref(),
+        request.names,
+        &preferences,
+        state.batch_concurrency,
+    )
+    .instrument(span.clone())
+    .await;
+
+    let found = response
+        .results
+        .iter()
+        .filter(|item| item.status == BatchItemStatus::Found)
+        .count();
+    span.in_scope(|| info!(size, found, "Resolved pokemon batch"));
+    Json(response).into_response()
+}
+
+/// Query parameters of `/pokemon/{name}/translation/`.
+#[derive(serde::Deserialize)]
+struct TranslationQuery {
+    /// Translator style overriding the automatic choice
+    style: Option<String>,
+    /// Whether to translate the description as PokéAPI returns it, not normalized
+    #[serde(default)]
+    raw: bool,
 }
 
 /// Fetches and translates a Pokémon's description.
@@ -367,19 +925,29 @@ async fn get_pokemon(
 ///
 /// * `state` - Application state containing Pokemon API client and translator
 /// * `name` - Pokémon name to fetch and translate
+/// * `query` - Query parameters, with the optional translator `style`
 ///
 /// # Returns
 ///
 /// Returns 200 OK with translated description and Content-Language header on success,
-/// 404 Not Found if the Pokémon doesn't exist, name is empty, or has no description,
+/// 400 Bad Request if the requested style is unknown,
+/// 404 Not Found (problem details with the closest known names) if the Pokémon doesn't exist,
+/// name is empty, or has no description,
 /// or 500 Internal Server Error on translation or API failures.
 ///
 /// # Translation Process
 ///
 /// 1. Fetches Pokémon data from PokéAPI (using DEFAULT_LANGUAGE: English)
-/// 2. Extracts description text and determines translator type from Pokémon species
-/// 3. Sends description to Fun Translations API with appropriate translator
-/// 4. Returns translated text as plain text (text/plain)
+/// 2. Extracts description text and selects the translator: the requested `style`, if any,
+///    otherwise the translator of the translator rules, returning the matching rule name in
+///    the `X-Translator-Rule` header
+/// 3. Sends description to Fun Translations API with appropriate translator, falling back on
+///    the local translators when it fails (`TRANSLATION_LOCAL_FALLBACK`)
+/// 4. Returns translated text as plain text (text/plain), or as a [`TranslatedDescription`]
+///    when the `Accept` header prefers JSON, with the engine that produced it (`funtranslations`,
+///    `local`, or `none` for the untranslated description) in the `X-Translation-Engine` header
+///    and the translation status (`translated`, `cached`, `fallback-local` or
+///    `fallback-original`) in the `X-Translation-Status` header
 ///
 /// # Tracing
 ///
@@ -389,91 +957,548 @@ async fn get_pokemon(
     get,
     path = "/pokemon/{name}/translation/",
     tag = "pokemon",
+    security((), ("api_key" = ["translation:read"]), ("bearer" = ["translation:read"])),
     description = "Fetches and translates a Pokemon's description",
     params(
-        ("name" = String, Path, description = "Pokemon name")
+        ("name" = String, Path, description = "Pokemon name"),
+        ("style" = Option<String>, Query, description = "Translator style overriding the automatic choice (see `GET /translators`)"),
+        ("raw" = Option<bool>, Query, description = "Translate the description as PokéAPI returns it, without normalizing line breaks, hyphenation and casing")
     ),
     responses(
-        (status = 200, description = "Translated Pokemon description", body = String, headers(
-            ("Content-Language" = String, description = "Language of the returned translated description")
+        (status = 200, description = "Translated Pokemon description, as plain text or, when the `Accept` header prefers JSON, with its translation status, translator and source text", content(
+            (String = "text/plain"),
+            (TranslatedDescription = "application/json")
+        ), headers(
+            ("Content-Language" = String, description = "Language of the returned translated description"),
+            ("X-Translator-Rule" = String, description = "Name of the translator rule that selected the translator, or `default` (absent when a style is requested)"),
+            ("X-Translation-Engine" = String, description = "Engine that produced the text: `funtranslations`, `local`, or `none` when the description is returned untranslated"),
+            ("X-Translation-Status" = String, description = "Translation outcome: `translated`, `cached`, `fallback-local` or `fallback-original`")
+        )),
+        (status = 400, description = "Unknown translator style", body = Problem, content_type = "application/problem+json"),
+        (status = 404, description = "Pokemon not found, with the closest known names", body = Problem, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid API key", body = Problem, content_type = "application/problem+json"),
+        (status = 403, description = "API key lacks the required scope", body = Problem, content_type = "application/problem+json"),
+        (status = 406, description = "No acceptable language found for Pokemon description, with the available languages", body = Problem, content_type = "application/problem+json"),
+        (status = 429, description = "Rate limit exceeded", body = Problem, content_type = "application/problem+json", headers(
+            ("Retry-After" = u64, description = "Seconds to wait before retrying")
         )),
-        (status = 404, description = "Pokemon not found"),
-        (status = 406, description = "No acceptable language found for Pokemon description"),
-        (status = 500, description = "Internal server error"),
-        (status = 503, description = "Service unavailable"),
+        (status = 500, description = "Internal server error", body = Problem, content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable", body = Problem, content_type = "application/problem+json"),
+        (status = 504, description = "Upstream request timed out", body = Problem, content_type = "application/problem+json"),
     )
 )]
 async fn get_pokemon_translation(
     State(state): State<AppState>,
     Path(name): Path<String>,
-) -> HttpResponse<String> {
+    query: Result<Query<TranslationQuery>, QueryRejection>,
+    headers: HeaderMap,
+) -> Response {
     let span = tracing::info_span!("get_pokemon_translation", pokemon_name = %name);
     let _guard = span.enter();
 
     if name.trim().is_empty() {
         warn!("Empty pokemon name requested for translation");
-        return HttpResponse::NotFound;
+        return empty_name().into_response();
     }
+    let Query(query) = match query {
+        Ok(query) => query,
+        Err(rejection) => return Problem::from(rejection).into_response(),
+    };
+    let requested = match query
+        .style
+        .map(|style| state.translator_registry.resolve(&style))
+        .transpose()
+    {
+        Ok(requested) => requested,
+        Err(e) => {
+            debug!(error = e, "Unknown translator style requested");
+            return Problem::new(ProblemType::InvalidRequest)
+                .with_detail(e)
+                .into_response();
+        }
+    };
 
     debug!("Translating pokemon description for: {}", name);
     metrics::TRANSLATIONS_TOTAL.inc();
+    let name_index = state.name_index.clone();
+    let translator_rules = state.translator_rules.clone();
+    let mut rule = None;
+    let json = prefers_json(headers.get(ACCEPT).and_then(|h| h.to_str().ok()));
 
     let response = match state
         .pokemon_api
-        .get_pokemon(&name, &[DEFAULT_LANGUAGE.to_string()], false)
+        .get_pokemon(
+            &name,
+            &LanguagePreferences::strict(&[DEFAULT_LANGUAGE]),
+            &DescriptionOptions {
+                raw: query.raw,
+                ..Default::default()
+            },
+        )
         .await
         .and_then(|(lang, p)| {
-            let translator = p.get_translator();
+            let translator = match requested {
+                Some(style) => {
+                    info!(pokemon = p.name, translator = %style, "Using requested translator");
+                    style
+                }
+                None => {
+                    let selection = translator_rules.select(&RuleSubject::from(&p));
+                    info!(
+                        pokemon = p.name,
+                        rule = selection.rule,
+                        translator = %selection.translator,
+                        "Selected translator"
+                    );
+                    rule = Some(selection.rule);
+                    selection.translator.clone()
+                }
+            };
             p.description
                 .map(|d| (lang, d, translator))
-                .ok_or(HttpClientError::NotFound)
+                .ok_or_else(|| ErrorKind::NoDescription.into())
         })
         .map(|(lang, d, t)| async move {
-            match state.fun_translator.translate(&d, t).await {
-                Ok(tr) => Ok((lang, tr.contents.translated)),
-                Err(HttpClientError::RateLimited) => {
-                    metrics::RATE_LIMITED_ERRORS.inc();
-                    Ok((lang, d)) // Fallback to original description on rate limit
-                }
-                Err(e) => Err(e),
-            }
+            let (status, engine, translated) =
+                match state.fun_translator.translate(&d, t.clone()).await {
+                    Ok(tr) => (
+                        TranslationStatus::from(&tr),
+                        tr.engine.as_str(),
+                        tr.contents.translated,
+                    ),
+                    Err(e) if e.kind() == &ErrorKind::RateLimited => {
+                        metrics::RATE_LIMITED_ERRORS.inc();
+                        // Fallback to original description on rate limit
+                        (
+                            TranslationStatus::FallbackOriginal,
+                            UNTRANSLATED_ENGINE,
+                            d.clone(),
+                        )
+                    }
+                    Err(e) => return Err(e),
+                };
+            Ok((
+                lang.clone(),
+                TranslatedDescription {
+                    status,
+                    translator: t.to_string(),
+                    engine: engine.to_string(),
+                    language: lang,
+                    source: d,
+                    translated,
+                },
+            ))
         }) {
         Ok(f) => f
             .await
-            .map(|(lang, text)| HttpResponse::Success(lang, text))
-            .unwrap_or_else(Into::into),
-        Err(e) => e.into(),
+            .map(|(lang, description)| HttpResponse::Success(lang, description))
+            .unwrap_or_else(translation_error),
+        Err(e) => pokemon_error(&name_index, &name, e),
     };
 
     match &response {
-        HttpResponse::Success(_, _) => {
+        HttpResponse::Success(_, description) => {
             metrics::TRANSLATIONS_SUCCEEDED.inc();
             info!(
                 pokemon = name,
+                engine = description.engine,
+                status = description.status.as_str(),
                 "Successfully translated pokemon description"
             );
         }
-        HttpResponse::NotFound => {
+        HttpResponse::Problem(problem) => {
             metrics::TRANSLATIONS_FAILED.inc();
-            debug!(pokemon = name, "Pokemon not found for translation");
+            match problem.kind {
+                ProblemType::PokemonNotFound | ProblemType::DescriptionNotFound => {
+                    debug!(pokemon = name, "Pokemon not found for translation");
+                }
+                ProblemType::UpstreamUnavailable => {
+                    metrics::SERVICE_UNAVAILABLE_ERRORS.inc();
+                    warn!(pokemon = name, "Translation service unavailable");
+                }
+                ProblemType::UpstreamTimeout => {
+                    metrics::GATEWAY_TIMEOUT_ERRORS.inc();
+                    warn!(pokemon = name, "Upstream timed out during translation");
+                }
+                ProblemType::UpstreamError => {
+                    warn!(pokemon = name, "Internal error during translation");
+                }
+                _ => {
+                    warn!(pokemon = name, "Translation failed");
+                }
+            }
         }
-        HttpResponse::ServiceUnavailable => {
-            metrics::SERVICE_UNAVAILABLE_ERRORS.inc();
-            metrics::TRANSLATIONS_FAILED.inc();
-            warn!(pokemon = name, "Translation service unavailable");
+    }
+    let outcome = match &response {
+        HttpResponse::Success(_, description) => vec![
+            (TRANSLATION_ENGINE_HEADER, description.engine.clone()),
+            (
+                TRANSLATION_STATUS_HEADER,
+                description.status.as_str().to_string(),
+            ),
+        ],
+        HttpResponse::Problem(_) => vec![],
+    };
+    let response = match response {
+        HttpResponse::Success(lang, description) if json => {
+            HttpResponse::Success(lang, JsonResponse(description)).into_response()
         }
-        HttpResponse::InternalError => {
-            metrics::TRANSLATIONS_FAILED.inc();
-            warn!(pokemon = name, "Internal error during translation");
+        HttpResponse::Success(lang, description) => {
+            HttpResponse::Success(lang, description.translated).into_response()
+        }
+        HttpResponse::Problem(problem) => problem.into_response(),
+    };
+    (
+        AppendHeaders(rule.map(|rule| (TRANSLATOR_RULE_HEADER, rule.to_string()))),
+        AppendHeaders(outcome),
+        response,
+    )
+        .into_response()
+}
+
+/// Maps a Pokémon lookup error, suggesting the closest known names of unknown Pokémon.
+fn pokemon_error<T>(name_index: &NameIndex, name: &str, error: HttpClientError) -> HttpResponse<T> {
+    match error.kind() {
+        ErrorKind::NotFound => HttpResponse::Problem(not_found(name_index, name)),
+        _ => error.into(),
+    }
+}
+
+/// Maps a translator error: a translator endpoint not found is an upstream error, the Pokémon
+/// exists.
+fn translation_error<T>(error: HttpClientError) -> HttpResponse<T> {
+    match error.kind() {
+        ErrorKind::NotFound => HttpResponse::Problem(Problem {
+            detail: Problem::from(error).detail,
+            ..Problem::new(ProblemType::UpstreamError)
+        }),
+        _ => error.into(),
+    }
+}
+
+/// Builds the 404 problem of an unknown Pokémon, suggesting the closest known names.
+fn not_found(name_index: &NameIndex, name: &str) -> Problem {
+    let suggestions = name_index.suggest(name);
+    if !suggestions.is_empty() {
+        debug!(pokemon = name, ?suggestions, "Suggesting pokemon names");
+    }
+    Problem::new(ProblemType::PokemonNotFound)
+        .with_detail(format!("Pokemon '{}' not found", name))
+        .with_suggestions(suggestions)
+}
+
+/// Builds the 404 problem of an empty Pokémon name.
+fn empty_name() -> Problem {
+    Problem::new(ProblemType::PokemonNotFound).with_detail("Pokemon name is empty")
+}
+
+/// Query parameters of `/pokemon/{name}/evolution`.
+#[derive(serde::Deserialize)]
+struct EvolutionQuery {
+    /// Whether to translate the stage descriptions
+    #[serde(default)]
+    translate: bool,
+    /// Whether to return the stage descriptions as PokéAPI returns them, not normalized
+    #[serde(default)]
+    raw: bool,
+}
+
+/// Fetches the evolution chain of a Pokémon.
+///
+/// # Arguments
+///
+/// * `state` - Application state containing Pokemon API client and translator
+/// * `name` - Pokémon name whose evolution chain is fetched
+/// * `query` - Query parameters, with the optional `translate` flag
+/// * `headers` - HTTP headers including optional `Accept-Language`
+///
+/// # Returns
+///
+/// Returns 200 OK with the stages of the chain, base species first, and the
+/// Content-Language header of the requested Pokémon description on success,
+/// 404 Not Found if the Pokémon doesn't exist or has no evolution chain,
+/// or 500 Internal Server Error on unexpected failures.
+///
+/// # Translation
+///
+/// With `translate=true`, English descriptions are fetched and every stage description is
+/// translated with the translator of its species, as for `/pokemon/{name}/translation/`.
+/// Descriptions failing to translate are returned untranslated (`translated: false`).
+///
+/// # Tracing
+///
+/// Creates a distributed tracing span `get_pokemon_evolution` with pokemon_name field.
+#[utoipa::path(
+    get,
+    path = "/pokemon/{name}/evolution",
+    tag = "pokemon",
+    security(
+        (),
+        ("api_key" = ["pokemon:read"]),
+        ("bearer" = ["pokemon:read"]),
+        ("api_key" = ["translation:read"]),
+        ("bearer" = ["translation:read"])
+    ),
+    description = "Fetches the evolution chain of a Pokemon (translating descriptions requires the translation:read scope)",
+    params(
+        ("name" = String, Path, description = "Pokemon name"),
+        ("translate" = Option<bool>, Query, description = "Translate the stage descriptions (English only)"),
+        ("raw" = Option<bool>, Query, description = "Return the stage descriptions as PokéAPI returns them, without normalizing line breaks, hyphenation and casing"),
+        ("accept-language" = Option<String>, Header, description = "Preferred language(s) for the stage descriptions, as for `GET /pokemon/{name}`; ignored when translating")
+    ),
+    responses(
+        (status = 200, description = "Evolution chain found", body = Evolution, headers(
+            ("Content-Language" = String, description = "Language of the requested Pokemon description")
+        )),
+        (status = 400, description = "Invalid query parameters", body = Problem, content_type = "application/problem+json"),
+        (status = 404, description = "Pokemon or evolution chain not found", body = Problem, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid API key", body = Problem, content_type = "application/problem+json"),
+        (status = 403, description = "API key lacks the required scope", body = Problem, content_type = "application/problem+json"),
+        (status = 429, description = "Rate limit exceeded", body = Problem, content_type = "application/problem+json", headers(
+            ("Retry-After" = u64, description = "Seconds to wait before retrying")
+        )),
+        (status = 500, description = "Internal server error", body = Problem, content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable", body = Problem, content_type = "application/problem+json"),
+        (status = 504, description = "Upstream request timed out", body = Problem, content_type = "application/problem+json")
+    )
+)]
+async fn get_pokemon_evolution(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    query: Result<Query<EvolutionQuery>, QueryRejection>,
+    headers: HeaderMap,
+) -> HttpResponse<JsonResponse<Evolution>> {
+    let span = tracing::info_span!("get_pokemon_evolution", pokemon_name = %name);
+
+    if name.trim().is_empty() {
+        span.in_scope(|| warn!("Empty pokemon name requested for evolution"));
+        return empty_name().into();
+    }
+    let Query(query) = match query {
+        Ok(query) => query,
+        Err(rejection) => return Problem::from(rejection).into(),
+    };
+
+    let preferences = match query.translate {
+        true => LanguagePreferences::strict(&[DEFAULT_LANGUAGE]),
+        false => headers.parse_accept_language(),
+    };
+    let mut evolution = match state
+        .pokemon_api
+        .get_evolution(
+            &name,
+            &preferences,
+            &DescriptionOptions {
+                raw: query.raw,
+                ..Default::default()
+            },
+        )
+        .instrument(span.clone())
+        .await
+    {
+        Ok(evolution) => evolution,
+        Err(e) => {
+            span.in_scope(|| debug!(pokemon = name, error = %e, "Evolution chain not fetched"));
+            return pokemon_error(&state.name_index, &name, e);
         }
+    };
+    if query.translate {
+        join_all(evolution.stages.iter_mut().map(|stage| {
+            translate_stage(
+                state.fun_translator.as_ref(),
+                state.translator_rules.as_ref(),
+                stage,
+            )
+        }))
+        .instrument(span.clone())
+        .await;
+    }
 
-        _ => {
-            metrics::TRANSLATIONS_FAILED.inc();
-            warn!(pokemon = name, "Translation failed");
+    let lang = evolution
+        .stages
+        .iter()
+        .find(|stage| stage.name == evolution.pokemon)
+        .and_then(|stage| stage.language.clone())
+        .unwrap_or_else(|| DEFAULT_LANGUAGE.to_string());
+    span.in_scope(|| {
+        info!(
+            pokemon = name,
+            stages = evolution.stages.len(),
+            "Successfully fetched evolution chain"
+        )
+    });
+    HttpResponse::Success(lang, JsonResponse(evolution))
+}
+
+/// Query parameters of `/pokemon/{name}/descriptions`.
+#[derive(serde::Deserialize)]
+struct DescriptionsQuery {
+    /// Game version of the descriptions (e.g., red)
+    version: Option<String>,
+    /// Version group of the descriptions (e.g., red-blue)
+    #[serde(rename = "version-group")]
+    version_group: Option<String>,
+}
+
+/// Lists every description of a Pokémon, by language and game version.
+///
+/// # Arguments
+///
+/// * `state` - Application state containing Pokemon API client
+/// * `name` - Pokémon name whose descriptions are listed
+/// * `query` - Query parameters, with the optional `version` or `version-group`
+/// * `headers` - HTTP headers including optional `Accept-Language`
+///
+/// # Returns
+///
+/// Returns 200 OK with the descriptions grouped by language, the game versions sharing the
+/// same text being merged, and the Content-Language header listing the returned languages,
+/// 400 Bad Request if the version group is unknown, 404 Not Found if the Pokémon doesn't
+/// exist or has no description (in the requested game version), or 406 Not Acceptable if
+/// no description is in the requested languages.
+///
+/// # Language Filtering
+///
+/// Without `Accept-Language` (or with `*`), every language is returned, the requested ones
+/// first; otherwise only the requested languages are.
+///
+/// # Tracing
+///
+/// Creates a distributed tracing span `get_pokemon_descriptions` with pokemon_name field.
+#[utoipa::path(
+    get,
+    path = "/pokemon/{name}/descriptions",
+    tag = "pokemon",
+    security((), ("api_key" = ["pokemon:read"]), ("bearer" = ["pokemon:read"])),
+    description = "Lists every description of a Pokemon, by language and game version",
+    params(
+        ("name" = String, Path, description = "Pokemon name"),
+        ("version" = Option<String>, Query, description = "Game version of the descriptions (e.g., 'red', 'sword')"),
+        ("version-group" = Option<String>, Query, description = "Version group of the descriptions (e.g., 'red-blue'); exclusive with `version`"),
+        ("accept-language" = Option<String>, Header, description = "Languages of the descriptions (e.g., 'en, ja'); every language when absent, or with '*' after the requested ones")
+    ),
+    responses(
+        (status = 200, description = "Descriptions found", body = PokemonDescriptions, headers(
+            ("Content-Language" = String, description = "Languages of the returned descriptions")
+        )),
+        (status = 400, description = "Unknown version group, or invalid query parameters", body = Problem, content_type = "application/problem+json"),
+        (status = 404, description = "Pokemon not found, with the closest known names, or no description in the requested game version", body = Problem, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid API key", body = Problem, content_type = "application/problem+json"),
+        (status = 403, description = "API key lacks the required scope", body = Problem, content_type = "application/problem+json"),
+        (status = 406, description = "No description in the requested languages, with the available languages", body = Problem, content_type = "application/problem+json"),
+        (status = 429, description = "Rate limit exceeded", body = Problem, content_type = "application/problem+json", headers(
+            ("Retry-After" = u64, description = "Seconds to wait before retrying")
+        )),
+        (status = 500, description = "Internal server error", body = Problem, content_type = "application/problem+json"),
+        (status = 503, description = "Service unavailable", body = Problem, content_type = "application/problem+json"),
+        (status = 504, description = "Upstream request timed out", body = Problem, content_type = "application/problem+json")
+    )
+)]
+async fn get_pokemon_descriptions(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    query: Result<Query<DescriptionsQuery>, QueryRejection>,
+    headers: HeaderMap,
+) -> HttpResponse<JsonResponse<PokemonDescriptions>> {
+    let span = tracing::info_span!("get_pokemon_descriptions", pokemon_name = %name);
+
+    if name.trim().is_empty() {
+        span.in_scope(|| warn!("Empty pokemon name requested for descriptions"));
+        return empty_name().into();
+    }
+    let Query(query) = match query {
+        Ok(query) => query,
+        Err(rejection) => return Problem::from(rejection).into(),
+    };
+    let version =
+        match VersionFilter::from_query(query.version.as_deref(), query.version_group.as_deref()) {
+            Ok(version) => version,
+            Err(message) => {
+                span.in_scope(|| debug!(message, "Invalid version selection"));
+                return Problem::new(ProblemType::InvalidRequest)
+                    .with_detail(message)
+                    .into();
+            }
+        };
+
+    let preferences = headers.parse_accept_language();
+    let descriptions = match state
+        .pokemon_api
+        .get_descriptions(&name, &preferences, version.as_ref())
+        .instrument(span.clone())
+        .await
+    {
+        Ok(descriptions) => descriptions,
+        Err(e) => {
+            span.in_scope(|| debug!(pokemon = name, error = %e, "Descriptions not fetched"));
+            return pokemon_error(&state.name_index, &name, e);
         }
+    };
+
+    let lang = descriptions
+        .languages
+        .iter()
+        .map(|l| l.language.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    span.in_scope(|| {
+        info!(
+            pokemon = name,
+            languages = lang,
+            "Successfully fetched descriptions"
+        )
+    });
+    HttpResponse::Success(lang, JsonResponse(descriptions))
+}
+
+/// Translates the description of an evolution stage in place, keeping it on failure.
+async fn translate_stage(
+    translator: &dyn Translator,
+    rules: &TranslatorRules,
+    stage: &mut EvolutionStage,
+) {
+    let Some(description) = stage.description.clone() else {
+        return;
+    };
+    let selection = rules.select(&RuleSubject::from(&*stage));
+    debug!(
+        stage = stage.name,
+        rule = selection.rule,
+        translator = %selection.translator,
+        "Selected translator"
+    );
+    match translator
+        .translate(&description, selection.translator.clone())
+        .await
+    {
+        Ok(translation) => {
+            stage.description = Some(translation.contents.translated);
+            stage.translated = true;
+        }
+        Err(e) if e.kind() == &ErrorKind::RateLimited => metrics::RATE_LIMITED_ERRORS.inc(),
+        Err(e) => warn!(stage = stage.name, error = %e, "Failed to translate stage description"),
     }
+}
 
-    response
+/// Lists the translator styles served by the API, in configuration order.
+///
+/// Any of them can be requested with `?style=` on `/pokemon/{name}/translation/`.
+///
+/// # Example
+///
+/// ```sh
+/// curl http://localhost:5000/translators
+/// # Response: {"translators":[{"name":"shakespeare"},{"name":"yoda"},{"name":"pirate"},...]}
+/// ```
+#[utoipa::path(
+    get,
+    path = "/translators",
+    description = "Lists the translator styles",
+    tag = "translators",
+    responses((status = 200, description = "Translator styles", body = TranslatorList))
+)]
+async fn list_translators(State(state): State<AppState>) -> Json<TranslatorList> {
+    Json(state.translator_registry.list())
 }
 
 /// Health check endpoint for monitoring and orchestration systems.
@@ -498,6 +1523,38 @@ async fn health() -> impl IntoResponse {
     StatusCode::OK
 }
 
+/// Readiness endpoint for orchestration systems.
+///
+/// Probes PokéAPI and Fun Translations (probe results are cached for a short interval)
+/// and returns 503 Service Unavailable while a required dependency is down, 200 OK
+/// otherwise. Unlike `/health`, it tells whether the pod should receive traffic.
+/// The body lists status, circuit state, probe latency and last error of every dependency.
+///
+/// # Example
+///
+/// ```sh
+/// curl http://localhost:5000/ready
+/// # Response: {"status":"ready","dependencies":[{"name":"pokeapi","required":true,"status":"up","circuit":"closed","latency_ms":42},...]}
+/// ```
+#[utoipa::path(
+    get,
+    path = "/ready",
+    description = "Readiness check endpoint",
+    tag = "system",
+    responses(
+        (status = 200, description = "Service is ready", body = ReadinessReport),
+        (status = 503, description = "A required dependency is down", body = ReadinessReport)
+    )
+)]
+async fn ready(State(state): State<AppState>) -> impl IntoResponse {
+    let report = state.readiness.report().await;
+    let status = match report.is_ready() {
+        true => StatusCode::OK,
+        false => StatusCode::SERVICE_UNAVAILABLE,
+    };
+    (status, Json(report))
+}
+
 /// Prometheus metrics endpoint.
 ///
 /// Exposes all application metrics in Prometheus text format (version 0.0.4).
@@ -525,7 +1582,12 @@ async fn health() -> impl IntoResponse {
     path = "/metrics",
     description = "Prometheus metrics endpoint",
     tag = "system",
-    responses((status = 200, description = "Prometheus format metrics"))
+    security((), ("api_key" = ["metrics:read"]), ("bearer" = ["metrics:read"])),
+    responses(
+        (status = 200, description = "Prometheus format metrics"),
+        (status = 401, description = "Missing or invalid API key", body = Problem, content_type = "application/problem+json"),
+        (status = 403, description = "API key lacks the required scope", body = Problem, content_type = "application/problem+json")
+    )
 )]
 async fn metrics_endpoint() -> impl IntoResponse {
     (
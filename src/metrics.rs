@@ -1,6 +1,9 @@
+use crate::constants::{
+    BATCH_ROUTE, DESCRIPTIONS_ROUTE, EVOLUTION_ROUTE, LIST_ROUTE, POKEMON_ROUTE, TRANSLATION_ROUTE,
+};
 use axum::{extract::Request, middleware::Next, response::Response};
 use once_cell::sync::Lazy;
-use prometheus::{Counter, CounterVec, HistogramVec, Registry};
+use prometheus::{Counter, CounterVec, HistogramVec, IntGauge, IntGaugeVec, Registry};
 use std::time::Instant;
 
 pub static REGISTRY: Lazy<Registry> = Lazy::new(Registry::new);
@@ -76,6 +79,170 @@ pub static RATE_LIMITED_ERRORS: Lazy<Counter> = Lazy::new(|| {
     .expect("Failed to create RATE_LIMITED_ERRORS metric")
 });
 
+pub static GATEWAY_TIMEOUT_ERRORS: Lazy<Counter> = Lazy::new(|| {
+    Counter::new(
+        "gateway_timeout_errors_total",
+        "Total gateway timeout errors (504)",
+    )
+    .expect("Failed to create GATEWAY_TIMEOUT_ERRORS metric")
+});
+
+pub static RATE_LIMIT_REJECTIONS: Lazy<CounterVec> = Lazy::new(|| {
+    CounterVec::new(
+        prometheus::Opts::new(
+            "rate_limit_rejections_total",
+            "Total requests rejected by the inbound rate limiter (429)",
+        ),
+        &["route"],
+    )
+    .expect("Failed to create RATE_LIMIT_REJECTIONS metric")
+});
+
+pub static API_KEY_REQUESTS: Lazy<CounterVec> = Lazy::new(|| {
+    CounterVec::new(
+        prometheus::Opts::new(
+            "api_key_requests_total",
+            "Authenticated requests, by API key name (anonymous without key)",
+        ),
+        &["key", "route"],
+    )
+    .expect("Failed to create API_KEY_REQUESTS metric")
+});
+
+pub static AUTH_REJECTIONS: Lazy<CounterVec> = Lazy::new(|| {
+    CounterVec::new(
+        prometheus::Opts::new(
+            "auth_rejections_total",
+            "Total requests rejected by authentication (401) or authorization (403)",
+        ),
+        &["route", "reason"],
+    )
+    .expect("Failed to create AUTH_REJECTIONS metric")
+});
+
+pub static POKEAPI_CACHE_HITS: Lazy<CounterVec> = Lazy::new(|| {
+    CounterVec::new(
+        prometheus::Opts::new(
+            "pokeapi_cache_hits_total",
+            "PokéAPI lookups served from the in-process cache",
+        ),
+        &["resource"],
+    )
+    .expect("Failed to create POKEAPI_CACHE_HITS metric")
+});
+
+pub static POKEAPI_CACHE_MISSES: Lazy<CounterVec> = Lazy::new(|| {
+    CounterVec::new(
+        prometheus::Opts::new(
+            "pokeapi_cache_misses_total",
+            "PokéAPI lookups that required an upstream fetch",
+        ),
+        &["resource"],
+    )
+    .expect("Failed to create POKEAPI_CACHE_MISSES metric")
+});
+
+pub static POKEAPI_CACHE_EVICTIONS: Lazy<CounterVec> = Lazy::new(|| {
+    CounterVec::new(
+        prometheus::Opts::new(
+            "pokeapi_cache_evictions_total",
+            "PokéAPI cache entries evicted because expired or over capacity",
+        ),
+        &["resource"],
+    )
+    .expect("Failed to create POKEAPI_CACHE_EVICTIONS metric")
+});
+
+pub static TRANSLATION_CACHE_HITS: Lazy<CounterVec> = Lazy::new(|| {
+    CounterVec::new(
+        prometheus::Opts::new(
+            "translation_cache_hits_total",
+            "Translations served from the persistent translation cache",
+        ),
+        &["translator"],
+    )
+    .expect("Failed to create TRANSLATION_CACHE_HITS metric")
+});
+
+pub static TRANSLATION_CACHE_MISSES: Lazy<CounterVec> = Lazy::new(|| {
+    CounterVec::new(
+        prometheus::Opts::new(
+            "translation_cache_misses_total",
+            "Translations not found in the persistent translation cache",
+        ),
+        &["translator"],
+    )
+    .expect("Failed to create TRANSLATION_CACHE_MISSES metric")
+});
+
+pub static LOCAL_TRANSLATIONS: Lazy<CounterVec> = Lazy::new(|| {
+    CounterVec::new(
+        prometheus::Opts::new(
+            "local_translations_total",
+            "Translations produced by the local translators after a Fun Translations failure",
+        ),
+        &["translator"],
+    )
+    .expect("Failed to create LOCAL_TRANSLATIONS metric")
+});
+
+pub static TRANSLATION_CACHE_ENTRIES: Lazy<IntGauge> = Lazy::new(|| {
+    IntGauge::new(
+        "translation_cache_entries",
+        "Translations currently stored in the persistent translation cache",
+    )
+    .expect("Failed to create TRANSLATION_CACHE_ENTRIES metric")
+});
+
+pub static POKEMON_INDEX_NAMES: Lazy<IntGauge> = Lazy::new(|| {
+    IntGauge::new(
+        "pokemon_index_names",
+        "Pokémon names currently held by the in-memory name index",
+    )
+    .expect("Failed to create POKEMON_INDEX_NAMES metric")
+});
+
+pub static POKEMON_INDEX_REFRESHES: Lazy<CounterVec> = Lazy::new(|| {
+    CounterVec::new(
+        prometheus::Opts::new(
+            "pokemon_index_refreshes_total",
+            "Refreshes of the in-memory Pokémon name index",
+        ),
+        &["outcome"],
+    )
+    .expect("Failed to create POKEMON_INDEX_REFRESHES metric")
+});
+
+pub static UPSTREAM_RETRIES: Lazy<CounterVec> = Lazy::new(|| {
+    CounterVec::new(
+        prometheus::Opts::new("upstream_retries_total", "Retried upstream HTTP requests"),
+        &["upstream"],
+    )
+    .expect("Failed to create UPSTREAM_RETRIES metric")
+});
+
+pub static CIRCUIT_BREAKER_STATE: Lazy<IntGaugeVec> = Lazy::new(|| {
+    IntGaugeVec::new(
+        prometheus::Opts::new(
+            "circuit_breaker_state",
+            "Upstream circuit breaker state (0 = closed, 1 = open, 2 = half-open)",
+        ),
+        &["upstream"],
+    )
+    .expect("Failed to create CIRCUIT_BREAKER_STATE metric")
+});
+
+pub static CIRCUIT_BREAKER_REJECTIONS: Lazy<CounterVec> = Lazy::new(|| {
+    CounterVec::new(
+        prometheus::Opts::new(
+            "circuit_breaker_rejections_total",
+            "Upstream calls rejected because the circuit breaker was open",
+        ),
+        &["upstream"],
+    )
+    .expect("Failed to create CIRCUIT_BREAKER_REJECTIONS metric")
+});
+
 /// Initializes the Prometheus metrics registry.
 ///
 /// Registers all defined metrics with the global registry. Should be called once
@@ -117,6 +284,54 @@ pub fn init() {
     REGISTRY
         .register(Box::new(RATE_LIMITED_ERRORS.clone()))
         .expect("Failed to register RATE_LIMITED_ERRORS");
+    REGISTRY
+        .register(Box::new(GATEWAY_TIMEOUT_ERRORS.clone()))
+        .expect("Failed to register GATEWAY_TIMEOUT_ERRORS");
+    REGISTRY
+        .register(Box::new(RATE_LIMIT_REJECTIONS.clone()))
+        .expect("Failed to register RATE_LIMIT_REJECTIONS");
+    REGISTRY
+        .register(Box::new(API_KEY_REQUESTS.clone()))
+        .expect("Failed to register API_KEY_REQUESTS");
+    REGISTRY
+        .register(Box::new(AUTH_REJECTIONS.clone()))
+        .expect("Failed to register AUTH_REJECTIONS");
+    REGISTRY
+        .register(Box::new(POKEAPI_CACHE_HITS.clone()))
+        .expect("Failed to register POKEAPI_CACHE_HITS");
+    REGISTRY
+        .register(Box::new(POKEAPI_CACHE_MISSES.clone()))
+        .expect("Failed to register POKEAPI_CACHE_MISSES");
+    REGISTRY
+        .register(Box::new(POKEAPI_CACHE_EVICTIONS.clone()))
+        .expect("Failed to register POKEAPI_CACHE_EVICTIONS");
+    REGISTRY
+        .register(Box::new(TRANSLATION_CACHE_HITS.clone()))
+        .expect("Failed to register TRANSLATION_CACHE_HITS");
+    REGISTRY
+        .register(Box::new(TRANSLATION_CACHE_MISSES.clone()))
+        .expect("Failed to register TRANSLATION_CACHE_MISSES");
+    REGISTRY
+        .register(Box::new(TRANSLATION_CACHE_ENTRIES.clone()))
+        .expect("Failed to register TRANSLATION_CACHE_ENTRIES");
+    REGISTRY
+        .register(Box::new(LOCAL_TRANSLATIONS.clone()))
+        .expect("Failed to register LOCAL_TRANSLATIONS");
+    REGISTRY
+        .register(Box::new(POKEMON_INDEX_NAMES.clone()))
+        .expect("Failed to register POKEMON_INDEX_NAMES");
+    REGISTRY
+        .register(Box::new(POKEMON_INDEX_REFRESHES.clone()))
+        .expect("Failed to register POKEMON_INDEX_REFRESHES");
+    REGISTRY
+        .register(Box::new(UPSTREAM_RETRIES.clone()))
+        .expect("Failed to register UPSTREAM_RETRIES");
+    REGISTRY
+        .register(Box::new(CIRCUIT_BREAKER_STATE.clone()))
+        .expect("Failed to register CIRCUIT_BREAKER_STATE");
+    REGISTRY
+        .register(Box::new(CIRCUIT_BREAKER_REJECTIONS.clone()))
+        .expect("Failed to register CIRCUIT_BREAKER_REJECTIONS");
 }
 
 /// Axum middleware that tracks HTTP request metrics.
@@ -127,6 +342,7 @@ pub fn init() {
 ///
 /// Excludes internal endpoints from tracking:
 /// - `/health` - health check endpoint
+/// - `/ready` - readiness endpoint
 /// - `/metrics` - metrics endpoint (avoid recursive tracking)
 /// - `/swagger-ui` - documentation UI
 /// - `/api-docs` - OpenAPI spec
@@ -173,12 +389,13 @@ pub async fn track_metrics(req: Request, next: Next) -> Response {
 ///
 /// Returns true for internal endpoints that don't need to be tracked:
 /// - Health checks (`/health`)
+/// - Readiness checks (`/ready`)
 /// - Metrics endpoint (`/metrics`)
 /// - Swagger UI (`/swagger-ui/*`)
 /// - API documentation (`/api-docs/*`)
 fn should_skip_tracking(path: &str) -> bool {
     // Fast path: check exact matches first (most common)
-    if path == "/health" || path == "/metrics" {
+    if path == "/health" || path == "/ready" || path == "/metrics" {
         return true;
     }
 
@@ -206,8 +423,12 @@ fn should_skip_tracking(path: &str) -> bool {
 /// # Examples
 ///
 /// - `/pokemon/pikachu` → `/pokemon/{name}`
+/// - `/pokemon` → `/pokemon`
+/// - `/pokemon/batch` → `/pokemon/batch`
 /// - `/pokemon/charizard/translation/` → `/pokemon/{name}/translation/`
-fn normalize_path(path: &str) -> String {
+/// - `/pokemon/charizard/evolution` → `/pokemon/{name}/evolution`
+/// - `/pokemon/charizard/descriptions` → `/pokemon/{name}/descriptions`
+pub(crate) fn normalize_path(path: &str) -> String {
     // Split path into segments
     let segments: Vec<&str> = path.split('/').collect();
 
@@ -216,10 +437,14 @@ fn normalize_path(path: &str) -> String {
         ["", ""] | [""] => "/".to_string(),
 
         // Pokemon endpoints
-        ["", "pokemon", _name] => "/pokemon/{name}".to_string(),
+        ["", "pokemon"] => LIST_ROUTE.to_string(),
+        ["", "pokemon", "batch"] => BATCH_ROUTE.to_string(),
+        ["", "pokemon", _name] => POKEMON_ROUTE.to_string(),
         ["", "pokemon", _name, "translation", ""] | ["", "pokemon", _name, "translation"] => {
-            "/pokemon/{name}/translation/".to_string()
+            TRANSLATION_ROUTE.to_string()
         }
+        ["", "pokemon", _name, "evolution"] => EVOLUTION_ROUTE.to_string(),
+        ["", "pokemon", _name, "descriptions"] => DESCRIPTIONS_ROUTE.to_string(),
 
         // Default: return as-is for unknown paths
         _ => path.to_string(),
@@ -235,6 +460,11 @@ mod tests {
         assert!(should_skip_tracking("/health"));
     }
 
+    #[test]
+    fn test_should_skip_tracking_ready() {
+        assert!(should_skip_tracking("/ready"));
+    }
+
     #[test]
     fn test_should_skip_tracking_metrics() {
         assert!(should_skip_tracking("/metrics"));
@@ -266,6 +496,32 @@ mod tests {
         assert_eq!(normalize_path("/pokemon/ditto"), "/pokemon/{name}");
     }
 
+    #[test]
+    fn test_normalize_path_list() {
+        assert_eq!(normalize_path("/pokemon"), "/pokemon");
+    }
+
+    #[test]
+    fn test_normalize_path_batch() {
+        assert_eq!(normalize_path("/pokemon/batch"), "/pokemon/batch");
+    }
+
+    #[test]
+    fn test_normalize_path_evolution() {
+        assert_eq!(
+            normalize_path("/pokemon/charmander/evolution"),
+            "/pokemon/{name}/evolution"
+        );
+    }
+
+    #[test]
+    fn test_normalize_path_descriptions() {
+        assert_eq!(
+            normalize_path("/pokemon/bulbasaur/descriptions"),
+            "/pokemon/{name}/descriptions"
+        );
+    }
+
     #[test]
     fn test_normalize_path_translation() {
         assert_eq!(
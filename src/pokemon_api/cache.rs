@@ -0,0 +1,382 @@
+//! # PokéAPI Response Cache
+//!
+//! This module provides an in-process caching decorator for [`PokemonApiProxy`]:
+//! - Base Pokémon, species and evolution chain responses are cached by name/URL with a
+//!   configurable TTL
+//! - The number of cached entries per resource is bounded (expired entries are evicted
+//!   first, then the oldest ones)
+//! - Concurrent misses for the same key are coalesced into a single upstream fetch
+//!
+//! PokéAPI data is effectively immutable, so caching it avoids paying two upstream
+//! round trips (`/pokemon/{name}` and `/pokemon-species/{id}`) for every request.
+
+use crate::{
+    http::client::HttpClientError,
+    metrics,
+    pokemon_api::{
+        client::{BasePokemonResponse, PokemonApiProxy, PokemonListResponse, SpeciesResponse},
+        evolution::EvolutionChainResponse,
+    },
+};
+use async_trait::async_trait;
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tokio::sync::OnceCell;
+use tracing::debug;
+
+/// A cache slot: created on the first miss and filled once the upstream fetch completes.
+///
+/// Callers racing on an empty slot wait on the same [`OnceCell`], so only one of them
+/// actually hits the upstream.
+struct Slot<V> {
+    created_at: Instant,
+    value: OnceCell<(V, Instant)>,
+}
+
+impl<V> Slot<V> {
+    fn new() -> Self {
+        Self {
+            created_at: Instant::now(),
+            value: OnceCell::new(),
+        }
+    }
+
+    /// Pending slots never expire: their fetch is still in flight.
+    fn is_expired(&self, ttl: Duration) -> bool {
+        self.value
+            .get()
+            .is_some_and(|(_, fetched_at)| fetched_at.elapsed() >= ttl)
+    }
+}
+
+/// Bounded TTL cache with miss coalescing.
+///
+/// Hits, misses and evictions are recorded in the `pokeapi_cache_*` metrics,
+/// labelled with the cached `resource`.
+struct TtlCache<V> {
+    resource: &'static str,
+    ttl: Duration,
+    max_entries: usize,
+    slots: Mutex<HashMap<String, Arc<Slot<V>>>>,
+}
+
+impl<V: Clone> TtlCache<V> {
+    fn new(resource: &'static str, ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            resource,
+            ttl,
+            max_entries,
+            slots: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the cached value for `key`, calling `fetch` on a miss.
+    ///
+    /// Errors are never cached: the slot is dropped so that the next caller retries.
+    async fn get_or_fetch<F, Fut>(&self, key: &str, fetch: F) -> Result<V, HttpClientError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V, HttpClientError>>,
+    {
+        let slot = self.slot(key);
+        let mut fetched = false;
+        let result = slot
+            .value
+            .get_or_try_init(|| {
+                fetched = true;
+                async { fetch().await.map(|value| (value, Instant::now())) }
+            })
+            .await
+            .map(|(value, _)| value.clone());
+
+        if fetched {
+            metrics::POKEAPI_CACHE_MISSES
+                .with_label_values(&[self.resource])
+                .inc();
+            debug!(resource = self.resource, key, "Cache miss");
+        } else {
+            metrics::POKEAPI_CACHE_HITS
+                .with_label_values(&[self.resource])
+                .inc();
+            debug!(resource = self.resource, key, "Cache hit");
+        }
+        if result.is_err() {
+            let mut slots = self.slots.lock().expect("cache lock poisoned");
+            if slots.get(key).is_some_and(|s| Arc::ptr_eq(s, &slot)) {
+                slots.remove(key);
+            }
+        }
+        result
+    }
+
+    /// Returns the live slot for `key`, creating (and making room for) a new one if needed.
+    fn slot(&self, key: &str) -> Arc<Slot<V>> {
+        let mut slots = self.slots.lock().expect("cache lock poisoned");
+        match slots.get(key) {
+            Some(slot) if !slot.is_expired(self.ttl) => return slot.clone(),
+            Some(_) => {
+                slots.remove(key);
+                self.record_evictions(1);
+            }
+            None => {}
+        }
+        if slots.len() >= self.max_entries {
+            self.evict(&mut slots);
+        }
+        let slot = Arc::new(Slot::new());
+        slots.insert(key.to_string(), slot.clone());
+        slot
+    }
+
+    /// Drops expired entries, then the oldest ones until there is room for a new entry.
+    fn evict(&self, slots: &mut HashMap<String, Arc<Slot<V>>>) {
+        let before = slots.len();
+        slots.retain(|_, slot| !slot.is_expired(self.ttl));
+        while slots.len() >= self.max_entries {
+            let oldest = slots
+                .iter()
+                .min_by_key(|(_, slot)| slot.created_at)
+                .map(|(key, _)| key.clone());
+            match oldest {
+                Some(key) => slots.remove(&key),
+                None => break,
+            };
+        }
+        self.record_evictions(before - slots.len());
+    }
+
+    fn record_evictions(&self, count: usize) {
+        if count > 0 {
+            metrics::POKEAPI_CACHE_EVICTIONS
+                .with_label_values(&[self.resource])
+                .inc_by(count as f64);
+            debug!(resource = self.resource, count, "Evicted cache entries");
+        }
+    }
+}
+
+/// Caching decorator for any [`PokemonApiProxy`] implementation.
+///
+/// Base Pokémon responses are keyed by (lowercased) name, species and evolution chain
+/// responses by URL.
+pub struct CachingPokemonApiProxy {
+    inner: Box<dyn PokemonApiProxy + Send + Sync>,
+    base_pokemon: TtlCache<BasePokemonResponse>,
+    species: TtlCache<SpeciesResponse>,
+    evolution_chains: TtlCache<EvolutionChainResponse>,
+}
+
+impl CachingPokemonApiProxy {
+    /// Creates a new caching proxy.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - Proxy used to fetch entries on cache misses
+    /// * `ttl` - How long a fetched entry is served from the cache
+    /// * `max_entries` - Maximum number of entries kept per resource
+    pub fn new(
+        inner: Box<dyn PokemonApiProxy + Send + Sync>,
+        ttl: Duration,
+        max_entries: usize,
+    ) -> Self {
+        Self {
+            inner,
+            base_pokemon: TtlCache::new("pokemon", ttl, max_entries),
+            species: TtlCache::new("species", ttl, max_entries),
+            evolution_chains: TtlCache::new("evolution_chain", ttl, max_entries),
+        }
+    }
+}
+
+#[async_trait]
+impl PokemonApiProxy for CachingPokemonApiProxy {
+    async fn get_base_pokemon(&self, name: &str) -> Result<BasePokemonResponse, HttpClientError> {
+        self.base_pokemon
+            .get_or_fetch(&name.to_lowercase(), || self.inner.get_base_pokemon(name))
+            .await
+    }
+
+    async fn get_species(&self, species_url: &str) -> Result<SpeciesResponse, HttpClientError> {
+        self.species
+            .get_or_fetch(species_url, || self.inner.get_species(species_url))
+            .await
+    }
+
+    async fn get_evolution_chain(
+        &self,
+        chain_url: &str,
+    ) -> Result<EvolutionChainResponse, HttpClientError> {
+        self.evolution_chains
+            .get_or_fetch(chain_url, || self.inner.get_evolution_chain(chain_url))
+            .await
+    }
+
+    /// Not cached: the list is only fetched by the periodically refreshed name index.
+    async fn get_pokemon_list(&self) -> Result<PokemonListResponse, HttpClientError> {
+        self.inner.get_pokemon_list().await
+    }
+
+    async fn probe(&self) -> Result<(), HttpClientError> {
+        self.inner.probe().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::client::ErrorKind;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Counts upstream calls, optionally failing them or slowing them down.
+    #[derive(Default)]
+    struct CountingClient {
+        base_calls: AtomicUsize,
+        species_calls: AtomicUsize,
+        delay: Duration,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl PokemonApiProxy for Arc<CountingClient> {
+        async fn get_base_pokemon(
+            &self,
+            name: &str,
+        ) -> Result<BasePokemonResponse, HttpClientError> {
+            self.base_calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(self.delay).await;
+            if self.fail {
+                return Err(ErrorKind::ServiceUnavailable.into());
+            }
+            Ok(serde_json::from_value(serde_json::json!({
+                "id": 25,
+                "name": name,
+                "species": { "url": "https://pokeapi.co/api/v2/pokemon-species/25" }
+            }))
+            .unwrap())
+        }
+
+        async fn get_species(
+            &self,
+            _species_url: &str,
+        ) -> Result<SpeciesResponse, HttpClientError> {
+            self.species_calls.fetch_add(1, Ordering::SeqCst);
+            Ok(serde_json::from_value(serde_json::json!({
+                "habitat": { "name": "forest" },
+                "is_legendary": false,
+                "flavor_text_entries": []
+            }))
+            .unwrap())
+        }
+
+        async fn get_evolution_chain(
+            &self,
+            _chain_url: &str,
+        ) -> Result<EvolutionChainResponse, HttpClientError> {
+            Err(ErrorKind::NotFound.into())
+        }
+
+        async fn get_pokemon_list(&self) -> Result<PokemonListResponse, HttpClientError> {
+            Err(ErrorKind::NotFound.into())
+        }
+    }
+
+    fn make_cache(
+        upstream: &Arc<CountingClient>,
+        ttl: Duration,
+        max_entries: usize,
+    ) -> CachingPokemonApiProxy {
+        CachingPokemonApiProxy::new(Box::new(upstream.clone()), ttl, max_entries)
+    }
+
+    #[tokio::test]
+    async fn serves_repeated_lookups_from_cache() {
+        let upstream = Arc::new(CountingClient::default());
+        let cache = make_cache(&upstream, Duration::from_secs(60), 10);
+
+        for _ in 0..3 {
+            assert!(cache.get_base_pokemon("pikachu").await.is_ok());
+            assert!(cache.get_species("species/25").await.is_ok());
+        }
+
+        assert_eq!(upstream.base_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(upstream.species_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn keys_base_pokemon_case_insensitively() {
+        let upstream = Arc::new(CountingClient::default());
+        let cache = make_cache(&upstream, Duration::from_secs(60), 10);
+
+        assert!(cache.get_base_pokemon("Pikachu").await.is_ok());
+        assert!(cache.get_base_pokemon("pikachu").await.is_ok());
+
+        assert_eq!(upstream.base_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn coalesces_concurrent_misses_for_the_same_key() {
+        let upstream = Arc::new(CountingClient {
+            delay: Duration::from_millis(50),
+            ..Default::default()
+        });
+        let cache = Arc::new(make_cache(&upstream, Duration::from_secs(60), 10));
+
+        let lookups = (0..10).map(|_| {
+            let cache = cache.clone();
+            tokio::spawn(async move { cache.get_base_pokemon("pikachu").await })
+        });
+        for lookup in lookups.collect::<Vec<_>>() {
+            assert!(lookup.await.unwrap().is_ok());
+        }
+
+        assert_eq!(upstream.base_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn refetches_expired_entries() {
+        let upstream = Arc::new(CountingClient::default());
+        let cache = make_cache(&upstream, Duration::from_millis(20), 10);
+
+        assert!(cache.get_base_pokemon("pikachu").await.is_ok());
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        assert!(cache.get_base_pokemon("pikachu").await.is_ok());
+
+        assert_eq!(upstream.base_calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn evicts_oldest_entry_when_full() {
+        let upstream = Arc::new(CountingClient::default());
+        let cache = make_cache(&upstream, Duration::from_secs(60), 2);
+
+        assert!(cache.get_base_pokemon("bulbasaur").await.is_ok());
+        assert!(cache.get_base_pokemon("ivysaur").await.is_ok());
+        assert!(cache.get_base_pokemon("venusaur").await.is_ok());
+        // still cached
+        assert!(cache.get_base_pokemon("venusaur").await.is_ok());
+        // evicted
+        assert!(cache.get_base_pokemon("bulbasaur").await.is_ok());
+
+        assert_eq!(upstream.base_calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn does_not_cache_errors() {
+        let upstream = Arc::new(CountingClient {
+            fail: true,
+            ..Default::default()
+        });
+        let cache = make_cache(&upstream, Duration::from_secs(60), 10);
+
+        let result = cache.get_base_pokemon("pikachu").await;
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::ServiceUnavailable);
+        let result = cache.get_base_pokemon("pikachu").await;
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::ServiceUnavailable);
+
+        assert_eq!(upstream.base_calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,156 @@
+//! # PokéAPI Circuit Breaker
+//!
+//! [`PokemonApiProxy`] decorator failing fast with `ErrorKind::ServiceUnavailable`
+//! while the PokéAPI circuit is open, instead of waiting on a failing upstream.
+
+use crate::{
+    http::{circuit_breaker::CircuitBreaker, client::HttpClientError},
+    pokemon_api::{
+        client::{BasePokemonResponse, PokemonApiProxy, PokemonListResponse, SpeciesResponse},
+        evolution::EvolutionChainResponse,
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Circuit breaking decorator for any [`PokemonApiProxy`] implementation.
+pub struct CircuitBreakingPokemonApiProxy {
+    inner: Box<dyn PokemonApiProxy + Send + Sync>,
+    breaker: Arc<CircuitBreaker>,
+}
+
+impl CircuitBreakingPokemonApiProxy {
+    /// Creates a new circuit breaking proxy.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - Proxy guarded by the breaker
+    /// * `breaker` - Circuit breaker shared with the readiness endpoint
+    pub fn new(
+        inner: Box<dyn PokemonApiProxy + Send + Sync>,
+        breaker: Arc<CircuitBreaker>,
+    ) -> Self {
+        Self { inner, breaker }
+    }
+}
+
+#[async_trait]
+impl PokemonApiProxy for CircuitBreakingPokemonApiProxy {
+    async fn get_base_pokemon(&self, name: &str) -> Result<BasePokemonResponse, HttpClientError> {
+        self.breaker
+            .call(|| self.inner.get_base_pokemon(name))
+            .await
+    }
+
+    async fn get_species(&self, species_url: &str) -> Result<SpeciesResponse, HttpClientError> {
+        self.breaker
+            .call(|| self.inner.get_species(species_url))
+            .await
+    }
+
+    async fn get_evolution_chain(
+        &self,
+        chain_url: &str,
+    ) -> Result<EvolutionChainResponse, HttpClientError> {
+        self.breaker
+            .call(|| self.inner.get_evolution_chain(chain_url))
+            .await
+    }
+
+    async fn get_pokemon_list(&self) -> Result<PokemonListResponse, HttpClientError> {
+        self.breaker.call(|| self.inner.get_pokemon_list()).await
+    }
+
+    /// Probes bypass the breaker, so that readiness reflects the actual upstream health.
+    async fn probe(&self) -> Result<(), HttpClientError> {
+        self.inner.probe().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::circuit_breaker::{CircuitBreakerConfig, CircuitState};
+    use crate::http::client::ErrorKind;
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    struct FailingClient {
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl PokemonApiProxy for FailingClient {
+        async fn get_base_pokemon(
+            &self,
+            _name: &str,
+        ) -> Result<BasePokemonResponse, HttpClientError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Err(ErrorKind::RequestFailed.into())
+        }
+
+        async fn get_species(
+            &self,
+            _species_url: &str,
+        ) -> Result<SpeciesResponse, HttpClientError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Err(ErrorKind::RequestFailed.into())
+        }
+
+        async fn get_evolution_chain(
+            &self,
+            _chain_url: &str,
+        ) -> Result<EvolutionChainResponse, HttpClientError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Err(ErrorKind::RequestFailed.into())
+        }
+
+        async fn get_pokemon_list(&self) -> Result<PokemonListResponse, HttpClientError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Err(ErrorKind::RequestFailed.into())
+        }
+    }
+
+    #[tokio::test]
+    async fn fails_fast_once_the_circuit_is_open() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let breaker = Arc::new(CircuitBreaker::new(
+            "pokeapi",
+            CircuitBreakerConfig {
+                failure_threshold: 2,
+                window: Duration::from_secs(60),
+                cool_down: Duration::from_secs(60),
+            },
+        ));
+        let proxy = CircuitBreakingPokemonApiProxy::new(
+            Box::new(FailingClient {
+                calls: calls.clone(),
+            }),
+            breaker.clone(),
+        );
+
+        assert!(
+            proxy
+                .get_base_pokemon("pikachu")
+                .await
+                .is_err_and(|e| e.kind() == &ErrorKind::RequestFailed)
+        );
+        assert!(
+            proxy
+                .get_species("species/25")
+                .await
+                .is_err_and(|e| e.kind() == &ErrorKind::RequestFailed)
+        );
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(
+            proxy
+                .get_base_pokemon("pikachu")
+                .await
+                .is_err_and(|e| e.kind() == &ErrorKind::ServiceUnavailable)
+        );
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
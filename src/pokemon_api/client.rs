@@ -1,33 +1,43 @@
 //! # Pokemon API Client
 //!
 //! This module handles integration with the [PokéAPI](https://pokeapi.co/) including:
-//! - Fetching base Pokémon data
+//! - Fetching base Pokémon data (types, abilities, base stats, height, weight and sprites)
 //! - Retrieving species information with flavor text descriptions
+//! - Following species evolution chains (see [`crate::pokemon_api::evolution`])
+//! - Listing every Pokémon name (see [`crate::pokemon_api::index`])
 //! - Language negotiation with fallback support
-//! - Automatic translator type selection based on Pokémon characteristics
 //!
 //! ## Language Negotiation
 //!
 //! The module supports RFC 7231 language negotiation with the following behavior:
-//! 1. Attempts to find a description in requested languages (in order)
-//! 2. Falls back to English if available and wildcard is present
-//! 3. Falls back to first available language if no match and wildcard is present
-//! 4. Returns `NotAcceptable` error if no suitable language found and no wildcard
+//! 1. Attempts to find a description in requested languages (in order), matching BCP 47
+//!    tags to PokéAPI languages (see [`crate::pokemon_api::language`])
+//! 2. Falls back on the configured fallback chains of the requested languages, then on the
+//!    default language (English unless configured), unless refused with `q=0`
+//! 3. Falls back to first acceptable language if no match and wildcard is present
+//! 4. Returns `NotAcceptable` error, listing the available languages, if no suitable language
+//!    found and no wildcard
 //!
-//! ## Translator Selection
-//!
-//! Translator type is automatically determined by the Pokémon's characteristics:
-//! - **Yoda translator**: Legendary Pokémon or cave habitat
-//! - **Shakespeare translator**: All other Pokémon
+//! The translator of a Pokémon description is selected by [`crate::translator::rules`].
 
 use crate::{
-    constants::DEFAULT_LANGUAGE,
-    http::client::{HttpClientError, TranslatorType},
+    constants::POKEAPI_UPSTREAM,
+    http::{
+        client::{ErrorKind, HttpClientError},
+        retry::RetryPolicy,
+    },
+    pokemon_api::{
+        descriptions::{self, PokemonDescriptions},
+        evolution::{Evolution, EvolutionChainResponse},
+        language::{LanguageFallback, LanguageFallbacks, LanguageMap, LanguagePreferences},
+        text::TextNormalizer,
+        version::{VersionFilter, VersionPolicy},
+    },
 };
 use async_trait::async_trait;
+use futures::future::try_join_all;
 use reqwest::StatusCode;
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use tracing::{debug, instrument};
 use utoipa::ToSchema;
 
@@ -35,7 +45,7 @@ use utoipa::ToSchema;
 ///
 /// This struct represents a Pokémon fetched from PokéAPI with additional metadata
 /// used for determining translation style.
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
 pub struct Pokemon {
     /// Pokemon ID
     pub id: i32,
@@ -46,24 +56,81 @@ pub struct Pokemon {
     /// Whether the Pokemon is legendary
     #[serde(rename = "isLegendary")]
     pub is_legendary: bool,
+    /// Whether the Pokemon is mythical
+    #[serde(rename = "isMythical")]
+    pub is_mythical: bool,
+    /// Generation the Pokemon was introduced in (e.g., generation-i)
+    pub generation: Option<String>,
     /// Pokemon description
     pub description: Option<String>,
+    /// Game version the description is taken from (e.g., sword)
+    #[serde(rename = "descriptionVersion")]
+    pub description_version: Option<String>,
+    /// Fallback step of the description language, returned in the `X-Language-Fallback` header
+    #[serde(skip)]
+    pub language_fallback: LanguageFallback,
+    /// Height, in decimetres
+    pub height: u32,
+    /// Weight, in hectograms
+    pub weight: u32,
+    /// Types, in slot order
+    pub types: Vec<PokemonType>,
+    /// Abilities, in slot order
+    pub abilities: Vec<PokemonAbility>,
+    /// Base stats (hp, attack, defense, special-attack, special-defense, speed)
+    pub stats: Vec<PokemonStat>,
+    /// Sprite URLs
+    pub sprites: PokemonSprites,
 }
 
-impl Pokemon {
-    /// Determines the appropriate translator type based on Pokémon characteristics.
-    ///
-    /// # Returns
-    ///
-    /// - `TranslatorType::Yoda` if the Pokémon is legendary or lives in caves
-    /// - `TranslatorType::Shakespeare` for all other Pokémon
-    pub fn get_translator(&self) -> TranslatorType {
-        match (self.habitat.as_deref(), self.is_legendary) {
-            (Some("cave"), _) => TranslatorType::Yoda,
-            (_, true) => TranslatorType::Yoda,
-            _ => TranslatorType::Shakespeare,
-        }
-    }
+/// Type of a Pokémon (e.g., electric).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PokemonType {
+    /// Slot of the type (1 for the primary type)
+    pub slot: u8,
+    /// Type name
+    pub name: String,
+}
+
+/// Ability of a Pokémon (e.g., static).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PokemonAbility {
+    /// Slot of the ability
+    pub slot: u8,
+    /// Ability name
+    pub name: String,
+    /// Whether the ability is hidden
+    #[serde(rename = "isHidden")]
+    pub is_hidden: bool,
+}
+
+/// Base stat of a Pokémon (e.g., speed).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PokemonStat {
+    /// Stat name
+    pub name: String,
+    /// Base value of the stat
+    #[serde(rename = "baseStat")]
+    pub base_stat: u32,
+    /// Effort points gained by defeating the Pokemon
+    pub effort: u32,
+}
+
+/// Sprite URLs of a Pokémon.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PokemonSprites {
+    /// Front sprite
+    #[serde(rename = "frontDefault")]
+    pub front_default: Option<String>,
+    /// Front sprite, shiny variant
+    #[serde(rename = "frontShiny")]
+    pub front_shiny: Option<String>,
+    /// Back sprite
+    #[serde(rename = "backDefault")]
+    pub back_default: Option<String>,
+    /// Back sprite, shiny variant
+    #[serde(rename = "backShiny")]
+    pub back_shiny: Option<String>,
 }
 
 /// Result type for Pokémon API operations.
@@ -73,41 +140,162 @@ impl Pokemon {
 pub type PokemonResult = Result<(String, Pokemon), HttpClientError>;
 
 /// Response from PokéAPI `/pokemon/{name}` endpoint.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct BasePokemonResponse {
     id: i32, // NOTE: i32 should be enough: there are many pokemon out there, but not that many!
     name: String,
     species: SpeciesReference,
+    #[serde(default)]
+    height: u32,
+    #[serde(default)]
+    weight: u32,
+    #[serde(default)]
+    types: Vec<TypeSlot>,
+    #[serde(default)]
+    abilities: Vec<AbilitySlot>,
+    #[serde(default)]
+    stats: Vec<StatEntry>,
+    #[serde(default)]
+    sprites: SpritesResponse,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct SpeciesReference {
     url: String,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+struct NamedReference {
+    name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct TypeSlot {
+    slot: u8,
+    #[serde(rename = "type")]
+    kind: NamedReference,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct AbilitySlot {
+    slot: u8,
+    ability: NamedReference,
+    is_hidden: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct StatEntry {
+    base_stat: u32,
+    effort: u32,
+    stat: NamedReference,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct SpritesResponse {
+    front_default: Option<String>,
+    front_shiny: Option<String>,
+    back_default: Option<String>,
+    back_shiny: Option<String>,
+}
+
+impl From<SpritesResponse> for PokemonSprites {
+    fn from(sprites: SpritesResponse) -> Self {
+        Self {
+            front_default: sprites.front_default,
+            front_shiny: sprites.front_shiny,
+            back_default: sprites.back_default,
+            back_shiny: sprites.back_shiny,
+        }
+    }
+}
+
 /// Response from PokéAPI `/pokemon-species/{id}` endpoint.
 ///
-/// Contains species-level metadata including habitat, legendary status,
-/// and multilingual flavor text descriptions.
-#[derive(Debug, Deserialize)]
+/// Contains species-level metadata including habitat, legendary and mythical status,
+/// generation, and multilingual flavor text descriptions.
+#[derive(Debug, Clone, Deserialize)]
 pub struct SpeciesResponse {
     habitat: Option<HabitatReference>,
     is_legendary: bool,
+    #[serde(default)]
+    is_mythical: bool,
+    #[serde(default)]
+    generation: Option<NamedReference>,
     flavor_text_entries: Vec<FlavorTextEntry>,
+    #[serde(default)]
+    evolution_chain: Option<EvolutionChainReference>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct EvolutionChainReference {
+    url: String,
+}
+
+/// Response from PokéAPI `/pokemon` list endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PokemonListResponse {
+    results: Vec<ListedPokemon>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ListedPokemon {
+    name: String,
+    url: String,
+}
+
+impl ListedPokemon {
+    /// Whether the Pokémon is the default form of its species: PokéAPI numbers the alternate
+    /// forms (e.g., `pikachu-rock-star`) from 10001.
+    fn is_species(&self) -> bool {
+        self.url
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .and_then(|id| id.parse::<u32>().ok())
+            .is_none_or(|id| id < FIRST_ALTERNATE_FORM_ID)
+    }
+}
+
+/// Pokémon name listed by PokéAPI.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PokemonName {
+    pub name: String,
+    /// Whether the Pokémon is the default form of its species, not an alternate form
+    /// (e.g., `pikachu-rock-star`, `charizard-mega-x`)
+    pub species: bool,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct HabitatReference {
     name: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct FlavorTextEntry {
     flavor_text: String,
     language: LanguageReference,
+    #[serde(default)]
+    version: Option<NamedReference>,
+}
+
+impl FlavorTextEntry {
+    fn version(&self) -> Option<&str> {
+        self.version.as_ref().map(|v| v.name.as_str())
+    }
+}
+
+/// Options of the descriptions returned by [`PokemonApi`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DescriptionOptions {
+    /// Whether to return descriptions as PokéAPI returns them, not normalized
+    /// (see [`crate::pokemon_api::text`])
+    pub raw: bool,
+    /// Game version to take descriptions from, instead of the configured
+    /// [`VersionPolicy`] (see [`crate::pokemon_api::version`])
+    pub version: Option<VersionFilter>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct LanguageReference {
     name: String,
 }
@@ -123,8 +311,8 @@ pub trait PokemonApi: Send + Sync {
     /// # Arguments
     ///
     /// * `name` - Pokémon name to fetch (case-insensitive)
-    /// * `languages` - List of preferred languages in priority order
-    /// * `has_wildcard` - Whether `Accept-Language` contains wildcard (`*`)
+    /// * `preferences` - Language preferences of `Accept-Language`
+    /// * `options` - Normalization and game version of the description
     ///
     /// # Returns
     ///
@@ -133,15 +321,73 @@ pub trait PokemonApi: Send + Sync {
     ///
     /// # Errors
     ///
-    /// - `NotFound` if Pokémon doesn't exist or has no descriptions
-    /// - `NotAcceptable` if no description in requested languages and no wildcard
+    /// - `NotFound` if Pokémon doesn't exist
+    /// - `NoDescription` if Pokémon has no descriptions (in the requested game version, if any)
+    /// - `NotAcceptable` if no description in requested languages and no wildcard, listing
+    ///   the available languages
     /// - `RequestFailed` or `ParseError` on API communication issues
+    /// - `Timeout` if PokéAPI does not answer in time
     async fn get_pokemon(
         &self,
         name: &str,
-        languages: &[String],
-        has_wildcard: bool,
+        preferences: &LanguagePreferences,
+        options: &DescriptionOptions,
     ) -> PokemonResult;
+
+    /// Fetches the evolution chain of a Pokémon.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pokémon name (case-insensitive)
+    /// * `preferences` - Language preferences of the stage descriptions
+    /// * `options` - Normalization and game version of the stage descriptions
+    ///
+    /// Stages without a description in an acceptable language have no description.
+    ///
+    /// # Errors
+    ///
+    /// - `NotFound` if the Pokémon doesn't exist or has no evolution chain
+    /// - `RequestFailed`, `ParseError` or `Timeout` on API communication issues
+    async fn get_evolution(
+        &self,
+        name: &str,
+        preferences: &LanguagePreferences,
+        options: &DescriptionOptions,
+    ) -> Result<Evolution, HttpClientError>;
+
+    /// Fetches every description of a Pokémon, grouped by language and distinct text
+    /// (see [`crate::pokemon_api::descriptions`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pokémon name (case-insensitive)
+    /// * `preferences` - Requested languages, the other ones being returned too with `*`
+    /// * `version` - Game version of the descriptions, if any
+    ///
+    /// # Errors
+    ///
+    /// - `NotFound` if the Pokémon doesn't exist
+    /// - `NoDescription` if the Pokémon has no descriptions (in the requested game version)
+    /// - `NotAcceptable` if no description in requested languages and no wildcard, listing
+    ///   the available languages
+    /// - `RequestFailed`, `ParseError` or `Timeout` on API communication issues
+    async fn get_descriptions(
+        &self,
+        name: &str,
+        preferences: &LanguagePreferences,
+        version: Option<&VersionFilter>,
+    ) -> Result<PokemonDescriptions, HttpClientError>;
+
+    /// Lists the names of every Pokémon, alternate forms included, in PokéAPI order
+    /// (national Pokédex first).
+    ///
+    /// # Errors
+    ///
+    /// - `RequestFailed`, `ParseError` or `Timeout` on API communication issues
+    async fn get_pokemon_names(&self) -> Result<Vec<PokemonName>, HttpClientError>;
+
+    /// Checks that the underlying upstream is reachable, bypassing caches and circuit breakers.
+    async fn probe(&self) -> Result<(), HttpClientError>;
 }
 
 /// Low-level trait for making HTTP requests to PokéAPI.
@@ -153,14 +399,34 @@ pub trait PokemonApiProxy: Send + Sync {
     async fn get_base_pokemon(&self, name: &str) -> Result<BasePokemonResponse, HttpClientError>;
     /// Fetches species data from the `/pokemon-species/{id}` endpoint.
     async fn get_species(&self, species_url: &str) -> Result<SpeciesResponse, HttpClientError>;
+    /// Fetches an evolution chain from the `/evolution-chain/{id}` endpoint.
+    async fn get_evolution_chain(
+        &self,
+        chain_url: &str,
+    ) -> Result<EvolutionChainResponse, HttpClientError>;
+    /// Fetches every Pokémon from the `/pokemon` list endpoint, in a single page.
+    async fn get_pokemon_list(&self) -> Result<PokemonListResponse, HttpClientError>;
+    /// Checks that PokéAPI is reachable with a lightweight request.
+    ///
+    /// Decorators forward the probe to the proxy they wrap; implementations not
+    /// talking to PokéAPI are always reachable.
+    async fn probe(&self) -> Result<(), HttpClientError> {
+        Ok(())
+    }
 }
 
+/// Page size large enough to list every Pokémon in a single request.
+const POKEAPI_LIST_LIMIT: usize = 100_000;
+/// First PokéAPI ID of the alternate forms of the listed Pokémon.
+const FIRST_ALTERNATE_FORM_ID: u32 = 10_001;
+
 /// HTTP client implementation for PokéAPI requests.
 ///
 /// Handles HTTP communication with PokéAPI including error handling and status code interpretation.
 pub struct PokemonApiProxyClient {
     client: reqwest::Client,
     base_url: String,
+    retry_policy: RetryPolicy,
 }
 
 impl PokemonApiProxyClient {
@@ -171,44 +437,98 @@ impl PokemonApiProxyClient {
     /// * `client` - Configured reqwest client
     /// * `base_url` - Base URL for PokéAPI (e.g., `https://pokeapi.co/api/v2`)
     pub fn new(client: reqwest::Client, base_url: String) -> Self {
-        PokemonApiProxyClient { client, base_url }
+        PokemonApiProxyClient {
+            client,
+            base_url,
+            retry_policy: RetryPolicy::none(),
+        }
+    }
+
+    /// Sets the retry policy applied to every PokéAPI request (no retries by default).
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Fetches a PokéAPI resource, mapping error statuses with `status_error`.
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        status_error: fn(StatusCode) -> Option<ErrorKind>,
+    ) -> Result<T, HttpClientError> {
+        let response = self
+            .retry_policy
+            .send(POKEAPI_UPSTREAM, || self.client.get(url))
+            .await?;
+        match status_error(response.status()) {
+            Some(kind) => {
+                Err(HttpClientError::from_response(POKEAPI_UPSTREAM, kind, response).await)
+            }
+            // NOTE: by default redirects followed automatically by reqwest::Client: https://docs.rs/reqwest/latest/reqwest/#redirect-policies
+            None => HttpClientError::read_json(POKEAPI_UPSTREAM, response).await,
+        }
     }
 }
 #[async_trait]
 impl PokemonApiProxy for PokemonApiProxyClient {
     async fn get_species(&self, species_url: &str) -> Result<SpeciesResponse, HttpClientError> {
-        self.client
-            .get(species_url)
-            .send()
-            .await
-            .map_err(|_| HttpClientError::RequestFailed)
-            .and_then(|r| match r.status() {
-                StatusCode::NOT_FOUND => Err(HttpClientError::NotFound),
-                StatusCode::SERVICE_UNAVAILABLE => Err(HttpClientError::ServiceUnavailable),
-                // NOTE: by default redirects followed automatically by reqwest::Client: https://docs.rs/reqwest/latest/reqwest/#redirect-policies
-                _ => Ok(r),
-            })?
-            .json::<SpeciesResponse>()
-            .await
-            .map_err(|_| HttpClientError::ParseError)
+        self.get_json(species_url, |status| match status {
+            StatusCode::NOT_FOUND => Some(ErrorKind::NotFound),
+            StatusCode::SERVICE_UNAVAILABLE => Some(ErrorKind::ServiceUnavailable),
+            _ => None,
+        })
+        .await
+    }
+
+    async fn get_evolution_chain(
+        &self,
+        chain_url: &str,
+    ) -> Result<EvolutionChainResponse, HttpClientError> {
+        self.get_json(chain_url, |status| match status {
+            StatusCode::NOT_FOUND => Some(ErrorKind::NotFound),
+            StatusCode::SERVICE_UNAVAILABLE => Some(ErrorKind::ServiceUnavailable),
+            _ => None,
+        })
+        .await
+    }
+
+    async fn get_pokemon_list(&self) -> Result<PokemonListResponse, HttpClientError> {
+        let url = format!("{}/pokemon?limit={}", self.base_url, POKEAPI_LIST_LIMIT);
+        self.get_json(&url, |status| match status {
+            StatusCode::SERVICE_UNAVAILABLE => Some(ErrorKind::ServiceUnavailable),
+            s if !s.is_success() => Some(ErrorKind::ServerError),
+            _ => None,
+        })
+        .await
     }
 
     async fn get_base_pokemon(&self, name: &str) -> Result<BasePokemonResponse, HttpClientError> {
-        self.client
-            .get(format!("{}/pokemon/{}", self.base_url, name))
+        let url = format!("{}/pokemon/{}", self.base_url, name);
+        self.get_json(&url, |status| match status {
+            StatusCode::NOT_FOUND => Some(ErrorKind::NotFound),
+            StatusCode::SERVICE_UNAVAILABLE => Some(ErrorKind::ServiceUnavailable),
+            StatusCode::INTERNAL_SERVER_ERROR => Some(ErrorKind::ServerError),
+            _ => None,
+        })
+        .await
+    }
+
+    /// Probes PokéAPI by listing a single Pokémon, without retries.
+    async fn probe(&self) -> Result<(), HttpClientError> {
+        let url = format!("{}/pokemon?limit=1", self.base_url);
+        let response = self
+            .client
+            .get(&url)
             .send()
             .await
-            .map_err(|_| HttpClientError::RequestFailed)
-            .and_then(|r| match r.status() {
-                StatusCode::NOT_FOUND => Err(HttpClientError::NotFound),
-                StatusCode::SERVICE_UNAVAILABLE => Err(HttpClientError::ServiceUnavailable),
-                StatusCode::INTERNAL_SERVER_ERROR => Err(HttpClientError::ServerError),
-                // NOTE: by default redirects followed automatically by reqwest::Client: https://docs.rs/reqwest/latest/reqwest/#redirect-policies
-                _ => Ok(r),
-            })?
-            .json::<BasePokemonResponse>()
-            .await
-            .map_err(|_| HttpClientError::ParseError)
+            .map_err(|e| HttpClientError::from_transport(POKEAPI_UPSTREAM, e))?;
+        let kind = match response.status() {
+            s if s.is_success() => return Ok(()),
+            StatusCode::TOO_MANY_REQUESTS => ErrorKind::RateLimited,
+            StatusCode::SERVICE_UNAVAILABLE => ErrorKind::ServiceUnavailable,
+            _ => ErrorKind::ServerError,
+        };
+        Err(HttpClientError::from_response(POKEAPI_UPSTREAM, kind, response).await)
     }
 }
 
@@ -218,6 +538,10 @@ impl PokemonApiProxy for PokemonApiProxyClient {
 /// descriptions based on requested languages with intelligent fallback.
 pub struct PokeApiClient {
     client: Box<dyn PokemonApiProxy + Send + Sync>,
+    normalizer: TextNormalizer,
+    version_policy: VersionPolicy,
+    language_map: LanguageMap,
+    language_fallbacks: LanguageFallbacks,
 }
 
 impl PokeApiClient {
@@ -227,7 +551,59 @@ impl PokeApiClient {
     ///
     /// * `client` - HTTP proxy implementation for making requests
     pub fn new(client: Box<dyn PokemonApiProxy + Send + Sync>) -> Self {
-        Self { client }
+        Self {
+            client,
+            normalizer: TextNormalizer::default(),
+            version_policy: VersionPolicy::default(),
+            language_map: LanguageMap::default(),
+            language_fallbacks: LanguageFallbacks::default(),
+        }
+    }
+
+    /// Sets the normalizer of the descriptions (no casing fixes by default).
+    pub fn with_text_normalizer(mut self, normalizer: TextNormalizer) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Sets the policy selecting the game version of the descriptions (latest by default).
+    pub fn with_version_policy(mut self, version_policy: VersionPolicy) -> Self {
+        self.version_policy = version_policy;
+        self
+    }
+
+    /// Sets the mapping of `Accept-Language` tags to PokéAPI languages (none by default).
+    pub fn with_language_map(mut self, language_map: LanguageMap) -> Self {
+        self.language_map = language_map;
+        self
+    }
+
+    /// Sets the fallback chains and the default language of the descriptions (English, without
+    /// chain, by default).
+    pub fn with_language_fallbacks(mut self, language_fallbacks: LanguageFallbacks) -> Self {
+        self.language_fallbacks = language_fallbacks;
+        self
+    }
+
+    /// Selects a description, normalized unless the caller asked for the `raw` one.
+    fn description(
+        &self,
+        flavor_text_entries: &[FlavorTextEntry],
+        preferences: &LanguagePreferences,
+        options: &DescriptionOptions,
+    ) -> Result<Description, HttpClientError> {
+        let mut description = select_description(
+            flavor_text_entries,
+            preferences,
+            &self.language_map,
+            &self.language_fallbacks,
+            options.version.as_ref(),
+            &self.version_policy,
+        )?;
+        if !options.raw {
+            description.text = self.normalizer.normalize(&description.text);
+        }
+        Ok(description)
     }
 }
 
@@ -237,70 +613,267 @@ impl PokemonApi for PokeApiClient {
     async fn get_pokemon(
         &self,
         name: &str,
-        languages: &[String],
-        has_wildcard: bool,
+        preferences: &LanguagePreferences,
+        options: &DescriptionOptions,
     ) -> PokemonResult {
         debug!("Fetching base pokemon data");
-        let BasePokemonResponse { id, name, species } = self.client.get_base_pokemon(name).await?;
+        let BasePokemonResponse {
+            id,
+            name,
+            species,
+            height,
+            weight,
+            types,
+            abilities,
+            stats,
+            sprites,
+        } = self.client.get_base_pokemon(name).await?;
 
         debug!(pokemon_id = id, species_url = %species.url, "Fetching species data");
         let SpeciesResponse {
             habitat,
             is_legendary,
+            is_mythical,
+            generation,
             flavor_text_entries,
+            ..
         } = self.client.get_species(&species.url).await?;
-        debug!(
-            available_languages = ?flavor_text_entries.iter().map(|e| &e.language.name).collect::<Vec<_>>(),
-            "Processing language descriptions"
-        );
+        let Description {
+            language,
+            text,
+            version,
+            fallback,
+        } = self.description(&flavor_text_entries, preferences, options)?;
+        Ok((
+            language,
+            Pokemon {
+                id,
+                name,
+                habitat: habitat.map(|h| h.name),
+                is_legendary,
+                is_mythical,
+                generation: generation.map(|g| g.name),
+                description: Some(text),
+                description_version: version,
+                language_fallback: fallback,
+                height,
+                weight,
+                types: types
+                    .into_iter()
+                    .map(|t| PokemonType {
+                        slot: t.slot,
+                        name: t.kind.name,
+                    })
+                    .collect(),
+                abilities: abilities
+                    .into_iter()
+                    .map(|a| PokemonAbility {
+                        slot: a.slot,
+                        name: a.ability.name,
+                        is_hidden: a.is_hidden,
+                    })
+                    .collect(),
+                stats: stats
+                    .into_iter()
+                    .map(|s| PokemonStat {
+                        name: s.stat.name,
+                        base_stat: s.base_stat,
+                        effort: s.effort,
+                    })
+                    .collect(),
+                sprites: sprites.into(),
+            },
+        ))
+    }
 
-        let flavor_texts: HashMap<&str, &str> = flavor_text_entries
-            .iter()
-            .map(|entry| (entry.language.name.as_str(), entry.flavor_text.as_str()))
-            .collect();
-        let description = languages
-            .iter()
-            .find_map(|lang| flavor_texts.get_key_value(lang.as_str()))
-            .or_else(|| flavor_texts.get_key_value(DEFAULT_LANGUAGE))
-            .map(|(lang, text)| (lang.to_string(), text.to_string()));
-        let not_acceptable = matches!((&description, has_wildcard), (None, false));
-        match (flavor_text_entries.first(), not_acceptable) {
-            // descriptions are empty
-            (None, _) => {
-                debug!("No descriptions available");
-                Err(HttpClientError::NotFound)
-            }
-            // no description found from requested languages and no wildcard to fall back on
-            (_, true) => {
-                debug!("Requested language not available and no wildcard");
-                Err(HttpClientError::NotAcceptable)
-            }
-            (Some(first), false) => {
-                let (lang, desc) = if let Some((l, t)) = description {
-                    debug!(selected_language = %l, "Using requested language");
-                    (l, t)
-                } else {
-                    debug!(fallback_language = %first.language.name, "Using fallback language");
-                    (first.language.name.clone(), first.flavor_text.clone())
-                };
-                Ok((
-                    lang,
-                    Pokemon {
-                        id,
-                        name,
-                        habitat: habitat.map(|h| h.name),
-                        is_legendary,
-                        description: Some(desc),
-                    },
-                ))
+    #[instrument(skip(self), fields(pokemon_name = %name))]
+    async fn get_evolution(
+        &self,
+        name: &str,
+        preferences: &LanguagePreferences,
+        options: &DescriptionOptions,
+    ) -> Result<Evolution, HttpClientError> {
+        debug!("Fetching base pokemon data");
+        let species_url = self.client.get_base_pokemon(name).await?.species.url;
+
+        debug!(species_url = %species_url, "Fetching species data");
+        let chain_url = self
+            .client
+            .get_species(&species_url)
+            .await?
+            .evolution_chain
+            .ok_or_else(|| HttpClientError::from(ErrorKind::NotFound))?
+            .url;
+
+        debug!(chain_url = %chain_url, "Fetching evolution chain");
+        let stages = self.client.get_evolution_chain(&chain_url).await?.stages();
+        let stages = try_join_all(stages.into_iter().map(|mut stage| async move {
+            let species = self.client.get_species(&stage.species_url).await?;
+            stage.habitat = species.habitat.map(|h| h.name);
+            stage.is_legendary = species.is_legendary;
+            stage.is_mythical = species.is_mythical;
+            stage.generation = species.generation.map(|g| g.name);
+            match self.description(&species.flavor_text_entries, preferences, options) {
+                Ok(description) => {
+                    stage.language = Some(description.language);
+                    stage.description = Some(description.text);
+                }
+                Err(e)
+                    if matches!(
+                        e.kind(),
+                        ErrorKind::NoDescription | ErrorKind::NotAcceptable(_)
+                    ) => {}
+                Err(e) => return Err(e),
             }
+            Ok(stage)
+        }))
+        .await?;
+
+        let pokemon = stages
+            .iter()
+            .find(|stage| stage.species_url == species_url)
+            .map_or_else(|| name.to_string(), |stage| stage.name.clone());
+        Ok(Evolution { pokemon, stages })
+    }
+
+    #[instrument(skip(self), fields(pokemon_name = %name))]
+    async fn get_descriptions(
+        &self,
+        name: &str,
+        preferences: &LanguagePreferences,
+        version: Option<&VersionFilter>,
+    ) -> Result<PokemonDescriptions, HttpClientError> {
+        debug!("Fetching base pokemon data");
+        let base = self.client.get_base_pokemon(name).await?;
+
+        debug!(species_url = %base.species.url, "Fetching species data");
+        let species = self.client.get_species(&base.species.url).await?;
+        descriptions::group(
+            base.name,
+            species
+                .flavor_text_entries
+                .iter()
+                .filter(|entry| version.is_none_or(|v| v.matches(entry.version())))
+                .map(|entry| {
+                    (
+                        entry.language.name.as_str(),
+                        entry.version(),
+                        entry.flavor_text.as_str(),
+                    )
+                }),
+            preferences,
+            &self.language_map,
+            &self.normalizer,
+        )
+    }
+
+    #[instrument(skip(self))]
+    async fn get_pokemon_names(&self) -> Result<Vec<PokemonName>, HttpClientError> {
+        debug!("Fetching pokemon list");
+        let PokemonListResponse { results } = self.client.get_pokemon_list().await?;
+        Ok(results
+            .into_iter()
+            .map(|pokemon| PokemonName {
+                species: pokemon.is_species(),
+                name: pokemon.name,
+            })
+            .collect())
+    }
+
+    async fn probe(&self) -> Result<(), HttpClientError> {
+        self.client.probe().await
+    }
+}
+
+/// Description selected among the flavor texts of a species.
+struct Description {
+    language: String,
+    text: String,
+    version: Option<String>,
+    fallback: LanguageFallback,
+}
+
+/// Selects the description matching the language preferences.
+///
+/// Returns the entry of the language selected by `fallbacks` (see
+/// [`LanguageFallbacks::select`]), requested languages being matched by `language_map`.
+/// Among the entries of that language, the entry of the requested game version (`version`)
+/// is returned, otherwise the entry chosen by `policy`.
+///
+/// # Errors
+///
+/// - `NoDescription` if there is no description at all (in the requested game version)
+/// - `NotAcceptable` if no description is in an acceptable language, listing the languages
+///   descriptions are available in
+fn select_description(
+    flavor_text_entries: &[FlavorTextEntry],
+    preferences: &LanguagePreferences,
+    language_map: &LanguageMap,
+    fallbacks: &LanguageFallbacks,
+    version: Option<&VersionFilter>,
+    policy: &VersionPolicy,
+) -> Result<Description, HttpClientError> {
+    debug!(
+        available_languages = ?flavor_text_entries.iter().map(|e| &e.language.name).collect::<Vec<_>>(),
+        "Processing language descriptions"
+    );
+
+    let entries: Vec<&FlavorTextEntry> = flavor_text_entries
+        .iter()
+        .filter(|entry| version.is_none_or(|v| v.matches(entry.version())))
+        .collect();
+    let mut available: Vec<&str> = Vec::new();
+    for entry in &entries {
+        if !available.contains(&entry.language.name.as_str()) {
+            available.push(&entry.language.name);
         }
     }
+    let selected = fallbacks.select(language_map, preferences, &available);
+    let (language, fallback) = match (available.first(), selected) {
+        // descriptions are empty
+        (None, _) => {
+            debug!(?version, "No descriptions available");
+            return Err(ErrorKind::NoDescription.into());
+        }
+        (_, Some((language, LanguageFallback::None))) => {
+            debug!(selected_language = %language, "Using requested language");
+            (language, LanguageFallback::None)
+        }
+        (_, Some((language, fallback))) => {
+            debug!(fallback_language = %language, fallback = fallback.as_str(), "Using fallback language");
+            (language, fallback)
+        }
+        // no description found from requested languages and no acceptable language to fall back on
+        (Some(_), None) => {
+            debug!("Requested language not available and no fallback");
+            return Err(ErrorKind::NotAcceptable(
+                available.into_iter().map(str::to_string).collect(),
+            )
+            .into());
+        }
+    };
+    let entry = policy
+        .choose(
+            entries
+                .iter()
+                .filter(|entry| entry.language.name == language),
+            |entry| entry.version(),
+        )
+        .ok_or_else(|| HttpClientError::from(ErrorKind::NoDescription))?;
+    debug!(version = entry.version(), "Using description version");
+    Ok(Description {
+        language: language.to_string(),
+        text: entry.flavor_text.clone(),
+        version: entry.version().map(str::to_string),
+        fallback,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{constants::DEFAULT_LANGUAGE, pokemon_api::evolution::EvolutionTrigger};
+    use std::collections::HashMap;
 
     struct MockBaseClient {
         base: BasePokemonResponse,
@@ -313,59 +886,121 @@ mod tests {
             &self,
             _name: &str,
         ) -> Result<BasePokemonResponse, HttpClientError> {
-            Ok(BasePokemonResponse {
-                id: self.base.id,
-                name: self.base.name.clone(),
-                species: SpeciesReference {
-                    url: self.base.species.url.clone(),
-                },
-            })
+            Ok(self.base.clone())
         }
 
         async fn get_species(
             &self,
             _species_url: &str,
         ) -> Result<SpeciesResponse, HttpClientError> {
-            Ok(SpeciesResponse {
-                habitat: self.species.habitat.as_ref().map(|h| HabitatReference {
-                    name: h.name.clone(),
-                }),
-                is_legendary: self.species.is_legendary,
-                flavor_text_entries: self
-                    .species
-                    .flavor_text_entries
-                    .iter()
-                    .map(|f| FlavorTextEntry {
-                        flavor_text: f.flavor_text.clone(),
-                        language: LanguageReference {
-                            name: f.language.name.clone(),
-                        },
-                    })
-                    .collect(),
-            })
+            Ok(self.species.clone())
+        }
+
+        async fn get_evolution_chain(
+            &self,
+            _chain_url: &str,
+        ) -> Result<EvolutionChainResponse, HttpClientError> {
+            Err(ErrorKind::NotFound.into())
+        }
+
+        async fn get_pokemon_list(&self) -> Result<PokemonListResponse, HttpClientError> {
+            Err(ErrorKind::NotFound.into())
         }
     }
 
+    /// Base data of pikachu, as returned by PokéAPI (trimmed down).
+    fn base_pokemon() -> BasePokemonResponse {
+        serde_json::from_value(serde_json::json!({
+            "id": 25,
+            "name": "pikachu",
+            "species": { "name": "pikachu", "url": "https://pokeapi.co/api/v2/pokemon-species/25" },
+            "height": 4,
+            "weight": 60,
+            "types": [
+                { "slot": 1, "type": { "name": "electric", "url": "https://pokeapi.co/api/v2/type/13/" } }
+            ],
+            "abilities": [
+                { "ability": { "name": "static", "url": "https://pokeapi.co/api/v2/ability/9/" }, "is_hidden": false, "slot": 1 },
+                { "ability": { "name": "lightning-rod", "url": "https://pokeapi.co/api/v2/ability/31/" }, "is_hidden": true, "slot": 3 }
+            ],
+            "stats": [
+                { "base_stat": 35, "effort": 0, "stat": { "name": "hp", "url": "https://pokeapi.co/api/v2/stat/1/" } },
+                { "base_stat": 90, "effort": 2, "stat": { "name": "speed", "url": "https://pokeapi.co/api/v2/stat/6/" } }
+            ],
+            "sprites": {
+                "front_default": "https://raw.githubusercontent.com/PokeAPI/sprites/master/sprites/pokemon/25.png",
+                "front_shiny": null,
+                "back_default": null,
+                "back_shiny": null,
+                "other": {}
+            }
+        }))
+        .unwrap()
+    }
+
     fn make_client(flavor_entries: Vec<FlavorTextEntry>) -> PokeApiClient {
-        let base = BasePokemonResponse {
-            id: 25,
-            name: "pikachu".to_string(),
-            species: SpeciesReference {
-                url: "https://pokeapi.co/api/v2/pokemon-species/25".to_string(),
-            },
-        };
+        let base = base_pokemon();
         let species = SpeciesResponse {
             habitat: Some(HabitatReference {
                 name: "forest".to_string(),
             }),
             is_legendary: false,
+            is_mythical: false,
+            generation: Some(NamedReference {
+                name: "generation-i".to_string(),
+            }),
             flavor_text_entries: flavor_entries,
+            evolution_chain: None,
         };
 
         let mock = MockBaseClient { base, species };
         PokeApiClient::new(Box::new(mock))
     }
 
+    #[tokio::test]
+    async fn normalizes_descriptions_unless_raw() {
+        let raw = "When several of\nthese POKéMON\ngather, their\u{c}electricity could\nbuild and cause\nlightning storms.";
+        let client = make_client(vec![FlavorTextEntry {
+            flavor_text: raw.to_string(),
+            language: LanguageReference {
+                name: DEFAULT_LANGUAGE.to_string(),
+            },
+            version: None,
+        }])
+        .with_text_normalizer(TextNormalizer::new(vec![(
+            "POKéMON".to_string(),
+            "Pokémon".to_string(),
+        )]));
+
+        let (_lang, pokemon) = client
+            .get_pokemon(
+                "pikachu",
+                &LanguagePreferences::new(&["en"], false),
+                &DescriptionOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            pokemon.description.as_deref(),
+            Some(
+                "When several of these Pokémon gather, their electricity could build and cause lightning storms."
+            )
+        );
+
+        let (_lang, pokemon) = client
+            .get_pokemon(
+                "pikachu",
+                &LanguagePreferences::new(&["en"], false),
+                &DescriptionOptions {
+                    raw: true,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(pokemon.description.as_deref(), Some(raw));
+    }
+
     #[tokio::test]
     async fn returns_english_description_when_present() {
         let client = make_client(vec![
@@ -374,26 +1009,78 @@ mod tests {
                 language: LanguageReference {
                     name: DEFAULT_LANGUAGE.to_string(),
                 },
+                version: None,
             },
             FlavorTextEntry {
                 flavor_text: "Una descripcion.".to_string(),
                 language: LanguageReference {
                     name: "es".to_string(),
                 },
+                version: None,
             },
         ]);
 
         let (_lang, pokemon) = client
-            .get_pokemon("pikachu", &["en".to_string()], false)
+            .get_pokemon(
+                "pikachu",
+                &LanguagePreferences::new(&["en"], false),
+                &DescriptionOptions::default(),
+            )
             .await
             .unwrap();
 
         assert_eq!(pokemon.name, "pikachu");
         assert_eq!(pokemon.habitat.as_deref(), Some("forest"));
         assert!(!pokemon.is_legendary);
+        assert!(!pokemon.is_mythical);
+        assert_eq!(pokemon.generation.as_deref(), Some("generation-i"));
         assert_eq!(pokemon.description.as_deref(), Some("A forest mouse."));
     }
 
+    #[tokio::test]
+    async fn maps_types_abilities_stats_and_sprites() {
+        let client = make_client(vec![FlavorTextEntry {
+            flavor_text: "A forest mouse.".to_string(),
+            language: LanguageReference {
+                name: DEFAULT_LANGUAGE.to_string(),
+            },
+            version: None,
+        }]);
+
+        let (_lang, pokemon) = client
+            .get_pokemon(
+                "pikachu",
+                &LanguagePreferences::any(),
+                &DescriptionOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!((pokemon.height, pokemon.weight), (4, 60));
+        assert_eq!(
+            pokemon.types,
+            vec![PokemonType {
+                slot: 1,
+                name: "electric".to_string()
+            }]
+        );
+        assert_eq!(pokemon.abilities[1].name, "lightning-rod");
+        assert!(pokemon.abilities[1].is_hidden);
+        assert_eq!(
+            pokemon.stats[1],
+            PokemonStat {
+                name: "speed".to_string(),
+                base_stat: 90,
+                effort: 2
+            }
+        );
+        assert_eq!(
+            pokemon.sprites.front_default.as_deref(),
+            Some("https://raw.githubusercontent.com/PokeAPI/sprites/master/sprites/pokemon/25.png")
+        );
+        assert_eq!(pokemon.sprites.back_default, None);
+    }
+
     #[tokio::test]
     async fn falls_back_to_first_description_when_no_english() {
         let client = make_client(vec![FlavorTextEntry {
@@ -401,47 +1088,356 @@ mod tests {
             language: LanguageReference {
                 name: "es".to_string(),
             },
+            version: None,
         }]);
 
         // Should return NotAcceptable if no wildcard and language not present
         let result = client
-            .get_pokemon("pikachu", &["en".to_string()], false)
+            .get_pokemon(
+                "pikachu",
+                &LanguagePreferences::new(&["en"], false),
+                &DescriptionOptions::default(),
+            )
             .await;
-        assert!(matches!(result, Err(HttpClientError::NotAcceptable)));
+        assert_eq!(
+            result.unwrap_err().kind(),
+            &ErrorKind::NotAcceptable(vec!["es".to_string()])
+        );
 
         // Should fall back to first if wildcard is allowed
-        let (_lang, pokemon) = client.get_pokemon("pikachu", &[], true).await.unwrap();
+        let (_lang, pokemon) = client
+            .get_pokemon(
+                "pikachu",
+                &LanguagePreferences::any(),
+                &DescriptionOptions::default(),
+            )
+            .await
+            .unwrap();
         assert_eq!(
             pokemon.description.as_deref(),
             Some("Descripcion por defecto.")
         );
+        assert_eq!(pokemon.language_fallback, LanguageFallback::Wildcard);
+    }
+
+    #[tokio::test]
+    async fn negotiates_regional_tags_and_exclusions() {
+        let entry = |text: &str, language: &str| FlavorTextEntry {
+            flavor_text: text.to_string(),
+            language: LanguageReference {
+                name: language.to_string(),
+            },
+            version: None,
+        };
+        let client = make_client(vec![
+            entry("ピカチュウ", "ja-Hrkt"),
+            entry("皮卡丘", "zh-Hant"),
+            entry("Electric mouse.", "en"),
+        ])
+        .with_language_map(LanguageMap::new(vec![(
+            "zh-TW".to_string(),
+            "zh-Hant".to_string(),
+        )]));
+        let language = |header: &'static str| {
+            let client = &client;
+            async move {
+                client
+                    .get_pokemon(
+                        "pikachu",
+                        &LanguagePreferences::parse(Some(header)),
+                        &DescriptionOptions::default(),
+                    )
+                    .await
+                    .map(|(language, _)| language)
+            }
+        };
+
+        assert_eq!(language("zh-TW").await.unwrap(), "zh-Hant");
+        assert_eq!(language("ja-Hrkt-JP, en").await.unwrap(), "ja-Hrkt");
+        // English is refused, the wildcard falls back on the first acceptable language
+        assert_eq!(language("fr, en;q=0, *").await.unwrap(), "ja-Hrkt");
+        assert_eq!(
+            language("fr, en;q=0").await.unwrap_err().kind(),
+            &ErrorKind::NotAcceptable(vec![
+                "ja-Hrkt".to_string(),
+                "zh-Hant".to_string(),
+                "en".to_string()
+            ])
+        );
     }
 
     #[tokio::test]
-    async fn returns_not_found_when_no_descriptions() {
+    async fn returns_no_description_when_no_descriptions() {
         let client = make_client(vec![]);
 
         let result = client
-            .get_pokemon("pikachu", &["en".to_string()], false)
+            .get_pokemon(
+                "pikachu",
+                &LanguagePreferences::new(&["en"], false),
+                &DescriptionOptions::default(),
+            )
             .await;
 
-        assert!(matches!(result, Err(HttpClientError::NotFound)));
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::NoDescription);
+    }
+
+    #[tokio::test]
+    async fn selects_the_description_version_by_query_or_policy() {
+        let entry = |text: &str, version: &str| FlavorTextEntry {
+            flavor_text: text.to_string(),
+            language: LanguageReference {
+                name: DEFAULT_LANGUAGE.to_string(),
+            },
+            version: Some(NamedReference {
+                name: version.to_string(),
+            }),
+        };
+        let entries = || {
+            vec![
+                entry("Sword text.", "sword"),
+                entry("Red text.", "red"),
+                entry("Blue text.", "blue"),
+                entry("X text.", "x"),
+            ]
+        };
+        async fn description(
+            client: &PokeApiClient,
+            version: Option<VersionFilter>,
+        ) -> Result<(String, String), HttpClientError> {
+            let options = DescriptionOptions {
+                version,
+                ..Default::default()
+            };
+            client
+                .get_pokemon(
+                    "pikachu",
+                    &LanguagePreferences::new(&["en"], false),
+                    &options,
+                )
+                .await
+                .map(|(_, p)| (p.description.unwrap(), p.description_version.unwrap()))
+        }
+
+        let client = make_client(entries());
+        assert_eq!(
+            description(&client, None).await.unwrap(),
+            ("Sword text.".to_string(), "sword".to_string())
+        );
+        let red_blue = VersionFilter::from_query(None, Some("red-blue")).unwrap();
+        assert_eq!(
+            description(&client, red_blue).await.unwrap(),
+            ("Blue text.".to_string(), "blue".to_string())
+        );
+        let yellow = VersionFilter::from_query(Some("yellow"), None).unwrap();
+        assert!(
+            description(&client, yellow)
+                .await
+                .is_err_and(|e| e.kind() == &ErrorKind::NoDescription)
+        );
+
+        let client = make_client(entries()).with_version_policy(VersionPolicy::Earliest);
+        assert_eq!(description(&client, None).await.unwrap().1, "red");
+        let client =
+            make_client(entries()).with_version_policy(VersionPolicy::parse("y,x").unwrap());
+        assert_eq!(description(&client, None).await.unwrap().1, "x");
+    }
+
+    #[tokio::test]
+    async fn lists_descriptions_of_the_requested_versions() {
+        let entry = |text: &str, version: &str| FlavorTextEntry {
+            flavor_text: text.to_string(),
+            language: LanguageReference {
+                name: DEFAULT_LANGUAGE.to_string(),
+            },
+            version: Some(NamedReference {
+                name: version.to_string(),
+            }),
+        };
+        let client = make_client(vec![
+            entry("A forest\nmouse.", "red"),
+            entry("A forest mouse.", "blue"),
+            entry("A yellow mouse.", "yellow"),
+        ]);
+
+        let red_blue = VersionFilter::from_query(None, Some("red-blue")).unwrap();
+        let descriptions = client
+            .get_descriptions("pikachu", &LanguagePreferences::any(), red_blue.as_ref())
+            .await
+            .unwrap();
+
+        assert_eq!(descriptions.pokemon, "pikachu");
+        let entries = &descriptions.languages[0].entries;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].versions, vec!["red", "blue"]);
+        assert_eq!(entries[0].text, "A forest mouse.");
+        assert_eq!(entries[0].raw, "A forest\nmouse.");
+
+        let gold = VersionFilter::from_query(Some("gold"), None).unwrap();
+        let result = client
+            .get_descriptions("pikachu", &LanguagePreferences::any(), gold.as_ref())
+            .await;
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::NoDescription);
     }
 
     #[tokio::test]
     async fn returns_not_acceptable_when_language_not_available_and_no_wildcard() {
-        let client = make_client(vec![FlavorTextEntry {
-            flavor_text: "Beschreibung auf Deutsch.".to_string(),
+        let entry = |language: &str| FlavorTextEntry {
+            flavor_text: "Beschreibung.".to_string(),
             language: LanguageReference {
-                name: "de".to_string(),
+                name: language.to_string(),
             },
-        }]);
+            version: None,
+        };
+        let client = make_client(vec![entry("de"), entry("ja"), entry("de")]);
 
         let result = client
-            .get_pokemon("pikachu", &["fr".to_string()], false)
+            .get_pokemon(
+                "pikachu",
+                &LanguagePreferences::new(&["fr"], false),
+                &DescriptionOptions::default(),
+            )
             .await;
 
-        assert!(matches!(result, Err(HttpClientError::NotAcceptable)));
+        assert_eq!(
+            result.unwrap_err().kind(),
+            &ErrorKind::NotAcceptable(vec!["de".to_string(), "ja".to_string()])
+        );
+    }
+
+    /// Serves species by URL, and the Charmander evolution chain.
+    struct MockEvolutionClient {
+        species: HashMap<String, serde_json::Value>,
+    }
+
+    impl MockEvolutionClient {
+        fn new() -> Self {
+            let species = |habitat: &str, is_legendary: bool, descriptions: serde_json::Value| {
+                serde_json::json!({
+                    "habitat": { "name": habitat },
+                    "is_legendary": is_legendary,
+                    "generation": { "name": "generation-i" },
+                    "flavor_text_entries": descriptions,
+                    "evolution_chain": { "url": "https://pokeapi.co/api/v2/evolution-chain/2/" }
+                })
+            };
+            let english = |text: &str| serde_json::json!([{ "flavor_text": text, "language": { "name": "en" } }]);
+            Self {
+                species: HashMap::from([
+                    (
+                        "https://pokeapi.co/api/v2/pokemon-species/4/".to_string(),
+                        species("mountain", false, english("Lizard")),
+                    ),
+                    (
+                        "https://pokeapi.co/api/v2/pokemon-species/5/".to_string(),
+                        species("mountain", false, serde_json::json!([])),
+                    ),
+                    (
+                        "https://pokeapi.co/api/v2/pokemon-species/6/".to_string(),
+                        species("cave", false, english("Flame")),
+                    ),
+                ]),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl PokemonApiProxy for MockEvolutionClient {
+        async fn get_base_pokemon(
+            &self,
+            name: &str,
+        ) -> Result<BasePokemonResponse, HttpClientError> {
+            match name {
+                "charmeleon" => Ok(serde_json::from_value(serde_json::json!({
+                    "id": 5,
+                    "name": "charmeleon",
+                    "species": { "name": "charmeleon", "url": "https://pokeapi.co/api/v2/pokemon-species/5/" }
+                }))
+                .unwrap()),
+                _ => Err(ErrorKind::NotFound.into()),
+            }
+        }
+
+        async fn get_species(&self, species_url: &str) -> Result<SpeciesResponse, HttpClientError> {
+            self.species
+                .get(species_url)
+                .map(|species| serde_json::from_value(species.clone()).unwrap())
+                .ok_or_else(|| ErrorKind::NotFound.into())
+        }
+
+        async fn get_evolution_chain(
+            &self,
+            _chain_url: &str,
+        ) -> Result<EvolutionChainResponse, HttpClientError> {
+            let link = |name: &str, id: u32, level: Option<u32>, next: serde_json::Value| {
+                serde_json::json!({
+                    "species": { "name": name, "url": format!("https://pokeapi.co/api/v2/pokemon-species/{id}/") },
+                    "evolution_details": level.map_or_else(Vec::new, |level| vec![serde_json::json!({
+                        "trigger": { "name": "level-up" },
+                        "min_level": level
+                    })]),
+                    "evolves_to": next
+                })
+            };
+            Ok(serde_json::from_value(serde_json::json!({
+                "chain": link("charmander", 4, None, serde_json::json!([
+                    link("charmeleon", 5, Some(16), serde_json::json!([
+                        link("charizard", 6, Some(36), serde_json::json!([]))
+                    ]))
+                ]))
+            }))
+            .unwrap())
+        }
+
+        async fn get_pokemon_list(&self) -> Result<PokemonListResponse, HttpClientError> {
+            Err(ErrorKind::NotFound.into())
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_evolution_stages_with_species_data() {
+        let client = PokeApiClient::new(Box::new(MockEvolutionClient::new()));
+
+        let evolution = client
+            .get_evolution(
+                "charmeleon",
+                &LanguagePreferences::new(&["en"], false),
+                &DescriptionOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(evolution.pokemon, "charmeleon");
+        let names: Vec<_> = evolution.stages.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["charmander", "charmeleon", "charizard"]);
+        assert_eq!(evolution.stages[0].description.as_deref(), Some("Lizard"));
+        assert_eq!(evolution.stages[0].language.as_deref(), Some("en"));
+        assert_eq!(evolution.stages[1].description, None);
+        assert_eq!(evolution.stages[2].habitat.as_deref(), Some("cave"));
+        assert_eq!(
+            evolution.stages[2].generation.as_deref(),
+            Some("generation-i")
+        );
+        assert_eq!(
+            evolution.stages[2].triggers,
+            vec![EvolutionTrigger::Level {
+                min_level: Some(36)
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_for_unknown_pokemon_evolution() {
+        let client = PokeApiClient::new(Box::new(MockEvolutionClient::new()));
+
+        let result = client
+            .get_evolution(
+                "agumon",
+                &LanguagePreferences::any(),
+                &DescriptionOptions::default(),
+            )
+            .await;
+
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::NotFound);
     }
 
     struct MockServiceUnavailableClient;
@@ -452,14 +1448,25 @@ mod tests {
             &self,
             _name: &str,
         ) -> Result<BasePokemonResponse, HttpClientError> {
-            Err(HttpClientError::ServiceUnavailable)
+            Err(ErrorKind::ServiceUnavailable.into())
         }
 
         async fn get_species(
             &self,
             _species_url: &str,
         ) -> Result<SpeciesResponse, HttpClientError> {
-            Err(HttpClientError::ServiceUnavailable)
+            Err(ErrorKind::ServiceUnavailable.into())
+        }
+
+        async fn get_evolution_chain(
+            &self,
+            _chain_url: &str,
+        ) -> Result<EvolutionChainResponse, HttpClientError> {
+            Err(ErrorKind::ServiceUnavailable.into())
+        }
+
+        async fn get_pokemon_list(&self) -> Result<PokemonListResponse, HttpClientError> {
+            Err(ErrorKind::ServiceUnavailable.into())
         }
     }
 
@@ -468,21 +1475,19 @@ mod tests {
         let client = PokeApiClient::new(Box::new(MockServiceUnavailableClient));
 
         let result = client
-            .get_pokemon("pikachu", &["en".to_string()], false)
+            .get_pokemon(
+                "pikachu",
+                &LanguagePreferences::new(&["en"], false),
+                &DescriptionOptions::default(),
+            )
             .await;
 
-        assert!(matches!(result, Err(HttpClientError::ServiceUnavailable)));
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::ServiceUnavailable);
     }
 
     #[tokio::test]
     async fn returns_service_unavailable_on_species_unavailable() {
-        let base = BasePokemonResponse {
-            id: 25,
-            name: "pikachu".to_string(),
-            species: SpeciesReference {
-                url: "https://pokeapi.co/api/v2/pokemon-species/25".to_string(),
-            },
-        };
+        let base = base_pokemon();
 
         struct MockPartiallyUnavailableClient {
             base: BasePokemonResponse,
@@ -494,30 +1499,39 @@ mod tests {
                 &self,
                 _name: &str,
             ) -> Result<BasePokemonResponse, HttpClientError> {
-                Ok(BasePokemonResponse {
-                    id: self.base.id,
-                    name: self.base.name.clone(),
-                    species: SpeciesReference {
-                        url: self.base.species.url.clone(),
-                    },
-                })
+                Ok(self.base.clone())
             }
 
             async fn get_species(
                 &self,
                 _species_url: &str,
             ) -> Result<SpeciesResponse, HttpClientError> {
-                Err(HttpClientError::ServiceUnavailable)
+                Err(ErrorKind::ServiceUnavailable.into())
+            }
+
+            async fn get_evolution_chain(
+                &self,
+                _chain_url: &str,
+            ) -> Result<EvolutionChainResponse, HttpClientError> {
+                Err(ErrorKind::ServiceUnavailable.into())
+            }
+
+            async fn get_pokemon_list(&self) -> Result<PokemonListResponse, HttpClientError> {
+                Err(ErrorKind::ServiceUnavailable.into())
             }
         }
 
         let client = PokeApiClient::new(Box::new(MockPartiallyUnavailableClient { base }));
 
         let result = client
-            .get_pokemon("pikachu", &["en".to_string()], false)
+            .get_pokemon(
+                "pikachu",
+                &LanguagePreferences::new(&["en"], false),
+                &DescriptionOptions::default(),
+            )
             .await;
 
-        assert!(matches!(result, Err(HttpClientError::ServiceUnavailable)));
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::ServiceUnavailable);
     }
 
     struct MockRateLimitedClient;
@@ -528,14 +1542,25 @@ mod tests {
             &self,
             _name: &str,
         ) -> Result<BasePokemonResponse, HttpClientError> {
-            Err(HttpClientError::RateLimited)
+            Err(ErrorKind::RateLimited.into())
         }
 
         async fn get_species(
             &self,
             _species_url: &str,
         ) -> Result<SpeciesResponse, HttpClientError> {
-            Err(HttpClientError::RateLimited)
+            Err(ErrorKind::RateLimited.into())
+        }
+
+        async fn get_evolution_chain(
+            &self,
+            _chain_url: &str,
+        ) -> Result<EvolutionChainResponse, HttpClientError> {
+            Err(ErrorKind::RateLimited.into())
+        }
+
+        async fn get_pokemon_list(&self) -> Result<PokemonListResponse, HttpClientError> {
+            Err(ErrorKind::RateLimited.into())
         }
     }
 
@@ -544,78 +1569,83 @@ mod tests {
         let client = PokeApiClient::new(Box::new(MockRateLimitedClient));
 
         let result = client
-            .get_pokemon("pikachu", &["en".to_string()], false)
+            .get_pokemon(
+                "pikachu",
+                &LanguagePreferences::new(&["en"], false),
+                &DescriptionOptions::default(),
+            )
             .await;
 
-        assert!(matches!(result, Err(HttpClientError::RateLimited)));
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::RateLimited);
     }
 
-    mod get_translator_tests {
-        use super::*;
-
-        #[test]
-        fn returns_yoda_for_cave_habitat() {
-            let pokemon = Pokemon {
-                id: 1,
-                name: "zubat".to_string(),
-                habitat: Some("cave".to_string()),
-                is_legendary: false,
-                description: None,
-            };
-
-            assert_eq!(pokemon.get_translator(), TranslatorType::Yoda);
-        }
-
-        #[test]
-        fn returns_yoda_for_legendary_pokemon() {
-            let pokemon = Pokemon {
-                id: 144,
-                name: "articuno".to_string(),
-                habitat: Some("sky".to_string()),
-                is_legendary: true,
-                description: None,
-            };
-
-            assert_eq!(pokemon.get_translator(), TranslatorType::Yoda);
-        }
+    #[tokio::test]
+    async fn probe_lists_a_single_pokemon() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/pokemon?limit=1")
+            .with_status(200)
+            .with_body(r#"{"count":1302,"results":[]}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let client = PokemonApiProxyClient::new(reqwest::Client::new(), server.url());
 
-        #[test]
-        fn returns_yoda_for_cave_habitat_and_legendary() {
-            let pokemon = Pokemon {
-                id: 150,
-                name: "mewtwo".to_string(),
-                habitat: Some("cave".to_string()),
-                is_legendary: true,
-                description: None,
-            };
+        assert!(client.probe().await.is_ok());
+        mock.assert_async().await;
+    }
 
-            assert_eq!(pokemon.get_translator(), TranslatorType::Yoda);
-        }
+    #[tokio::test]
+    async fn lists_every_pokemon_name_in_a_single_page() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/pokemon?limit=100000")
+            .with_status(200)
+            .with_body(
+                r#"{"count":2,"next":null,"previous":null,"results":[
+                    {"name":"bulbasaur","url":"https://pokeapi.co/api/v2/pokemon/1/"},
+                    {"name":"ivysaur","url":"https://pokeapi.co/api/v2/pokemon/2/"},
+                    {"name":"venusaur-mega","url":"https://pokeapi.co/api/v2/pokemon/10033/"}
+                ]}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let client = PokeApiClient::new(Box::new(PokemonApiProxyClient::new(
+            reqwest::Client::new(),
+            server.url(),
+        )));
 
-        #[test]
-        fn returns_shakespeare_for_non_cave_non_legendary() {
-            let pokemon = Pokemon {
-                id: 25,
-                name: "pikachu".to_string(),
-                habitat: Some("forest".to_string()),
-                is_legendary: false,
-                description: None,
-            };
+        let names = client.get_pokemon_names().await.unwrap();
 
-            assert_eq!(pokemon.get_translator(), TranslatorType::Shakespeare);
-        }
+        let name = |name: &str, species| PokemonName {
+            name: name.to_string(),
+            species,
+        };
+        assert_eq!(
+            names,
+            vec![
+                name("bulbasaur", true),
+                name("ivysaur", true),
+                name("venusaur-mega", false)
+            ]
+        );
+        mock.assert_async().await;
+    }
 
-        #[test]
-        fn returns_shakespeare_for_no_habitat_non_legendary() {
-            let pokemon = Pokemon {
-                id: 132,
-                name: "ditto".to_string(),
-                habitat: None,
-                is_legendary: false,
-                description: None,
-            };
+    #[tokio::test]
+    async fn probe_fails_when_pokeapi_is_unavailable() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/pokemon?limit=1")
+            .with_status(503)
+            .create_async()
+            .await;
+        let client = PokemonApiProxyClient::new(reqwest::Client::new(), server.url());
 
-            assert_eq!(pokemon.get_translator(), TranslatorType::Shakespeare);
-        }
+        assert_eq!(
+            client.probe().await.unwrap_err().kind(),
+            &ErrorKind::ServiceUnavailable
+        );
     }
 }
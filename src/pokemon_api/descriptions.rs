@@ -0,0 +1,219 @@
+//! # Pokémon Descriptions
+//!
+//! Every flavor text of a Pokémon (`GET /pokemon/{name}/descriptions`), so that descriptions
+//! can be compared across games and languages:
+//! - Entries are grouped by language, in `Accept-Language` order when languages are
+//!   requested (see [`crate::pokemon_api::language`]), otherwise in PokéAPI order
+//! - Within a language, games sharing the same (normalized) text are merged into a single
+//!   entry listing their versions, in PokéAPI order
+//! - Each entry has the normalized text (see [`crate::pokemon_api::text`]) and the raw text of
+//!   its first version side by side
+
+use crate::{
+    http::client::{ErrorKind, HttpClientError},
+    pokemon_api::{
+        language::{LanguageMap, LanguagePreferences},
+        text::TextNormalizer,
+    },
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Every description of a Pokémon.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct PokemonDescriptions {
+    /// Pokemon name
+    pub pokemon: String,
+    /// Descriptions, by language
+    pub languages: Vec<LanguageDescriptions>,
+}
+
+/// Descriptions of a Pokémon in a language.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct LanguageDescriptions {
+    /// Language code (e.g., en)
+    pub language: String,
+    /// Distinct descriptions, in PokéAPI order
+    pub entries: Vec<DescriptionEntry>,
+}
+
+/// Description shared by one or several game versions.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct DescriptionEntry {
+    /// Game versions with this description (e.g., red, blue)
+    pub versions: Vec<String>,
+    /// Normalized description
+    pub text: String,
+    /// Description as PokéAPI returns it, for the first version
+    pub raw: String,
+}
+
+/// Flavor text of a species: language, game version and raw text.
+pub type FlavorText<'a> = (&'a str, Option<&'a str>, &'a str);
+
+/// Groups flavor texts by language and distinct normalized text.
+///
+/// # Arguments
+///
+/// * `pokemon` - Pokemon name
+/// * `flavor_texts` - Flavor texts of the species, in PokéAPI order
+/// * `preferences` - Requested languages, the other ones being returned too, after the
+///   requested ones, with `*`
+/// * `language_map` - Mapping of the requested tags to PokéAPI languages
+/// * `normalizer` - Normalizer of the texts
+///
+/// # Errors
+///
+/// - `NoDescription` if there is no flavor text at all
+/// - `NotAcceptable` if no flavor text is in an acceptable language, listing the languages
+///   flavor texts are available in
+pub fn group<'a>(
+    pokemon: String,
+    flavor_texts: impl IntoIterator<Item = FlavorText<'a>>,
+    preferences: &LanguagePreferences,
+    language_map: &LanguageMap,
+    normalizer: &TextNormalizer,
+) -> Result<PokemonDescriptions, HttpClientError> {
+    let mut grouped: Vec<LanguageDescriptions> = Vec::new();
+    for (language, version, raw) in flavor_texts {
+        let text = normalizer.normalize(raw);
+        let position = match grouped.iter().position(|g| g.language == language) {
+            Some(position) => position,
+            None => {
+                grouped.push(LanguageDescriptions {
+                    language: language.to_string(),
+                    entries: vec![],
+                });
+                grouped.len() - 1
+            }
+        };
+        let entries = &mut grouped[position].entries;
+        let versions = version.map(str::to_string).into_iter();
+        match entries.iter_mut().find(|entry| entry.text == text) {
+            Some(entry) => entry.versions.extend(versions),
+            None => entries.push(DescriptionEntry {
+                versions: versions.collect(),
+                text,
+                raw: raw.to_string(),
+            }),
+        }
+    }
+    if grouped.is_empty() {
+        return Err(ErrorKind::NoDescription.into());
+    }
+    let available: Vec<String> = grouped.iter().map(|g| g.language.clone()).collect();
+    let negotiated: Vec<String> = language_map
+        .negotiate(
+            preferences,
+            &available.iter().map(String::as_str).collect::<Vec<_>>(),
+        )
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    if negotiated.is_empty() {
+        return Err(ErrorKind::NotAcceptable(available).into());
+    }
+    let mut languages = Vec::with_capacity(negotiated.len());
+    for language in negotiated {
+        if let Some(position) = grouped.iter().position(|g| g.language == language) {
+            languages.push(grouped.swap_remove(position));
+        }
+    }
+    Ok(PokemonDescriptions { pokemon, languages })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FLAVOR_TEXTS: [FlavorText; 5] = [
+        (
+            "en",
+            Some("red"),
+            "A strange seed was\nplanted on its\nback at birth.",
+        ),
+        (
+            "en",
+            Some("blue"),
+            "A strange seed was\nplanted on its\nback at birth.",
+        ),
+        (
+            "ja",
+            Some("x"),
+            "うまれたときから　せなかに\nしょくぶつの　タネが　あって\nすこし　ずつ　おおきく　そだつ。",
+        ),
+        (
+            "en",
+            Some("yellow"),
+            "It can go for days\nwithout eating a\nsingle morsel.",
+        ),
+        (
+            "en",
+            Some("firered"),
+            "A strange seed was planted\non its back at birth.",
+        ),
+    ];
+
+    fn group_texts(
+        languages: &[&str],
+        has_wildcard: bool,
+    ) -> Result<PokemonDescriptions, HttpClientError> {
+        group(
+            "bulbasaur".to_string(),
+            FLAVOR_TEXTS,
+            &LanguagePreferences::new(languages, has_wildcard),
+            &LanguageMap::default(),
+            &TextNormalizer::default(),
+        )
+    }
+
+    #[test]
+    fn merges_versions_sharing_the_same_text() {
+        let descriptions = group_texts(&[], true).unwrap();
+
+        assert_eq!(descriptions.pokemon, "bulbasaur");
+        let english = &descriptions.languages[0];
+        assert_eq!(english.language, "en");
+        assert_eq!(english.entries.len(), 2);
+        assert_eq!(english.entries[0].versions, vec!["red", "blue", "firered"]);
+        assert_eq!(
+            english.entries[0].text,
+            "A strange seed was planted on its back at birth."
+        );
+        assert_eq!(english.entries[0].raw, FLAVOR_TEXTS[0].2);
+        assert_eq!(english.entries[1].versions, vec!["yellow"]);
+        assert_eq!(descriptions.languages[1].language, "ja");
+    }
+
+    #[test]
+    fn filters_languages_in_preference_order() {
+        let languages = |result: Result<PokemonDescriptions, HttpClientError>| {
+            result
+                .unwrap()
+                .languages
+                .into_iter()
+                .map(|l| l.language)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(languages(group_texts(&["ja-JP", "fr"], false)), vec!["ja"]);
+        assert_eq!(languages(group_texts(&["ja"], true)), vec!["ja", "en"]);
+        assert_eq!(
+            group_texts(&["fr"], false).unwrap_err().kind(),
+            &ErrorKind::NotAcceptable(vec!["en".to_string(), "ja".to_string()])
+        );
+    }
+
+    #[test]
+    fn fails_without_flavor_text() {
+        let result = group(
+            "missingno".to_string(),
+            [],
+            &LanguagePreferences::any(),
+            &LanguageMap::default(),
+            &TextNormalizer::default(),
+        );
+
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::NoDescription);
+    }
+}
@@ -0,0 +1,304 @@
+//! # Evolution Chains
+//!
+//! PokéAPI describes evolutions as a recursive chain of species (`/evolution-chain/{id}`).
+//! This module flattens it into a list of stages, base species first (depth-first order):
+//! every stage names the species it evolves from and into, and how it is reached from
+//! the previous stage (level, item, trade, happiness).
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Response from PokéAPI `/evolution-chain/{id}` endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EvolutionChainResponse {
+    chain: ChainLink,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ChainLink {
+    species: SpeciesLink,
+    #[serde(default)]
+    evolution_details: Vec<EvolutionDetail>,
+    #[serde(default)]
+    evolves_to: Vec<ChainLink>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct SpeciesLink {
+    name: String,
+    url: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct NamedLink {
+    name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct EvolutionDetail {
+    trigger: NamedLink,
+    min_level: Option<u32>,
+    min_happiness: Option<u32>,
+    item: Option<NamedLink>,
+    held_item: Option<NamedLink>,
+}
+
+/// Evolution chain of a Pokémon.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Evolution {
+    /// Species of the requested Pokemon
+    pub pokemon: String,
+    /// Stages of the chain, base species first
+    pub stages: Vec<EvolutionStage>,
+}
+
+/// Stage of an evolution chain.
+#[derive(Debug, Clone, Default, Serialize, ToSchema)]
+pub struct EvolutionStage {
+    /// Species name
+    pub name: String,
+    /// Number of evolutions from the base species (0 for the base species)
+    pub depth: usize,
+    /// Species this stage evolves from
+    #[serde(rename = "evolvesFrom")]
+    pub evolves_from: Option<String>,
+    /// Species this stage evolves into
+    #[serde(rename = "evolvesTo")]
+    pub evolves_to: Vec<String>,
+    /// Ways of reaching this stage from the previous one (empty for the base species)
+    pub triggers: Vec<EvolutionTrigger>,
+    /// Species habitat (e.g., cave, forest)
+    pub habitat: Option<String>,
+    /// Whether the species is legendary
+    #[serde(rename = "isLegendary")]
+    pub is_legendary: bool,
+    /// Whether the species is mythical
+    #[serde(rename = "isMythical")]
+    pub is_mythical: bool,
+    /// Generation the species was introduced in (e.g., generation-i)
+    pub generation: Option<String>,
+    /// Species description, if available in an accepted language
+    pub description: Option<String>,
+    /// Language of the description
+    pub language: Option<String>,
+    /// Whether the description was translated
+    pub translated: bool,
+    /// PokéAPI URL of the species
+    #[serde(skip)]
+    pub(crate) species_url: String,
+}
+
+/// How an evolution is triggered.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(tag = "trigger", rename_all = "snake_case")]
+pub enum EvolutionTrigger {
+    /// Leveling up, from a minimum level if any
+    Level {
+        #[serde(rename = "minLevel")]
+        min_level: Option<u32>,
+    },
+    /// Leveling up with a minimum happiness
+    Happiness {
+        #[serde(rename = "minHappiness")]
+        min_happiness: u32,
+    },
+    /// Using an item (e.g., thunder-stone)
+    Item { item: String },
+    /// Trading, holding an item if any
+    Trade {
+        #[serde(rename = "heldItem")]
+        held_item: Option<String>,
+    },
+    /// Any other trigger (e.g., shed, spin), by PokéAPI name
+    Other { name: String },
+}
+
+impl From<EvolutionDetail> for EvolutionTrigger {
+    fn from(detail: EvolutionDetail) -> Self {
+        match detail.trigger.name.as_str() {
+            "level-up" => match detail.min_happiness {
+                Some(min_happiness) => Self::Happiness { min_happiness },
+                None => Self::Level {
+                    min_level: detail.min_level,
+                },
+            },
+            "use-item" => match detail.item {
+                Some(item) => Self::Item { item: item.name },
+                None => Self::Other {
+                    name: detail.trigger.name,
+                },
+            },
+            "trade" => Self::Trade {
+                held_item: detail.held_item.map(|item| item.name),
+            },
+            _ => Self::Other {
+                name: detail.trigger.name,
+            },
+        }
+    }
+}
+
+impl EvolutionChainResponse {
+    /// Flattens the chain into stages, base species first (depth-first order).
+    ///
+    /// Species data (habitat, description, ...) is left empty.
+    pub fn stages(self) -> Vec<EvolutionStage> {
+        let mut stages = Vec::new();
+        flatten(self.chain, None, 0, &mut stages);
+        stages
+    }
+}
+
+fn flatten(
+    link: ChainLink,
+    evolves_from: Option<String>,
+    depth: usize,
+    stages: &mut Vec<EvolutionStage>,
+) {
+    let name = link.species.name;
+    stages.push(EvolutionStage {
+        name: name.clone(),
+        depth,
+        evolves_from,
+        evolves_to: link
+            .evolves_to
+            .iter()
+            .map(|next| next.species.name.clone())
+            .collect(),
+        triggers: link
+            .evolution_details
+            .into_iter()
+            .map(EvolutionTrigger::from)
+            .collect(),
+        species_url: link.species.url,
+        ..Default::default()
+    });
+    for next in link.evolves_to {
+        flatten(next, Some(name.clone()), depth + 1, stages);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn species(name: &str, id: u32) -> serde_json::Value {
+        serde_json::json!({
+            "name": name,
+            "url": format!("https://pokeapi.co/api/v2/pokemon-species/{id}/")
+        })
+    }
+
+    fn detail(trigger: &str, extra: serde_json::Value) -> serde_json::Value {
+        let mut detail = serde_json::json!({
+            "trigger": { "name": trigger, "url": "https://pokeapi.co/api/v2/evolution-trigger/1/" },
+            "min_level": null,
+            "min_happiness": null,
+            "item": null,
+            "held_item": null,
+            "time_of_day": ""
+        });
+        detail
+            .as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        detail
+    }
+
+    /// Pichu → Pikachu → Raichu, plus a made-up branch to cover every trigger.
+    fn chain() -> EvolutionChainResponse {
+        serde_json::from_value(serde_json::json!({
+            "id": 10,
+            "chain": {
+                "species": species("pichu", 172),
+                "is_baby": true,
+                "evolution_details": [],
+                "evolves_to": [
+                    {
+                        "species": species("pikachu", 25),
+                        "evolution_details": [detail("level-up", serde_json::json!({"min_happiness": 220}))],
+                        "evolves_to": [
+                            {
+                                "species": species("raichu", 26),
+                                "evolution_details": [detail("use-item", serde_json::json!({"item": {"name": "thunder-stone"}}))],
+                                "evolves_to": []
+                            }
+                        ]
+                    },
+                    {
+                        "species": species("pikachu-trade", 10000),
+                        "evolution_details": [
+                            detail("trade", serde_json::json!({"held_item": {"name": "metal-coat"}})),
+                            detail("level-up", serde_json::json!({"min_level": 30})),
+                            detail("shed", serde_json::json!({}))
+                        ],
+                        "evolves_to": []
+                    }
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn flattens_the_chain_depth_first() {
+        let stages = chain().stages();
+
+        let names: Vec<_> = stages.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["pichu", "pikachu", "raichu", "pikachu-trade"]);
+        let depths: Vec<_> = stages.iter().map(|s| s.depth).collect();
+        assert_eq!(depths, vec![0, 1, 2, 1]);
+
+        assert_eq!(stages[0].evolves_from, None);
+        assert_eq!(stages[0].evolves_to, vec!["pikachu", "pikachu-trade"]);
+        assert_eq!(stages[2].evolves_from.as_deref(), Some("pikachu"));
+        assert!(stages[2].evolves_to.is_empty());
+        assert_eq!(
+            stages[1].species_url,
+            "https://pokeapi.co/api/v2/pokemon-species/25/"
+        );
+    }
+
+    #[test]
+    fn maps_evolution_triggers() {
+        let stages = chain().stages();
+
+        assert!(stages[0].triggers.is_empty());
+        assert_eq!(
+            stages[1].triggers,
+            vec![EvolutionTrigger::Happiness { min_happiness: 220 }]
+        );
+        assert_eq!(
+            stages[2].triggers,
+            vec![EvolutionTrigger::Item {
+                item: "thunder-stone".to_string()
+            }]
+        );
+        assert_eq!(
+            stages[3].triggers,
+            vec![
+                EvolutionTrigger::Trade {
+                    held_item: Some("metal-coat".to_string())
+                },
+                EvolutionTrigger::Level {
+                    min_level: Some(30)
+                },
+                EvolutionTrigger::Other {
+                    name: "shed".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn serializes_triggers_with_their_kind() {
+        let trigger = EvolutionTrigger::Level {
+            min_level: Some(16),
+        };
+
+        assert_eq!(
+            serde_json::to_value(trigger).unwrap(),
+            serde_json::json!({"trigger": "level", "minLevel": 16})
+        );
+    }
+}
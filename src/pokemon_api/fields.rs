@@ -0,0 +1,130 @@
+//! # Sparse Fieldsets
+//!
+//! Selection of the [`Pokemon`] fields returned to clients (e.g., `?fields=name,types`),
+//! so that clients only download the data they use.
+//!
+//! Field names are the JSON names of the `Pokemon` schema (case-insensitive); without
+//! selection, every field is returned.
+
+use crate::pokemon_api::client::Pokemon;
+use serde_json::{Map, Value};
+
+/// JSON names of the [`Pokemon`] fields, in schema order.
+pub const POKEMON_FIELDS: [&str; 14] = [
+    "id",
+    "name",
+    "habitat",
+    "isLegendary",
+    "isMythical",
+    "generation",
+    "description",
+    "descriptionVersion",
+    "height",
+    "weight",
+    "types",
+    "abilities",
+    "stats",
+    "sprites",
+];
+
+/// Fields of a [`Pokemon`] to return.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FieldSelection(Option<Vec<&'static str>>);
+
+impl FieldSelection {
+    /// Parses a comma separated list of field names.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if a field is unknown, or if no field is listed.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut fields = Vec::new();
+        for requested in value.split(',').map(str::trim).filter(|f| !f.is_empty()) {
+            let field = POKEMON_FIELDS
+                .into_iter()
+                .find(|field| field.eq_ignore_ascii_case(requested))
+                .ok_or_else(|| {
+                    format!(
+                        "unknown field '{}' (expected any of {})",
+                        requested,
+                        POKEMON_FIELDS.join(", ")
+                    )
+                })?;
+            if !fields.contains(&field) {
+                fields.push(field);
+            }
+        }
+        match fields.is_empty() {
+            true => Err("no field selected".to_string()),
+            false => Ok(Self(Some(fields))),
+        }
+    }
+
+    /// Serializes the selected fields of `pokemon`.
+    pub fn apply(&self, pokemon: &Pokemon) -> Value {
+        let value = serde_json::to_value(pokemon).expect("Pokemon serializes to JSON");
+        match (&self.0, value) {
+            (Some(fields), Value::Object(object)) => Value::Object(
+                object
+                    .into_iter()
+                    .filter(|(name, _)| fields.contains(&name.as_str()))
+                    .collect::<Map<_, _>>(),
+            ),
+            (_, value) => value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pikachu() -> Pokemon {
+        Pokemon {
+            id: 25,
+            name: "pikachu".to_string(),
+            height: 4,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn field_names_match_the_pokemon_schema() {
+        let Value::Object(object) = serde_json::to_value(pikachu()).unwrap() else {
+            panic!("Pokemon is not serialized as an object");
+        };
+
+        let mut names: Vec<&str> = object.keys().map(String::as_str).collect();
+        let mut expected = POKEMON_FIELDS.to_vec();
+        names.sort_unstable();
+        expected.sort_unstable();
+        assert_eq!(names, expected);
+    }
+
+    #[test]
+    fn keeps_only_the_selected_fields() {
+        let selection = FieldSelection::parse("name, HEIGHT,name").unwrap();
+
+        assert_eq!(
+            selection.apply(&pikachu()),
+            serde_json::json!({"name": "pikachu", "height": 4})
+        );
+    }
+
+    #[test]
+    fn returns_every_field_without_selection() {
+        let value = FieldSelection::default().apply(&pikachu());
+
+        assert_eq!(value.as_object().unwrap().len(), POKEMON_FIELDS.len());
+    }
+
+    #[test]
+    fn rejects_unknown_or_missing_fields() {
+        assert!(
+            FieldSelection::parse("name,nickname")
+                .unwrap_err()
+                .contains("nickname")
+        );
+        assert!(FieldSelection::parse(" , ").is_err());
+    }
+}
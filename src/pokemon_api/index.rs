@@ -0,0 +1,477 @@
+//! # Pokémon Name Index
+//!
+//! In-memory index of every Pokémon name, backing the listing and search endpoint
+//! (`GET /pokemon?offset=&limit=&q=`):
+//! - Names are loaded from PokéAPI's `/pokemon` list resource, then refreshed periodically
+//!   (or on the first request, if PokéAPI was unreachable so far)
+//! - Searches match names starting with or containing the query, then names within a small
+//!   edit distance of it, so that typos (e.g., "pikahcu") still find their Pokémon
+//! - Results are paginated with an offset and a limit, and linked with RFC 8288 `Link`
+//!   headers (`rel="next"`, `rel="prev"`)
+//!
+//! The index also suggests the closest known species names for a misspelled Pokémon (e.g.,
+//! "pikachuu"), ranked by Jaro-Winkler similarity: alternate forms (e.g.,
+//! "pikachu-rock-star") are listed and searched, never suggested.
+
+use crate::{
+    constants::LIST_ROUTE,
+    http::client::HttpClientError,
+    metrics,
+    pokemon_api::client::{PokemonApi, PokemonName},
+};
+use serde::Serialize;
+use std::{
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+use utoipa::ToSchema;
+
+/// Page size when the request sets no limit.
+pub const DEFAULT_PAGE_LIMIT: usize = 20;
+/// Largest page size a request may ask for.
+pub const MAX_PAGE_LIMIT: usize = 100;
+/// Queries shorter than this only match prefixes and substrings.
+const FUZZY_MIN_QUERY_LEN: usize = 3;
+/// Maximum number of names suggested for an unknown Pokémon.
+const MAX_SUGGESTIONS: usize = 3;
+/// Minimum Jaro-Winkler similarity of a suggested name.
+const MIN_SUGGESTION_SIMILARITY: f64 = 0.8;
+
+/// Listed Pokémon.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct PokemonListItem {
+    /// Pokemon name
+    pub name: String,
+    /// Path of the Pokemon resource
+    pub url: String,
+}
+
+/// Page of Pokémon names.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PokemonPage {
+    /// Total number of matching Pokemon
+    pub count: usize,
+    /// Position of the first result among the matching Pokemon
+    pub offset: usize,
+    /// Maximum number of results of the page
+    pub limit: usize,
+    /// Matching Pokemon: best matches first when searching, Pokédex order otherwise
+    pub results: Vec<PokemonListItem>,
+}
+
+impl PokemonPage {
+    /// Builds the RFC 8288 `Link` header value of the previous and next pages, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Search query of the page, repeated in the links
+    pub fn links(&self, query: Option<&str>) -> Option<String> {
+        let link = |offset: usize, rel: &str| {
+            let search = query
+                .map(|q| format!("&q={}", urlencoding::encode(q)))
+                .unwrap_or_default();
+            format!(
+                "<{}?offset={}&limit={}{}>; rel=\"{}\"",
+                LIST_ROUTE, offset, self.limit, search, rel
+            )
+        };
+        let next = self
+            .offset
+            .checked_add(self.limit)
+            .filter(|&next| next < self.count)
+            .map(|next| link(next, "next"));
+        // past the end, the previous page is the last one
+        let prev = (self.offset > 0).then(|| match self.offset < self.count {
+            true => link(self.offset.saturating_sub(self.limit), "prev"),
+            false => link(
+                self.count.saturating_sub(1) / self.limit * self.limit,
+                "prev",
+            ),
+        });
+        let links: Vec<String> = next.into_iter().chain(prev).collect();
+        (!links.is_empty()).then(|| links.join(", "))
+    }
+}
+
+/// Indexed names.
+#[derive(Default)]
+struct Names {
+    /// Every Pokémon, alternate forms included, in PokéAPI order
+    all: Vec<String>,
+    /// Default forms of the species, suggested for unknown names
+    species: Vec<String>,
+}
+
+impl From<Vec<PokemonName>> for Names {
+    fn from(names: Vec<PokemonName>) -> Self {
+        let species = names
+            .iter()
+            .filter(|name| name.species)
+            .map(|name| name.name.clone())
+            .collect();
+        Self {
+            all: names.into_iter().map(|name| name.name).collect(),
+            species,
+        }
+    }
+}
+
+/// Periodically refreshed index of the Pokémon names.
+pub struct NameIndex {
+    pokemon_api: Arc<dyn PokemonApi>,
+    names: RwLock<Arc<Names>>,
+    /// Held while a search loads the empty index, so that concurrent searches wait for it
+    loading: Mutex<()>,
+}
+
+impl NameIndex {
+    /// Creates an empty index, loaded on the first refresh.
+    ///
+    /// # Arguments
+    ///
+    /// * `pokemon_api` - Pokemon API client listing the names
+    pub fn new(pokemon_api: Arc<dyn PokemonApi>) -> Self {
+        Self {
+            pokemon_api,
+            names: RwLock::new(Arc::new(Names::default())),
+            loading: Mutex::new(()),
+        }
+    }
+
+    /// Reloads the names from PokéAPI, keeping the current ones on failure.
+    ///
+    /// Returns the number of indexed names.
+    pub async fn refresh(&self) -> Result<usize, HttpClientError> {
+        let names = match self.pokemon_api.get_pokemon_names().await {
+            Ok(names) => names,
+            Err(e) => {
+                metrics::POKEMON_INDEX_REFRESHES
+                    .with_label_values(&["failure"])
+                    .inc();
+                return Err(e);
+            }
+        };
+        let count = names.len();
+        *self.names.write().expect("name index lock poisoned") = Arc::new(Names::from(names));
+        metrics::POKEMON_INDEX_REFRESHES
+            .with_label_values(&["success"])
+            .inc();
+        metrics::POKEMON_INDEX_NAMES.set(count as i64);
+        Ok(count)
+    }
+
+    /// Refreshes the index every `interval`, starting immediately.
+    pub async fn run(self: Arc<Self>, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match self.refresh().await {
+                Ok(count) => info!(count, "Refreshed pokemon name index"),
+                Err(e) => warn!(error = %e, "Failed to refresh pokemon name index"),
+            }
+        }
+    }
+
+    /// Returns a page of the names matching `query` (all of them without query).
+    ///
+    /// # Errors
+    ///
+    /// Returns the PokéAPI error if the index is empty and cannot be loaded.
+    pub async fn search(
+        &self,
+        query: Option<&str>,
+        offset: usize,
+        limit: usize,
+    ) -> Result<PokemonPage, HttpClientError> {
+        let names = self.names.read().expect("name index lock poisoned").clone();
+        let names = match names.all.is_empty() {
+            true => self.load().await?,
+            false => names,
+        };
+        let matches = match query.map(str::trim).filter(|q| !q.is_empty()) {
+            Some(query) => rank(&names.all, &query.to_lowercase()),
+            None => names.all.iter().map(String::as_str).collect(),
+        };
+        Ok(PokemonPage {
+            count: matches.len(),
+            offset,
+            limit,
+            results: matches
+                .into_iter()
+                .skip(offset)
+                .take(limit)
+                .map(|name| PokemonListItem {
+                    name: name.to_string(),
+                    url: format!("{}/{}", LIST_ROUTE, name),
+                })
+                .collect(),
+        })
+    }
+
+    /// Loads the empty index, once for all the concurrent searches.
+    async fn load(&self) -> Result<Arc<Names>, HttpClientError> {
+        let _loading = self.loading.lock().await;
+        let names = self.names.read().expect("name index lock poisoned").clone();
+        if !names.all.is_empty() {
+            return Ok(names);
+        }
+        self.refresh().await?;
+        Ok(self.names.read().expect("name index lock poisoned").clone())
+    }
+
+    /// Returns the known species names closest to `name`, most similar first.
+    ///
+    /// Nothing is suggested for a known name, nor while the index is not loaded yet:
+    /// suggestions never wait on PokéAPI.
+    pub fn suggest(&self, name: &str) -> Vec<String> {
+        let names = self.names.read().expect("name index lock poisoned").clone();
+        let name = name.trim().to_lowercase();
+        match names.all.contains(&name) {
+            true => Vec::new(),
+            false => closest(&names.species, &name)
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+}
+
+/// Returns the names most similar to `name`, most similar first (ties keep the index order).
+fn closest<'a>(names: &'a [String], name: &str) -> Vec<&'a str> {
+    let mut scored: Vec<(f64, &str)> = names
+        .iter()
+        .map(|known| (strsim::jaro_winkler(known, name), known.as_str()))
+        .filter(|(similarity, _)| *similarity >= MIN_SUGGESTION_SIMILARITY)
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    scored
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, known)| known)
+        .collect()
+}
+
+/// Returns the names matching `query`, best matches first: exact match, prefix matches,
+/// substring matches, then names within the edit distance allowed for the query length.
+/// Ties keep the index order.
+fn rank<'a>(names: &'a [String], query: &str) -> Vec<&'a str> {
+    let query_len = query.chars().count();
+    let max_distance = (query_len / 4).clamp(1, 3);
+    let mut ranked: Vec<(u8, usize, usize, &str)> = names
+        .iter()
+        .enumerate()
+        .filter_map(|(position, name)| {
+            let (tier, distance) = if name == query {
+                (0, 0)
+            } else if name.starts_with(query) {
+                (1, 0)
+            } else if name.contains(query) {
+                (2, 0)
+            } else if query_len >= FUZZY_MIN_QUERY_LEN {
+                let distance = strsim::osa_distance(name, query);
+                (distance <= max_distance).then_some((3, distance))?
+            } else {
+                return None;
+            };
+            Some((tier, distance, position, name.as_str()))
+        })
+        .collect();
+    ranked.sort_unstable();
+    ranked.into_iter().map(|(_, _, _, name)| name).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::client::ErrorKind;
+    use crate::pokemon_api::{
+        client::{DescriptionOptions, Pokemon},
+        descriptions::PokemonDescriptions,
+        evolution::Evolution,
+        language::LanguagePreferences,
+        version::VersionFilter,
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Fake API listing a few names, counting the list requests.
+    #[derive(Default)]
+    struct FakePokemonApi {
+        list_calls: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl PokemonApi for FakePokemonApi {
+        async fn get_pokemon(
+            &self,
+            _name: &str,
+            _preferences: &LanguagePreferences,
+            _options: &DescriptionOptions,
+        ) -> Result<(String, Pokemon), HttpClientError> {
+            Err(ErrorKind::NotFound.into())
+        }
+
+        async fn get_evolution(
+            &self,
+            _name: &str,
+            _preferences: &LanguagePreferences,
+            _options: &DescriptionOptions,
+        ) -> Result<Evolution, HttpClientError> {
+            Err(ErrorKind::NotFound.into())
+        }
+
+        async fn get_descriptions(
+            &self,
+            _name: &str,
+            _preferences: &LanguagePreferences,
+            _version: Option<&VersionFilter>,
+        ) -> Result<PokemonDescriptions, HttpClientError> {
+            Err(ErrorKind::NotFound.into())
+        }
+
+        async fn get_pokemon_names(&self) -> Result<Vec<PokemonName>, HttpClientError> {
+            self.list_calls.fetch_add(1, Ordering::SeqCst);
+            // let concurrent searches run while the names are listed
+            tokio::task::yield_now().await;
+            Ok(["pichu", "pikachu", "raichu", "pikachu-rock-star", "mew"]
+                .into_iter()
+                .map(|name| PokemonName {
+                    name: name.to_string(),
+                    species: name != "pikachu-rock-star",
+                })
+                .collect())
+        }
+
+        async fn probe(&self) -> Result<(), HttpClientError> {
+            Ok(())
+        }
+    }
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn ranks_prefix_then_substring_then_fuzzy_matches() {
+        let index = names(&["raichu", "pikachu-rock-star", "pichu", "pikachu", "mew"]);
+
+        assert_eq!(
+            rank(&index, "pikachu"),
+            vec!["pikachu", "pikachu-rock-star"]
+        );
+        assert_eq!(
+            rank(&index, "chu"),
+            vec!["raichu", "pikachu-rock-star", "pichu", "pikachu"]
+        );
+        assert_eq!(rank(&index, "pikahcu"), vec!["pikachu"]);
+        // short queries are never fuzzy matched
+        assert_eq!(rank(&index, "me"), vec!["mew"]);
+        assert!(rank(&index, "zz").is_empty());
+    }
+
+    #[tokio::test]
+    async fn loads_the_index_lazily_and_paginates() {
+        let api = Arc::new(FakePokemonApi::default());
+        let index = NameIndex::new(api.clone());
+
+        let first = index.search(None, 0, 2).await.unwrap();
+        let second = index.search(None, 2, 2).await.unwrap();
+
+        assert_eq!(api.list_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(first.count, 5);
+        assert_eq!(
+            first.results,
+            vec![
+                PokemonListItem {
+                    name: "pichu".to_string(),
+                    url: "/pokemon/pichu".to_string()
+                },
+                PokemonListItem {
+                    name: "pikachu".to_string(),
+                    url: "/pokemon/pikachu".to_string()
+                },
+            ]
+        );
+        assert_eq!(second.results[0].name, "raichu");
+    }
+
+    #[tokio::test]
+    async fn loads_the_index_once_for_concurrent_searches() {
+        let api = Arc::new(FakePokemonApi::default());
+        let index = NameIndex::new(api.clone());
+
+        let (first, second) =
+            tokio::join!(index.search(None, 0, 20), index.search(Some("pika"), 0, 20));
+
+        assert_eq!(api.list_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(first.unwrap().count, 5);
+        assert_eq!(second.unwrap().count, 2);
+    }
+
+    #[tokio::test]
+    async fn searches_case_insensitively() {
+        let index = NameIndex::new(Arc::new(FakePokemonApi::default()));
+
+        let page = index.search(Some(" PIKA "), 0, 20).await.unwrap();
+
+        assert_eq!(page.count, 2);
+        assert_eq!(page.results[0].name, "pikachu");
+    }
+
+    #[test]
+    fn suggests_the_closest_names() {
+        let index = names(&["pichu", "pikachu", "raichu", "pikachu-rock-star", "mew"]);
+
+        assert_eq!(
+            closest(&index, "pikachuu"),
+            vec!["pikachu", "pichu", "pikachu-rock-star"]
+        );
+        assert!(closest(&index, "agumon").is_empty());
+    }
+
+    #[tokio::test]
+    async fn suggests_nothing_for_known_names_or_before_loading() {
+        let index = NameIndex::new(Arc::new(FakePokemonApi::default()));
+        assert!(index.suggest("pikachuu").is_empty());
+
+        index.refresh().await.unwrap();
+
+        assert_eq!(index.suggest("Pikachuu"), vec!["pikachu", "pichu"]);
+        assert!(index.suggest("pikachu").is_empty());
+        assert!(index.suggest("pikachu-rock-star").is_empty());
+    }
+
+    #[test]
+    fn links_the_previous_and_next_pages() {
+        let page = |offset| PokemonPage {
+            count: 45,
+            offset,
+            limit: 20,
+            results: vec![],
+        };
+
+        assert_eq!(
+            page(0).links(None).as_deref(),
+            Some(r#"</pokemon?offset=20&limit=20>; rel="next""#)
+        );
+        assert_eq!(
+            page(30).links(Some("mr mime")).as_deref(),
+            Some(r#"</pokemon?offset=10&limit=20&q=mr%20mime>; rel="prev""#)
+        );
+        assert_eq!(
+            page(20).links(None).as_deref(),
+            Some(
+                r#"</pokemon?offset=40&limit=20>; rel="next", </pokemon?offset=0&limit=20>; rel="prev""#
+            )
+        );
+        assert_eq!(
+            page(50).links(None),
+            Some(r#"</pokemon?offset=40&limit=20>; rel="prev""#.to_string())
+        );
+        assert_eq!(
+            page(usize::MAX).links(None),
+            Some(r#"</pokemon?offset=40&limit=20>; rel="prev""#.to_string())
+        );
+    }
+}
@@ -0,0 +1,607 @@
+//! # Language Negotiation
+//!
+//! `Accept-Language` carries BCP 47 language tags (e.g., `es-MX`, `zh-Hant-TW`), whereas
+//! PokéAPI names its languages with its own identifiers (`es`, `zh-Hant`, `ja-Hrkt`,
+//! `roomaji`). Descriptions are negotiated with the RFC 4647 lookup scheme:
+//! 1. Language ranges are tried by decreasing quality, in header order for equal qualities
+//! 2. A range matches the PokéAPI language with the same identifier (case-insensitively), then
+//!    the languages the [`LanguageMap`] configured with `POKEAPI_LANGUAGE_MAP` maps it to
+//!    (e.g., `zh-TW=zh-Hant`)
+//! 3. A range without match is truncated progressively (`zh-Hant-TW`, then `zh-Hant`, then
+//!    `zh`), a trailing single-character subtag being removed with the subtag after it
+//! 4. Languages matched by a `q=0` range (e.g., `fr;q=0` refuses `fr`) are never returned,
+//!    not even for the wildcard (`*`)
+//!
+//! When no requested language is available, [`LanguageFallbacks`] tries the fallback chains
+//! of the requested languages configured with `LANGUAGE_FALLBACK_CHAINS` (e.g.,
+//! `ca=es>fr`), then the `DEFAULT_LANGUAGE`, then any acceptable language for the wildcard.
+//! The step used is reported in the `X-Language-Fallback` header.
+
+use crate::constants::DEFAULT_LANGUAGE;
+use accept_language::parse_with_quality;
+use axum::http::HeaderName;
+
+/// Header carrying the [`LanguageFallback`] step of a negotiated description.
+pub const LANGUAGE_FALLBACK_HEADER: HeaderName = HeaderName::from_static("x-language-fallback");
+
+/// Language preferences of an `Accept-Language` header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguagePreferences {
+    /// Language ranges, by decreasing preference
+    pub ranges: Vec<String>,
+    /// Language ranges refused with `q=0`
+    pub excluded: Vec<String>,
+    /// Whether the other languages are acceptable (`*`)
+    pub has_wildcard: bool,
+    /// Whether only the requested languages are acceptable, without fallback chains nor
+    /// default language
+    pub strict: bool,
+}
+
+impl LanguagePreferences {
+    /// Creates preferences for `ranges`, in priority order.
+    pub fn new(ranges: &[&str], has_wildcard: bool) -> Self {
+        Self {
+            ranges: ranges.iter().map(|r| r.to_string()).collect(),
+            excluded: vec![],
+            has_wildcard,
+            strict: false,
+        }
+    }
+
+    /// Preferences accepting `ranges` only, without fallback.
+    pub fn strict(ranges: &[&str]) -> Self {
+        Self {
+            strict: true,
+            ..Self::new(ranges, false)
+        }
+    }
+
+    /// Preferences of a client accepting any language.
+    pub fn any() -> Self {
+        Self::new(&[], true)
+    }
+
+    /// Parses an `Accept-Language` header; any language is acceptable without header.
+    pub fn parse(header: Option<&str>) -> Self {
+        let Some(header) = header else {
+            return Self::any();
+        };
+        let mut preferences = Self::new(&[], false);
+        for (range, quality) in parse_with_quality(header) {
+            match (range.as_str(), quality > 0.0) {
+                ("*", accepted) => preferences.has_wildcard |= accepted,
+                (_, true) => preferences.ranges.push(range),
+                (_, false) => preferences.excluded.push(range),
+            }
+        }
+        preferences
+    }
+}
+
+/// Mapping of BCP 47 language tags to PokéAPI languages, besides identical identifiers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LanguageMap {
+    aliases: Vec<(String, String)>,
+}
+
+impl LanguageMap {
+    /// Creates a new map of `(tag, PokéAPI language)` aliases, tried in order.
+    pub fn new(aliases: Vec<(String, String)>) -> Self {
+        Self { aliases }
+    }
+
+    /// Parses a comma separated list of `tag=language` aliases, or `none`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if an alias is not a pair of language tags.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        if value.trim().eq_ignore_ascii_case("none") {
+            return Ok(Self::default());
+        }
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| match s.split_once('=') {
+                Some((tag, language)) if is_tag(tag.trim()) && is_tag(language.trim()) => {
+                    Ok((tag.trim().to_string(), language.trim().to_string()))
+                }
+                _ => Err(format!("'{}' is not a tag=language pair", s)),
+            })
+            .collect::<Result<_, _>>()
+            .map(Self::new)
+    }
+
+    /// Available languages matching the language ranges, in preference order, one per range.
+    pub fn lookup<'a>(
+        &self,
+        preferences: &LanguagePreferences,
+        available: &[&'a str],
+    ) -> Vec<&'a str> {
+        let mut matched: Vec<&str> = Vec::new();
+        for range in &preferences.ranges {
+            if let Some(language) = self.find(preferences, range, available)
+                && !matched.contains(&language)
+            {
+                matched.push(language);
+            }
+        }
+        matched
+    }
+
+    /// Available languages matching the language ranges, in preference order, followed by
+    /// the other acceptable ones (in the given order) when the wildcard is set.
+    pub fn negotiate<'a>(
+        &self,
+        preferences: &LanguagePreferences,
+        available: &[&'a str],
+    ) -> Vec<&'a str> {
+        let mut languages = self.lookup(preferences, available);
+        if preferences.has_wildcard {
+            for language in available {
+                if !languages.contains(language) && !self.is_excluded(preferences, language) {
+                    languages.push(language);
+                }
+            }
+        }
+        languages
+    }
+
+    /// Whether a PokéAPI language is refused with `q=0`, under its identifier or a tag
+    /// mapped to it.
+    pub fn is_excluded(&self, preferences: &LanguagePreferences, language: &str) -> bool {
+        let tags = || {
+            std::iter::once(language).chain(
+                self.aliases
+                    .iter()
+                    .filter(|(_, to)| to.eq_ignore_ascii_case(language))
+                    .map(|(from, _)| from.as_str()),
+            )
+        };
+        preferences
+            .excluded
+            .iter()
+            .any(|range| tags().any(|tag| matches_range(range, tag)))
+    }
+
+    /// Acceptable language of a language range (RFC 4647 lookup), if available.
+    fn find<'a>(
+        &self,
+        preferences: &LanguagePreferences,
+        range: &str,
+        available: &[&'a str],
+    ) -> Option<&'a str> {
+        truncations(range).find_map(|tag| {
+            self.targets(tag, available)
+                .find(|language| !self.is_excluded(preferences, language))
+        })
+    }
+
+    /// Available languages a tag stands for: the identical one first, then the mapped ones.
+    fn targets<'a, 'b>(
+        &'b self,
+        tag: &'b str,
+        available: &'b [&'a str],
+    ) -> impl Iterator<Item = &'a str> + 'b {
+        let find = move |language: &str| {
+            available
+                .iter()
+                .copied()
+                .find(|l| l.eq_ignore_ascii_case(language))
+        };
+        find(tag).into_iter().chain(
+            self.aliases
+                .iter()
+                .filter(move |(from, _)| from.eq_ignore_ascii_case(tag))
+                .filter_map(move |(_, to)| find(to)),
+        )
+    }
+}
+
+/// Fallback step of a negotiated language.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LanguageFallback {
+    /// A requested language
+    #[default]
+    None,
+    /// A language of the fallback chain of a requested language
+    Chain,
+    /// The default language
+    Default,
+    /// Any acceptable language, for the wildcard
+    Wildcard,
+}
+
+impl LanguageFallback {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Chain => "chain",
+            Self::Default => "default",
+            Self::Wildcard => "wildcard",
+        }
+    }
+}
+
+/// Fallback languages of the descriptions missing in the requested languages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageFallbacks {
+    chains: Vec<(String, Vec<String>)>,
+    default_language: String,
+}
+
+impl Default for LanguageFallbacks {
+    fn default() -> Self {
+        Self::new(vec![], DEFAULT_LANGUAGE)
+    }
+}
+
+impl LanguageFallbacks {
+    /// Creates new fallbacks.
+    ///
+    /// # Arguments
+    ///
+    /// * `chains` - `(language range, fallback ranges)` pairs, e.g. `("ca", ["es", "fr"])`
+    /// * `default_language` - Language range tried after the chains
+    pub fn new(chains: Vec<(String, Vec<String>)>, default_language: &str) -> Self {
+        Self {
+            chains,
+            default_language: default_language.to_string(),
+        }
+    }
+
+    /// Parses a comma separated list of `range=fallback>fallback` chains, or `none`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if a chain is not a language tag followed by `>` separated
+    /// language tags.
+    pub fn parse_chains(value: &str) -> Result<Vec<(String, Vec<String>)>, String> {
+        if value.trim().eq_ignore_ascii_case("none") {
+            return Ok(vec![]);
+        }
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                s.split_once('=')
+                    .map(|(range, chain)| {
+                        let chain: Vec<String> =
+                            chain.split('>').map(|l| l.trim().to_string()).collect();
+                        (range.trim().to_string(), chain)
+                    })
+                    .filter(|(range, chain)| is_tag(range) && chain.iter().all(|l| is_tag(l)))
+                    .ok_or_else(|| format!("'{}' is not a language=fallback>fallback chain", s))
+            })
+            .collect()
+    }
+
+    /// Selects the language of a description among the available ones: the first requested
+    /// one, then the first one of the fallback chains of the requested languages (in order),
+    /// then the default language, then the first acceptable one for the wildcard.
+    ///
+    /// Returns `None` if no language is acceptable.
+    pub fn select<'a>(
+        &self,
+        language_map: &LanguageMap,
+        preferences: &LanguagePreferences,
+        available: &[&'a str],
+    ) -> Option<(&'a str, LanguageFallback)> {
+        if let Some(language) = language_map.lookup(preferences, available).first() {
+            return Some((language, LanguageFallback::None));
+        }
+        if !preferences.strict {
+            let chained = preferences
+                .ranges
+                .iter()
+                .filter_map(|range| self.chain(range))
+                .flatten()
+                .find_map(|step| language_map.find(preferences, step, available));
+            if let Some(language) = chained {
+                return Some((language, LanguageFallback::Chain));
+            }
+            if let Some(language) =
+                language_map.find(preferences, &self.default_language, available)
+            {
+                return Some((language, LanguageFallback::Default));
+            }
+        }
+        match preferences.has_wildcard {
+            true => available
+                .iter()
+                .copied()
+                .find(|language| !language_map.is_excluded(preferences, language))
+                .map(|language| (language, LanguageFallback::Wildcard)),
+            false => None,
+        }
+    }
+
+    /// Fallback chain of a language range, looked up by progressive truncation.
+    fn chain(&self, range: &str) -> Option<&[String]> {
+        truncations(range).find_map(|tag| {
+            self.chains
+                .iter()
+                .find(|(from, _)| from.eq_ignore_ascii_case(tag))
+                .map(|(_, chain)| chain.as_slice())
+        })
+    }
+}
+
+/// Progressive truncations of a language range, the range itself first (RFC 4647, 3.4).
+fn truncations(range: &str) -> impl Iterator<Item = &str> {
+    std::iter::successors(Some(range), |tag| {
+        let (prefix, _) = tag.rsplit_once('-')?;
+        match prefix.rsplit_once('-') {
+            Some((rest, singleton)) if singleton.len() == 1 => Some(rest),
+            _ => Some(prefix),
+        }
+    })
+}
+
+/// Whether a language range matches a tag with basic filtering (RFC 4647, 3.3.1).
+fn matches_range(range: &str, tag: &str) -> bool {
+    tag.get(..range.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(range))
+        && matches!(tag.as_bytes().get(range.len()), None | Some(b'-'))
+}
+
+/// Whether a value is a well-formed language tag: alphanumeric subtags of 1 to 8 characters.
+pub fn is_tag(value: &str) -> bool {
+    value.split('-').all(|subtag| {
+        (1..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{DEFAULT_LANGUAGE_FALLBACK_CHAINS, DEFAULT_POKEAPI_LANGUAGE_MAP};
+    use proptest::prelude::*;
+
+    /// PokéAPI languages, in PokéAPI order.
+    const POKEAPI_LANGUAGES: [&str; 13] = [
+        "ja-Hrkt", "roomaji", "ko", "zh-Hant", "fr", "de", "es", "it", "en", "cs", "ja", "zh-Hans",
+        "pt-BR",
+    ];
+
+    fn map() -> LanguageMap {
+        LanguageMap::parse(DEFAULT_POKEAPI_LANGUAGE_MAP).unwrap()
+    }
+
+    fn lookup(header: &str, available: &[&'static str]) -> Vec<&'static str> {
+        map().lookup(&LanguagePreferences::parse(Some(header)), available)
+    }
+
+    #[test]
+    fn maps_regional_tags_to_pokeapi_languages() {
+        assert_eq!(lookup("es-MX", &POKEAPI_LANGUAGES), vec!["es"]);
+        assert_eq!(lookup("fr-CA", &POKEAPI_LANGUAGES), vec!["fr"]);
+        assert_eq!(lookup("zh-TW", &POKEAPI_LANGUAGES), vec!["zh-Hant"]);
+        assert_eq!(lookup("zh-Hant-HK", &POKEAPI_LANGUAGES), vec!["zh-Hant"]);
+        assert_eq!(lookup("zh-CN", &POKEAPI_LANGUAGES), vec!["zh-Hans"]);
+        assert_eq!(lookup("ja-JP", &POKEAPI_LANGUAGES), vec!["ja"]);
+        assert_eq!(lookup("ja-JP", &["en", "ja-Hrkt"]), vec!["ja-Hrkt"]);
+        assert_eq!(lookup("ja-Latn", &POKEAPI_LANGUAGES), vec!["roomaji"]);
+        assert_eq!(lookup("pt-PT", &POKEAPI_LANGUAGES), vec!["pt-BR"]);
+        assert_eq!(lookup("de-CH-x-phonebk", &POKEAPI_LANGUAGES), vec!["de"]);
+        assert_eq!(lookup("EN-us", &POKEAPI_LANGUAGES), vec!["en"]);
+        assert!(lookup("nl-BE", &POKEAPI_LANGUAGES).is_empty());
+    }
+
+    #[test]
+    fn honors_exclusions() {
+        let preferences = LanguagePreferences::parse(Some("fr;q=0, zh;q=0, *"));
+        let languages = map().negotiate(&preferences, &["fr", "zh-Hant", "en", "ja"]);
+        assert_eq!(languages, vec!["en", "ja"]);
+
+        // zh-TW is mapped to zh-Hant, which is refused
+        let preferences = LanguagePreferences::parse(Some("zh-TW, zh-Hant;q=0, zh;q=0.5"));
+        assert!(map().is_excluded(&preferences, "zh-Hant"));
+        assert_eq!(
+            map().lookup(&preferences, &["zh-Hant", "zh-Hans"]),
+            vec!["zh-Hans"]
+        );
+
+        let preferences = LanguagePreferences::parse(Some("en, *;q=0"));
+        assert!(!preferences.has_wildcard);
+        assert_eq!(map().negotiate(&preferences, &["ja", "en"]), vec!["en"]);
+    }
+
+    #[test]
+    fn parses_headers_by_quality() {
+        let preferences = LanguagePreferences::parse(Some("es;q=0.5, fr-CA, *;q=0.1, de;q=0"));
+        assert_eq!(preferences.ranges, vec!["fr-CA", "es"]);
+        assert_eq!(preferences.excluded, vec!["de"]);
+        assert!(preferences.has_wildcard);
+        assert_eq!(LanguagePreferences::parse(None), LanguagePreferences::any());
+    }
+
+    fn select(
+        header: &str,
+        available: &[&'static str],
+    ) -> Option<(&'static str, LanguageFallback)> {
+        let fallbacks = LanguageFallbacks::new(
+            vec![
+                (
+                    "pt-BR".to_string(),
+                    vec!["pt".to_string(), "es".to_string()],
+                ),
+                ("ca".to_string(), vec!["es".to_string(), "fr".to_string()]),
+            ],
+            "en",
+        );
+        fallbacks.select(&map(), &LanguagePreferences::parse(Some(header)), available)
+    }
+
+    #[test]
+    fn falls_back_on_chains_then_default_language() {
+        assert_eq!(
+            select("ca", &["en", "fr", "es"]),
+            Some(("es", LanguageFallback::Chain))
+        );
+        assert_eq!(
+            select("ca-ES, es;q=0", &["en", "fr", "es"]),
+            Some(("fr", LanguageFallback::Chain))
+        );
+        assert_eq!(
+            select("pt-BR, ca;q=0.5", &["de", "fr"]),
+            Some(("fr", LanguageFallback::Chain))
+        );
+        assert_eq!(
+            select("ca, fr;q=0.1", &["en", "fr"]),
+            Some(("fr", LanguageFallback::None))
+        );
+        assert_eq!(
+            select("ca", &["de", "en"]),
+            Some(("en", LanguageFallback::Default))
+        );
+        assert_eq!(
+            select("ca, en;q=0, *", &["de", "en"]),
+            Some(("de", LanguageFallback::Wildcard))
+        );
+        assert_eq!(select("ca", &["de"]), None);
+
+        let strict = LanguagePreferences::strict(&["ca"]);
+        let fallbacks = LanguageFallbacks::default();
+        assert_eq!(fallbacks.select(&map(), &strict, &["es", "en"]), None);
+    }
+
+    #[test]
+    fn parses_default_chains() {
+        let chains = LanguageFallbacks::parse_chains(DEFAULT_LANGUAGE_FALLBACK_CHAINS).unwrap();
+        assert!(chains.contains(&("ca".to_string(), vec!["es".to_string(), "fr".to_string()])));
+        assert!(LanguageFallbacks::parse_chains("none").unwrap().is_empty());
+        assert!(LanguageFallbacks::parse_chains("ca=es>,pt=es").is_err());
+        assert!(LanguageFallbacks::parse_chains("ca").is_err());
+    }
+
+    #[test]
+    fn parses_maps() {
+        let preferences = LanguagePreferences::new(&["zh-MO"], false);
+        assert_eq!(map().lookup(&preferences, &["zh-Hant"]), vec!["zh-Hant"]);
+        assert_eq!(LanguageMap::parse("none"), Ok(LanguageMap::default()));
+        assert!(LanguageMap::parse("zh-TW=zh-Hant,zh").is_err());
+        assert!(LanguageMap::parse("zh TW=zh-Hant").is_err());
+    }
+
+    fn language() -> impl Strategy<Value = &'static str> {
+        prop::sample::select(&POKEAPI_LANGUAGES[..])
+    }
+
+    fn region() -> impl Strategy<Value = String> {
+        "[A-Z]{2}|[0-9]{3}"
+    }
+
+    proptest! {
+        #[test]
+        fn negotiates_languages_in_quality_order(
+            ranked in prop::collection::vec((language(), 1..=10u8), 1..8),
+        ) {
+            let header = ranked
+                .iter()
+                .map(|(language, q)| format!("{};q={}", language, f32::from(*q) / 10.0))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let preferences = LanguagePreferences::parse(Some(&header));
+            let languages = map().lookup(&preferences, &POKEAPI_LANGUAGES);
+
+            // stable sort: by decreasing quality, in header order for equal qualities
+            let mut expected = ranked.clone();
+            expected.sort_by_key(|(_, q)| std::cmp::Reverse(*q));
+            let mut expected: Vec<&str> = expected.into_iter().map(|(l, _)| l).collect();
+            let mut seen = Vec::new();
+            expected.retain(|l| match seen.contains(l) {
+                true => false,
+                false => { seen.push(*l); true }
+            });
+            prop_assert_eq!(languages, expected);
+        }
+
+        #[test]
+        fn ignores_regions_of_matching_languages(
+            languages in prop::collection::vec(language(), 1..6),
+            regions in prop::collection::vec(region(), 6),
+        ) {
+            let ranges: Vec<&str> = languages.clone();
+            let regional: Vec<String> = languages
+                .iter()
+                .zip(&regions)
+                .map(|(language, region)| format!("{}-{}", language, region))
+                .collect();
+            let regional: Vec<&str> = regional.iter().map(String::as_str).collect();
+
+            prop_assert_eq!(
+                map().lookup(&LanguagePreferences::new(&regional, false), &POKEAPI_LANGUAGES),
+                map().lookup(&LanguagePreferences::new(&ranges, false), &POKEAPI_LANGUAGES)
+            );
+        }
+
+        #[test]
+        fn negotiates_every_acceptable_language_once(
+            ranges in prop::collection::vec(language(), 0..6),
+            excluded in prop::collection::vec(language(), 0..4),
+            available in prop::sample::subsequence(&POKEAPI_LANGUAGES[..], 0..13),
+            has_wildcard: bool,
+        ) {
+            let preferences = LanguagePreferences {
+                ranges: ranges.iter().map(|r| r.to_string()).collect(),
+                excluded: excluded.iter().map(|r| r.to_string()).collect(),
+                has_wildcard,
+                strict: false,
+            };
+            let languages = map().negotiate(&preferences, &available);
+
+            for (i, language) in languages.iter().enumerate() {
+                prop_assert!(available.contains(language));
+                prop_assert!(!map().is_excluded(&preferences, language));
+                prop_assert!(!languages[..i].contains(language));
+            }
+            if has_wildcard {
+                let acceptable = available
+                    .iter()
+                    .filter(|l| !map().is_excluded(&preferences, l))
+                    .count();
+                prop_assert_eq!(languages.len(), acceptable);
+            }
+            let looked_up = map().lookup(&preferences, &available);
+            prop_assert_eq!(&languages[..looked_up.len()], &looked_up[..]);
+        }
+
+        #[test]
+        fn selects_requested_languages_before_fallbacks(
+            ranges in prop::collection::vec(language(), 0..4),
+            excluded in prop::collection::vec(language(), 0..4),
+            available in prop::sample::subsequence(&POKEAPI_LANGUAGES[..], 0..13),
+            has_wildcard: bool,
+            strict: bool,
+        ) {
+            let preferences = LanguagePreferences {
+                ranges: ranges.iter().map(|r| r.to_string()).collect(),
+                excluded: excluded.iter().map(|r| r.to_string()).collect(),
+                has_wildcard,
+                strict,
+            };
+            let fallbacks = LanguageFallbacks::new(
+                LanguageFallbacks::parse_chains(DEFAULT_LANGUAGE_FALLBACK_CHAINS).unwrap(),
+                "en",
+            );
+            let selected = fallbacks.select(&map(), &preferences, &available);
+
+            match map().lookup(&preferences, &available).first() {
+                Some(language) => prop_assert_eq!(selected, Some((*language, LanguageFallback::None))),
+                None => prop_assert_ne!(selected.map(|(_, f)| f), Some(LanguageFallback::None)),
+            }
+            if let Some((language, fallback)) = selected {
+                prop_assert!(available.contains(&language));
+                prop_assert!(!map().is_excluded(&preferences, language));
+                prop_assert!(!strict || matches!(fallback, LanguageFallback::None | LanguageFallback::Wildcard));
+            }
+            if has_wildcard && available.iter().any(|l| !map().is_excluded(&preferences, l)) {
+                prop_assert!(selected.is_some());
+            }
+        }
+    }
+}
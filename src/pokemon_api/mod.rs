@@ -1 +1,10 @@
+pub mod cache;
+pub mod circuit_breaker;
 pub mod client;
+pub mod descriptions;
+pub mod evolution;
+pub mod fields;
+pub mod index;
+pub mod language;
+pub mod text;
+pub mod version;
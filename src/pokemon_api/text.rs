@@ -0,0 +1,143 @@
+//! # Flavor Text Normalization
+//!
+//! PokéAPI flavor texts are copied from the games verbatim: hard line breaks and form feeds
+//! of the in-game text boxes, soft hyphens at line ends, and small-caps spellings such as
+//! "POKéMON". [`TextNormalizer`] cleans them up before they are returned or translated:
+//! 1. NFC normalization (composed accents, so that "é" is matched in a single form)
+//! 2. Hyphenation repair: soft hyphens are dropped, words split by a line break after a soft
+//!    or hard hyphen are joined back ("evolu\u{ad}\ntion" becomes "evolution", "self-\nish"
+//!    becomes "self-ish")
+//! 3. Whitespace cleanup: control characters, line breaks and whitespace runs become single
+//!    spaces, and the text is trimmed
+//! 4. Casing fixes, configured with `DESCRIPTION_CASING_FIXES` (e.g., "POKéMON" becomes
+//!    "Pokémon")
+//!
+//! Callers may opt out with `raw=true`, getting the flavor text as PokéAPI returns it.
+
+use unicode_normalization::UnicodeNormalization;
+
+const SOFT_HYPHEN: char = '\u{ad}';
+
+/// Normalizes PokéAPI flavor texts.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextNormalizer {
+    casing_fixes: Vec<(String, String)>,
+}
+
+impl TextNormalizer {
+    /// Creates a new normalizer applying `casing_fixes`, in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `casing_fixes` - `(spelling, replacement)` pairs, e.g. `("POKéMON", "Pokémon")`
+    pub fn new(casing_fixes: Vec<(String, String)>) -> Self {
+        Self {
+            casing_fixes: casing_fixes
+                .into_iter()
+                .map(|(from, to)| (from.nfc().collect(), to.nfc().collect()))
+                .collect(),
+        }
+    }
+
+    /// Normalizes a flavor text.
+    pub fn normalize(&self, text: &str) -> String {
+        let text: String = text.nfc().collect();
+        let mut normalized = String::with_capacity(text.len());
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                // "evolu\u{ad}\ntion" -> "evolution", "self-\nish" -> "self-ish"
+                SOFT_HYPHEN | '-' if chars.peek().is_some_and(|next| is_line_break(*next)) => {
+                    while chars.peek().is_some_and(|next| is_blank(*next)) {
+                        chars.next();
+                    }
+                    if c == '-' {
+                        normalized.push(c);
+                    }
+                }
+                SOFT_HYPHEN => {}
+                c if is_blank(c) => {
+                    if !normalized.is_empty() && !normalized.ends_with(' ') {
+                        normalized.push(' ');
+                    }
+                }
+                c => normalized.push(c),
+            }
+        }
+        let mut normalized = normalized.trim_end().to_string();
+        for (from, to) in &self.casing_fixes {
+            normalized = normalized.replace(from, to);
+        }
+        normalized
+    }
+}
+
+fn is_line_break(c: char) -> bool {
+    matches!(
+        c,
+        '\n' | '\r' | '\u{c}' | '\u{b}' | '\u{85}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+fn is_blank(c: char) -> bool {
+    c.is_whitespace() || c.is_control()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalizer() -> TextNormalizer {
+        TextNormalizer::new(vec![
+            ("POKéMON".to_string(), "Pokémon".to_string()),
+            ("POKé BALL".to_string(), "Poké Ball".to_string()),
+        ])
+    }
+
+    #[test]
+    fn normalizes_generation_i_text_boxes() {
+        // Bulbasaur, Pokémon Red
+        assert_eq!(
+            normalizer().normalize(
+                "A strange seed was\nplanted on its\nback at birth.\u{c}The plant sprouts\nand grows with\nthis POKéMON."
+            ),
+            "A strange seed was planted on its back at birth. The plant sprouts and grows with this Pokémon."
+        );
+    }
+
+    #[test]
+    fn repairs_hyphenation_across_line_breaks() {
+        // Pikachu, Pokémon Ruby
+        assert_eq!(
+            normalizer().normalize(
+                "It stores electricity in the electric\nsacs on its cheeks. When it re\u{ad}\nleases pent-up energy in a burst,\nthe electric power is equal to a\nlightning bolt."
+            ),
+            "It stores electricity in the electric sacs on its cheeks. When it releases pent-up energy in a burst, the electric power is equal to a lightning bolt."
+        );
+        // Voltorb, Pokémon Gold
+        assert_eq!(
+            normalizer().normalize("It is said to be\ndrawn to electric\u{ad}ity. It self-\ndestructs when\nhit by a POKé BALL."),
+            "It is said to be drawn to electricity. It self-destructs when hit by a Poké Ball."
+        );
+    }
+
+    #[test]
+    fn composes_accents_before_fixing_casing() {
+        // Decomposed "é" (e + combining acute accent), as found in some Generation IV texts
+        assert_eq!(
+            normalizer().normalize("Many POKe\u{301}MON\r\n  gather\u{a0}here.  "),
+            "Many Pokémon gather here."
+        );
+    }
+
+    #[test]
+    fn leaves_modern_texts_untouched() {
+        // Charmander, Pokémon Sword
+        let text = "It has a preference for hot things. When it rains, steam is said to spout from the tip of its tail.";
+        assert_eq!(normalizer().normalize(text), text);
+        assert_eq!(
+            TextNormalizer::default().normalize("this POKéMON."),
+            "this POKéMON."
+        );
+    }
+}
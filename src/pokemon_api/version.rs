@@ -0,0 +1,254 @@
+//! # Game Versions
+//!
+//! PokéAPI species have one flavor text per game version and language. The description of a
+//! Pokémon is selected among the entries of its language:
+//! - by the game version (`?version=red`) or version group (`?version-group=red-blue`) the
+//!   client asks for, if any
+//! - otherwise by the [`VersionPolicy`] configured with `FLAVOR_TEXT_VERSION_POLICY`: the
+//!   latest game, the earliest game, or the first available game of a preferred list
+//!
+//! Games are ordered by release with [`VERSIONS`]; versions PokéAPI knows but this catalogue
+//! does not (e.g., games released later) rank as the latest ones, in PokéAPI order.
+
+/// Game versions and their version group, in release order.
+pub const VERSIONS: [(&str, &str); 41] = [
+    ("red", "red-blue"),
+    ("blue", "red-blue"),
+    ("yellow", "yellow"),
+    ("gold", "gold-silver"),
+    ("silver", "gold-silver"),
+    ("crystal", "crystal"),
+    ("ruby", "ruby-sapphire"),
+    ("sapphire", "ruby-sapphire"),
+    ("emerald", "emerald"),
+    ("firered", "firered-leafgreen"),
+    ("leafgreen", "firered-leafgreen"),
+    ("diamond", "diamond-pearl"),
+    ("pearl", "diamond-pearl"),
+    ("platinum", "platinum"),
+    ("heartgold", "heartgold-soulsilver"),
+    ("soulsilver", "heartgold-soulsilver"),
+    ("black", "black-white"),
+    ("white", "black-white"),
+    ("black-2", "black-2-white-2"),
+    ("white-2", "black-2-white-2"),
+    ("x", "x-y"),
+    ("y", "x-y"),
+    ("omega-ruby", "omega-ruby-alpha-sapphire"),
+    ("alpha-sapphire", "omega-ruby-alpha-sapphire"),
+    ("sun", "sun-moon"),
+    ("moon", "sun-moon"),
+    ("ultra-sun", "ultra-sun-ultra-moon"),
+    ("ultra-moon", "ultra-sun-ultra-moon"),
+    ("lets-go-pikachu", "lets-go-pikachu-lets-go-eevee"),
+    ("lets-go-eevee", "lets-go-pikachu-lets-go-eevee"),
+    ("sword", "sword-shield"),
+    ("shield", "sword-shield"),
+    ("the-isle-of-armor", "the-isle-of-armor"),
+    ("the-crown-tundra", "the-crown-tundra"),
+    ("brilliant-diamond", "brilliant-diamond-and-shining-pearl"),
+    ("shining-pearl", "brilliant-diamond-and-shining-pearl"),
+    ("legends-arceus", "legends-arceus"),
+    ("scarlet", "scarlet-violet"),
+    ("violet", "scarlet-violet"),
+    ("the-teal-mask", "the-teal-mask"),
+    ("the-indigo-disk", "the-indigo-disk"),
+];
+
+/// Release rank of a version, unknown versions ranking last.
+fn rank(version: &str) -> usize {
+    VERSIONS
+        .iter()
+        .position(|(name, _)| *name == version)
+        .unwrap_or(VERSIONS.len())
+}
+
+/// Version group of a known version.
+pub fn version_group(version: &str) -> Option<&'static str> {
+    VERSIONS
+        .iter()
+        .find(|(name, _)| *name == version)
+        .map(|(_, group)| *group)
+}
+
+/// Game version (or version group) a client asks the description of.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionFilter {
+    /// A game version (e.g., red)
+    Version(String),
+    /// A version group (e.g., red-blue)
+    Group(String),
+}
+
+impl VersionFilter {
+    /// Creates the filter of `?version=` and `?version-group=` (case-insensitive).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if both are set, or if the version group is unknown.
+    pub fn from_query(
+        version: Option<&str>,
+        version_group: Option<&str>,
+    ) -> Result<Option<Self>, String> {
+        match (version, version_group) {
+            (Some(_), Some(_)) => Err("version and version-group are exclusive".to_string()),
+            (Some(version), None) => Ok(Some(Self::Version(version.trim().to_lowercase()))),
+            (None, Some(group)) => {
+                let group = group.trim().to_lowercase();
+                match VERSIONS.iter().any(|(_, known)| *known == group) {
+                    true => Ok(Some(Self::Group(group))),
+                    false => Err(format!("unknown version group '{}'", group)),
+                }
+            }
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// Whether an entry of `version` matches the filter.
+    pub fn matches(&self, version: Option<&str>) -> bool {
+        match self {
+            Self::Version(expected) => version == Some(expected.as_str()),
+            Self::Group(expected) => version.and_then(version_group) == Some(expected.as_str()),
+        }
+    }
+}
+
+/// Selection of the description among the versions of a language.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum VersionPolicy {
+    /// The latest game
+    #[default]
+    Latest,
+    /// The earliest game
+    Earliest,
+    /// The first available version of the list, otherwise the latest game
+    Preferred(Vec<String>),
+}
+
+impl VersionPolicy {
+    /// Parses `latest`, `earliest` or a comma separated list of preferred versions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if no version is listed.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "latest" => Ok(Self::Latest),
+            "earliest" => Ok(Self::Earliest),
+            list => {
+                let versions: Vec<String> = list
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|v| !v.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                match versions.is_empty() {
+                    true => Err("expected latest, earliest or a list of versions".to_string()),
+                    false => Ok(Self::Preferred(versions)),
+                }
+            }
+        }
+    }
+
+    /// Chooses among `items` (in PokéAPI order), given the version of each of them.
+    pub fn choose<'a, T>(
+        &self,
+        items: impl IntoIterator<Item = &'a T>,
+        version: impl Fn(&T) -> Option<&str>,
+    ) -> Option<&'a T> {
+        let items: Vec<&T> = items.into_iter().collect();
+        // `max_by_key` keeps the last maximum, `min_by_key` the first minimum
+        let latest = || {
+            items
+                .iter()
+                .max_by_key(|item| version(item).map_or(VERSIONS.len(), rank))
+                .copied()
+        };
+        match self {
+            Self::Latest => latest(),
+            Self::Earliest => items
+                .iter()
+                .min_by_key(|item| version(item).map_or(VERSIONS.len(), rank))
+                .copied(),
+            Self::Preferred(preferred) => preferred
+                .iter()
+                .find_map(|p| {
+                    items
+                        .iter()
+                        .find(|item| version(item) == Some(p.as_str()))
+                        .copied()
+                })
+                .or_else(latest),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENTRIES: [(&str, Option<&str>); 4] = [
+        ("Sword text", Some("sword")),
+        ("Red text", Some("red")),
+        ("Unknown text", None),
+        ("Yellow text", Some("yellow")),
+    ];
+
+    fn choose(policy: &VersionPolicy) -> &'static str {
+        policy.choose(&ENTRIES, |(_, version)| *version).unwrap().0
+    }
+
+    #[test]
+    fn chooses_versions_by_release_order() {
+        assert_eq!(choose(&VersionPolicy::Latest), "Unknown text");
+        assert_eq!(choose(&VersionPolicy::Earliest), "Red text");
+        assert_eq!(
+            VersionPolicy::Latest.choose(&ENTRIES[..2], |(_, version)| *version),
+            Some(&ENTRIES[0])
+        );
+        assert_eq!(
+            VersionPolicy::Latest.choose(&[] as &[(&str, Option<&str>)], |(_, v)| *v),
+            None
+        );
+    }
+
+    #[test]
+    fn chooses_preferred_versions_first() {
+        let policy = VersionPolicy::parse("x, yellow,red").unwrap();
+        assert_eq!(choose(&policy), "Yellow text");
+        assert_eq!(
+            choose(&VersionPolicy::Preferred(vec!["x".to_string()])),
+            "Unknown text"
+        );
+    }
+
+    #[test]
+    fn parses_policies() {
+        assert_eq!(VersionPolicy::parse("Latest"), Ok(VersionPolicy::Latest));
+        assert_eq!(
+            VersionPolicy::parse("earliest"),
+            Ok(VersionPolicy::Earliest)
+        );
+        assert!(VersionPolicy::parse(" , ").is_err());
+    }
+
+    #[test]
+    fn filters_versions_and_groups() {
+        let red = VersionFilter::from_query(Some("Red"), None)
+            .unwrap()
+            .unwrap();
+        assert!(red.matches(Some("red")));
+        assert!(!red.matches(Some("blue")));
+
+        let red_blue = VersionFilter::from_query(None, Some("red-blue"))
+            .unwrap()
+            .unwrap();
+        assert!(red_blue.matches(Some("blue")));
+        assert!(!red_blue.matches(Some("yellow")));
+        assert!(!red_blue.matches(None));
+
+        assert_eq!(VersionFilter::from_query(None, None), Ok(None));
+        assert!(VersionFilter::from_query(None, Some("green-japan")).is_err());
+        assert!(VersionFilter::from_query(Some("red"), Some("red-blue")).is_err());
+    }
+}
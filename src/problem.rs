@@ -0,0 +1,289 @@
+//! # Problem Details
+//!
+//! [RFC 7807](https://www.rfc-editor.org/rfc/rfc7807) problem details, returned as
+//! `application/problem+json` bodies by every error response. Besides the standard
+//! members, a problem carries:
+//! - `requestId`: the ID of the failed request (see [`crate::request_id`])
+//! - `availableLanguages`: for `406 Not Acceptable`, the languages the description exists in
+//! - `suggestions`: for an unknown Pokémon, the closest known names
+//!
+//! Problem types are identified by URIs relative to the API (`/problems/{slug}`).
+
+use crate::{
+    http::client::{ErrorKind, HttpClientError},
+    request_id,
+};
+use axum::{
+    Json,
+    extract::rejection::{JsonRejection, QueryRejection},
+    http::{HeaderValue, StatusCode, header::CONTENT_TYPE},
+    response::{IntoResponse, Response},
+};
+use serde::{Serialize, Serializer};
+use utoipa::ToSchema;
+
+/// Media type of problem details.
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// Kind of problem, each with its own type URI and status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProblemType {
+    /// The request parameters or body are invalid (400)
+    InvalidRequest,
+    /// The API key is missing or unknown (401)
+    Unauthorized,
+    /// The API key lacks the route scope (403)
+    Forbidden,
+    /// The Pokémon does not exist (404)
+    PokemonNotFound,
+    /// The Pokémon species has no description (404)
+    DescriptionNotFound,
+    /// The Pokémon has no description in an acceptable language (406)
+    LanguageNotAcceptable,
+    /// The client exhausted its rate limit budget (429)
+    RateLimited,
+    /// An upstream failed, or answered with an unreadable response (500)
+    UpstreamError,
+    /// An upstream is unavailable or rate limits the API (503)
+    UpstreamUnavailable,
+    /// An upstream did not answer in time (504)
+    UpstreamTimeout,
+}
+
+impl ProblemType {
+    fn slug(&self) -> &'static str {
+        match self {
+            Self::InvalidRequest => "invalid-request",
+            Self::Unauthorized => "unauthorized",
+            Self::Forbidden => "forbidden",
+            Self::PokemonNotFound => "pokemon-not-found",
+            Self::DescriptionNotFound => "description-not-found",
+            Self::LanguageNotAcceptable => "language-not-acceptable",
+            Self::RateLimited => "rate-limited",
+            Self::UpstreamError => "upstream-error",
+            Self::UpstreamUnavailable => "upstream-unavailable",
+            Self::UpstreamTimeout => "upstream-timeout",
+        }
+    }
+
+    /// Type URI of the problem.
+    pub fn uri(&self) -> String {
+        format!("/problems/{}", self.slug())
+    }
+
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::InvalidRequest => StatusCode::BAD_REQUEST,
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            Self::PokemonNotFound | Self::DescriptionNotFound => StatusCode::NOT_FOUND,
+            Self::LanguageNotAcceptable => StatusCode::NOT_ACCEPTABLE,
+            Self::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            Self::UpstreamError => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::UpstreamUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            Self::UpstreamTimeout => StatusCode::GATEWAY_TIMEOUT,
+        }
+    }
+
+    /// Short, human-readable summary of the problem type.
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::InvalidRequest => "Invalid request",
+            Self::Unauthorized => "Missing or invalid API key",
+            Self::Forbidden => "API key lacks the required scope",
+            Self::PokemonNotFound => "Pokemon not found",
+            Self::DescriptionNotFound => "Pokemon description not found",
+            Self::LanguageNotAcceptable => "No description in an acceptable language",
+            Self::RateLimited => "Rate limit exceeded",
+            Self::UpstreamError => "Upstream error",
+            Self::UpstreamUnavailable => "Upstream unavailable",
+            Self::UpstreamTimeout => "Upstream timed out",
+        }
+    }
+}
+
+impl Serialize for ProblemType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.uri())
+    }
+}
+
+/// Problem details of an error response.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct Problem {
+    /// URI identifying the problem type (e.g., `/problems/pokemon-not-found`)
+    #[serde(rename = "type")]
+    #[schema(value_type = String)]
+    pub kind: ProblemType,
+    /// Short summary of the problem type
+    pub title: String,
+    /// HTTP status code
+    pub status: u16,
+    /// Explanation specific to this occurrence of the problem
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// ID of the failed request, as in the `X-Request-Id` response header
+    #[serde(rename = "requestId", skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// Languages the Pokemon description is available in (406 only)
+    #[serde(rename = "availableLanguages", skip_serializing_if = "Vec::is_empty")]
+    pub available_languages: Vec<String>,
+    /// Closest known Pokemon names, best match first (unknown Pokemon only)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<String>,
+}
+
+impl Problem {
+    /// Creates a problem of the given type, for the request being served.
+    pub fn new(kind: ProblemType) -> Self {
+        Self {
+            kind,
+            title: kind.title().to_string(),
+            status: kind.status().as_u16(),
+            detail: None,
+            request_id: request_id::current(),
+            available_languages: Vec::new(),
+            suggestions: Vec::new(),
+        }
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub fn with_suggestions(mut self, suggestions: Vec<String>) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+}
+
+impl From<HttpClientError> for Problem {
+    fn from(error: HttpClientError) -> Self {
+        let kind = match error.kind() {
+            ErrorKind::NotFound => ProblemType::PokemonNotFound,
+            ErrorKind::NoDescription => ProblemType::DescriptionNotFound,
+            ErrorKind::NotAcceptable(_) => ProblemType::LanguageNotAcceptable,
+            ErrorKind::RateLimited | ErrorKind::ServiceUnavailable => {
+                ProblemType::UpstreamUnavailable
+            }
+            ErrorKind::Timeout => ProblemType::UpstreamTimeout,
+            ErrorKind::RequestFailed | ErrorKind::ParseError | ErrorKind::ServerError => {
+                ProblemType::UpstreamError
+            }
+        };
+        // the upstream URL and body stay in the logs
+        let detail = match error.context() {
+            Some(context) => match context.status {
+                Some(status) => format!(
+                    "{} (upstream {}, status {})",
+                    error.kind(),
+                    context.upstream,
+                    status
+                ),
+                None => format!("{} (upstream {})", error.kind(), context.upstream),
+            },
+            None => error.kind().to_string(),
+        };
+        let problem = Self::new(kind).with_detail(detail);
+        match error.kind() {
+            ErrorKind::NotAcceptable(available_languages) => Self {
+                available_languages: available_languages.clone(),
+                ..problem
+            },
+            _ => problem,
+        }
+    }
+}
+
+impl From<QueryRejection> for Problem {
+    fn from(rejection: QueryRejection) -> Self {
+        Self::new(ProblemType::InvalidRequest).with_detail(rejection.body_text())
+    }
+}
+
+impl From<JsonRejection> for Problem {
+    fn from(rejection: JsonRejection) -> Self {
+        Self::new(ProblemType::InvalidRequest).with_detail(rejection.body_text())
+    }
+}
+
+impl IntoResponse for Problem {
+    fn into_response(self) -> Response {
+        (
+            self.kind.status(),
+            [(CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON))],
+            Json(self),
+        )
+            .into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_the_standard_members_and_suggestions() {
+        let problem = Problem::new(ProblemType::PokemonNotFound)
+            .with_detail("Pokemon 'pikachuu' not found")
+            .with_suggestions(vec!["pikachu".to_string()]);
+
+        assert_eq!(
+            serde_json::to_value(problem).unwrap(),
+            serde_json::json!({
+                "type": "/problems/pokemon-not-found",
+                "title": "Pokemon not found",
+                "status": 404,
+                "detail": "Pokemon 'pikachuu' not found",
+                "suggestions": ["pikachu"]
+            })
+        );
+    }
+
+    #[test]
+    fn lists_the_available_languages_of_not_acceptable_errors() {
+        let problem = Problem::from(HttpClientError::from(ErrorKind::NotAcceptable(vec![
+            "ja".to_string(),
+            "fr".to_string(),
+        ])));
+
+        assert_eq!(problem.kind, ProblemType::LanguageNotAcceptable);
+        assert_eq!(problem.status, 406);
+        assert_eq!(problem.available_languages, vec!["ja", "fr"]);
+    }
+
+    #[test]
+    fn tells_upstream_failures_apart() {
+        let kind = |error| Problem::from(error).kind;
+
+        assert_eq!(
+            kind(HttpClientError::from(ErrorKind::NotFound)),
+            ProblemType::PokemonNotFound
+        );
+        assert_eq!(
+            kind(HttpClientError::from(ErrorKind::NoDescription)),
+            ProblemType::DescriptionNotFound
+        );
+        assert_eq!(
+            kind(HttpClientError::from(ErrorKind::ParseError)),
+            ProblemType::UpstreamError
+        );
+        assert_eq!(
+            kind(HttpClientError::from(ErrorKind::RateLimited)),
+            ProblemType::UpstreamUnavailable
+        );
+        assert_eq!(
+            kind(HttpClientError::from(ErrorKind::Timeout)),
+            ProblemType::UpstreamTimeout
+        );
+    }
+
+    #[test]
+    fn responds_with_the_problem_media_type_and_status() {
+        let response = Problem::new(ProblemType::UpstreamTimeout).into_response();
+
+        assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+        assert_eq!(response.headers()[CONTENT_TYPE], PROBLEM_JSON);
+    }
+}
@@ -0,0 +1,575 @@
+//! # Inbound Rate Limiting
+//!
+//! Token-bucket rate limiting of incoming requests, as an axum middleware:
+//! - Every client gets a bucket per rate limited route, holding up to the route budget
+//!   (requests per minute) and refilled continuously
+//! - Clients are identified by API key name (see [`crate::auth`]), otherwise by IP address;
+//!   behind trusted proxies (e.g., the bundled nginx) the address is taken from
+//!   `X-Forwarded-For`
+//! - API keys with a quota get that budget on every rate limited route instead
+//! - Translated evolution chains (`?translate=true`) call the translator once per stage, so
+//!   they are charged to the translation route bucket and budget
+//! - Batch lookups cost one token per requested name, as that many lookups
+//! - Responses carry `RateLimit-Limit`, `RateLimit-Remaining` and `RateLimit-Reset`
+//!   headers; rejected requests get `429 Too Many Requests` with `Retry-After`
+//!
+//! Rejections are counted in the `rate_limit_rejections_total` metric, labelled by route.
+//! Routes without a budget (health, metrics, docs) are not limited.
+
+use crate::{
+    auth::{Caller, translates},
+    batch::BatchRequest,
+    constants::{BATCH_ROUTE, EVOLUTION_ROUTE, TRANSLATION_ROUTE},
+    metrics,
+    problem::{Problem, ProblemType},
+};
+use axum::{
+    body::{Body, to_bytes},
+    extract::{ConnectInfo, Request, State},
+    http::{HeaderMap, HeaderName, HeaderValue, header::RETRY_AFTER},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use ipnet::IpNet;
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tracing::debug;
+
+const FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+const RATE_LIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+const RATE_LIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+const RATE_LIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+
+/// Number of tracked buckets above which idle (full) buckets are dropped.
+const MAX_TRACKED_BUCKETS: usize = 10_000;
+/// Largest batch body read to count its names, as axum's default JSON body limit.
+const MAX_BATCH_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// Request budget of a route.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Budget {
+    capacity: f64,
+    refill_per_sec: f64,
+}
+
+impl Budget {
+    fn per_minute(requests: usize) -> Self {
+        Self {
+            capacity: requests as f64,
+            refill_per_sec: requests as f64 / 60.0,
+        }
+    }
+}
+
+struct Bucket {
+    budget: Budget,
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    /// Refills the bucket up to `now`.
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens =
+            (self.tokens + elapsed * self.budget.refill_per_sec).min(self.budget.capacity);
+        self.updated_at = now;
+    }
+}
+
+/// Outcome of a rate limit check.
+#[derive(Debug, Clone, PartialEq)]
+struct Decision {
+    allowed: bool,
+    limit: u64,
+    remaining: u64,
+    /// Time until the bucket is full again
+    reset: Duration,
+    /// Time until the next request is allowed, when rejected
+    retry_after: Option<Duration>,
+}
+
+/// Token-bucket rate limiter keyed by route and client.
+pub struct RateLimiter {
+    budgets: HashMap<String, Budget>,
+    trusted_proxies: Vec<IpNet>,
+    buckets: Mutex<HashMap<(String, String), Bucket>>,
+}
+
+impl RateLimiter {
+    /// Creates a new rate limiter.
+    ///
+    /// # Arguments
+    ///
+    /// * `budgets` - Normalized routes (e.g., `/pokemon/{name}`) and their budget, in
+    ///   requests per minute
+    /// * `trusted_proxies` - Networks of the proxies whose `X-Forwarded-For` is trusted
+    pub fn new(budgets: Vec<(&str, usize)>, trusted_proxies: Vec<IpNet>) -> Self {
+        Self {
+            budgets: budgets
+                .into_iter()
+                .map(|(route, requests)| (route.to_string(), Budget::per_minute(requests)))
+                .collect(),
+            trusted_proxies,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Takes `cost` tokens from the bucket of `client` for `route`, if that many are left.
+    fn check(
+        &self,
+        route: &str,
+        client: &str,
+        budget: Budget,
+        cost: usize,
+        now: Instant,
+    ) -> Decision {
+        let mut buckets = self.buckets.lock().expect("rate limiter lock poisoned");
+        if buckets.len() >= MAX_TRACKED_BUCKETS {
+            buckets.retain(|_, bucket| {
+                bucket.refill(now);
+                bucket.tokens < bucket.budget.capacity
+            });
+        }
+        let bucket = buckets
+            .entry((route.to_string(), client.to_string()))
+            .or_insert(Bucket {
+                budget,
+                tokens: budget.capacity,
+                updated_at: now,
+            });
+        // the quota of an API key may have changed since the bucket was created
+        bucket.budget = budget;
+        bucket.refill(now);
+
+        let cost = cost as f64;
+        let allowed = bucket.tokens >= cost;
+        if allowed {
+            bucket.tokens -= cost;
+        }
+        // a zero budget never refills: the bucket is never full again nor the next request allowed
+        let secs_until = |tokens: f64| match tokens > 0.0 {
+            true => {
+                Duration::try_from_secs_f64(tokens / budget.refill_per_sec).unwrap_or(Duration::MAX)
+            }
+            false => Duration::ZERO,
+        };
+        Decision {
+            allowed,
+            limit: budget.capacity as u64,
+            remaining: bucket.tokens.floor() as u64,
+            reset: secs_until(budget.capacity - bucket.tokens),
+            retry_after: (!allowed).then(|| secs_until(cost - bucket.tokens)),
+        }
+    }
+
+    /// Identifies the client: authenticated API key first, then the client address.
+    ///
+    /// When the peer is a trusted proxy, `X-Forwarded-For` is walked from the right,
+    /// skipping trusted proxies: the first untrusted address is the client (a client
+    /// can prepend anything to the header, but not append).
+    fn client_identity(
+        &self,
+        caller: Option<&Caller>,
+        headers: &HeaderMap,
+        peer: Option<IpAddr>,
+    ) -> String {
+        if let Some(caller) = caller.filter(|caller| caller.authenticated) {
+            return format!("key:{}", caller.name);
+        }
+        let Some(peer) = peer else {
+            return "unknown".to_string();
+        };
+        if !self.is_trusted(peer) {
+            return format!("ip:{peer}");
+        }
+        let forwarded: Vec<IpAddr> = headers
+            .get_all(FORWARDED_FOR_HEADER)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .filter_map(|address| address.trim().parse().ok())
+            .collect();
+        let client = forwarded
+            .iter()
+            .rev()
+            .find(|address| !self.is_trusted(**address))
+            .or(forwarded.first())
+            .copied()
+            .unwrap_or(peer);
+        format!("ip:{client}")
+    }
+
+    fn is_trusted(&self, address: IpAddr) -> bool {
+        self.trusted_proxies
+            .iter()
+            .any(|network| network.contains(&address))
+    }
+}
+
+/// Middleware enforcing the route budgets.
+pub async fn limit(State(limiter): State<Arc<RateLimiter>>, req: Request, next: Next) -> Response {
+    let mut route = metrics::normalize_path(req.uri().path());
+    if route == EVOLUTION_ROUTE && req.uri().query().is_some_and(translates) {
+        route = TRANSLATION_ROUTE.to_string();
+    }
+    let Some(route_budget) = limiter.budgets.get(&route).copied() else {
+        return next.run(req).await;
+    };
+    let caller = req.extensions().get::<Caller>().cloned();
+    let budget = caller
+        .as_ref()
+        .and_then(|caller| caller.quota)
+        .map(Budget::per_minute)
+        .unwrap_or(route_budget);
+    let peer = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(address)| address.ip());
+    let client = limiter.client_identity(caller.as_ref(), req.headers(), peer);
+    let (req, cost) = match route == BATCH_ROUTE {
+        true => match batch_cost(req).await {
+            Ok(batch) => batch,
+            Err(problem) => return problem.into_response(),
+        },
+        false => (req, 1),
+    };
+    let decision = limiter.check(&route, &client, budget, cost, Instant::now());
+
+    let mut response = match decision.allowed {
+        true => next.run(req).await,
+        false => {
+            metrics::RATE_LIMIT_REJECTIONS
+                .with_label_values(&[&route])
+                .inc();
+            debug!(route, "Rate limit exceeded");
+            Problem::new(ProblemType::RateLimited)
+                .with_detail(match cost {
+                    1 => format!("{} requests per minute allowed", decision.limit),
+                    _ => format!(
+                        "{} lookups per minute allowed, {} requested",
+                        decision.limit, cost
+                    ),
+                })
+                .into_response()
+        }
+    };
+    let headers = response.headers_mut();
+    headers.insert(RATE_LIMIT_LIMIT, HeaderValue::from(decision.limit));
+    headers.insert(RATE_LIMIT_REMAINING, HeaderValue::from(decision.remaining));
+    headers.insert(
+        RATE_LIMIT_RESET,
+        HeaderValue::from(decision.reset.as_secs_f64().ceil() as u64),
+    );
+    if let Some(retry_after) = decision.retry_after {
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from(retry_after.as_secs_f64().ceil() as u64),
+        );
+    }
+    response
+}
+
+/// Counts the names of a batch request, buffering its body.
+///
+/// A body that is not a batch costs one token, the handler rejecting it.
+async fn batch_cost(req: Request) -> Result<(Request, usize), Problem> {
+    let (parts, body) = req.into_parts();
+    let bytes = to_bytes(body, MAX_BATCH_BODY_BYTES).await.map_err(|e| {
+        Problem::new(ProblemType::InvalidRequest)
+            .with_detail(format!("failed to read the batch body: {}", e))
+    })?;
+    let cost = serde_json::from_slice::<BatchRequest>(&bytes)
+        .map(|batch| batch.names.len().max(1))
+        .unwrap_or(1);
+    Ok((Request::from_parts(parts, Body::from(bytes)), cost))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problem::PROBLEM_JSON;
+    use axum::{
+        Router,
+        body::Body,
+        http::{StatusCode, header::CONTENT_TYPE},
+        middleware,
+        routing::{get, post},
+    };
+    use tower::ServiceExt;
+
+    fn limiter(trusted_proxies: &[&str]) -> RateLimiter {
+        RateLimiter::new(
+            vec![("/pokemon/{name}", 2)],
+            trusted_proxies.iter().map(|p| p.parse().unwrap()).collect(),
+        )
+    }
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| {
+                (
+                    HeaderName::from_static(name),
+                    HeaderValue::from_static(value),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rejects_requests_beyond_the_budget_until_refilled() {
+        let limiter = limiter(&[]);
+        let budget = Budget::per_minute(2);
+        let now = Instant::now();
+
+        assert!(
+            limiter
+                .check("/pokemon/{name}", "a", budget, 1, now)
+                .allowed
+        );
+        assert!(
+            limiter
+                .check("/pokemon/{name}", "a", budget, 1, now)
+                .allowed
+        );
+        let rejected = limiter.check("/pokemon/{name}", "a", budget, 1, now);
+        assert!(!rejected.allowed);
+        assert_eq!(rejected.remaining, 0);
+        assert_eq!(rejected.retry_after, Some(Duration::from_secs(30)));
+
+        // one token is refilled every 30 seconds
+        let later = now + Duration::from_secs(30);
+        assert!(
+            limiter
+                .check("/pokemon/{name}", "a", budget, 1, later)
+                .allowed
+        );
+    }
+
+    #[test]
+    fn rejects_every_request_of_a_zero_budget() {
+        let limiter = limiter(&[]);
+        let now = Instant::now();
+
+        let rejected = limiter.check("/pokemon/{name}", "a", Budget::per_minute(0), 1, now);
+
+        assert!(!rejected.allowed);
+        assert_eq!(rejected.reset, Duration::ZERO);
+        assert_eq!(rejected.retry_after, Some(Duration::MAX));
+        // the lock is not poisoned
+        assert!(
+            limiter
+                .check("/pokemon/{name}", "b", Budget::per_minute(1), 1, now)
+                .allowed
+        );
+    }
+
+    #[test]
+    fn keeps_separate_buckets_per_client_and_route() {
+        let limiter = limiter(&[]);
+        let budget = Budget::per_minute(1);
+        let now = Instant::now();
+
+        assert!(
+            limiter
+                .check("/pokemon/{name}", "a", budget, 1, now)
+                .allowed
+        );
+        assert!(
+            !limiter
+                .check("/pokemon/{name}", "a", budget, 1, now)
+                .allowed
+        );
+        assert!(
+            limiter
+                .check("/pokemon/{name}", "b", budget, 1, now)
+                .allowed
+        );
+        assert!(
+            limiter
+                .check("/pokemon/{name}/translation/", "a", budget, 1, now)
+                .allowed
+        );
+    }
+
+    #[test]
+    fn identifies_clients_by_authenticated_api_key_first() {
+        let limiter = limiter(&["10.0.0.0/8"]);
+        let headers = headers(&[("x-api-key", "secret"), ("x-forwarded-for", "1.2.3.4")]);
+        let peer = Some("10.0.0.1".parse().unwrap());
+        let mut caller = Caller {
+            name: "team-a".to_string(),
+            quota: None,
+            authenticated: true,
+        };
+
+        let client = limiter.client_identity(Some(&caller), &headers, peer);
+        assert_eq!(client, "key:team-a");
+
+        // unverified keys do not identify the client
+        caller.authenticated = false;
+        let client = limiter.client_identity(Some(&caller), &headers, peer);
+        assert_eq!(client, "ip:1.2.3.4");
+    }
+
+    #[test]
+    fn ignores_forwarded_for_from_untrusted_peers() {
+        let limiter = limiter(&["10.0.0.0/8"]);
+        let headers = headers(&[("x-forwarded-for", "1.2.3.4")]);
+
+        let client = limiter.client_identity(None, &headers, Some("192.168.1.1".parse().unwrap()));
+
+        assert_eq!(client, "ip:192.168.1.1");
+    }
+
+    #[test]
+    fn takes_the_rightmost_untrusted_forwarded_address_from_trusted_peers() {
+        let limiter = limiter(&["10.0.0.0/8"]);
+        // 6.6.6.6 is spoofed by the client, 10.0.0.2 is an intermediate trusted proxy
+        let headers = headers(&[("x-forwarded-for", "6.6.6.6, 1.2.3.4, 10.0.0.2")]);
+
+        let client = limiter.client_identity(None, &headers, Some("10.0.0.1".parse().unwrap()));
+
+        assert_eq!(client, "ip:1.2.3.4");
+    }
+
+    #[tokio::test]
+    async fn middleware_sets_headers_and_rejects_with_429() {
+        let limiter = Arc::new(limiter(&[]));
+        let app = Router::new()
+            .route("/pokemon/{name}", get(|| async { "ok" }))
+            .route("/health", get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(limiter, limit));
+        let request = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let first = app
+            .clone()
+            .oneshot(request("/pokemon/pikachu"))
+            .await
+            .unwrap();
+        assert_eq!(first.status(), StatusCode::OK);
+        assert_eq!(first.headers()["ratelimit-limit"], "2");
+        assert_eq!(first.headers()["ratelimit-remaining"], "1");
+
+        let _ = app
+            .clone()
+            .oneshot(request("/pokemon/eevee"))
+            .await
+            .unwrap();
+        let rejected = app
+            .clone()
+            .oneshot(request("/pokemon/pikachu"))
+            .await
+            .unwrap();
+        assert_eq!(rejected.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(rejected.headers()["ratelimit-remaining"], "0");
+        assert_eq!(rejected.headers()[RETRY_AFTER], "30");
+        assert_eq!(rejected.headers()[CONTENT_TYPE], PROBLEM_JSON);
+
+        // routes without a budget are not limited
+        let health = app.oneshot(request("/health")).await.unwrap();
+        assert_eq!(health.status(), StatusCode::OK);
+        assert!(!health.headers().contains_key("ratelimit-limit"));
+    }
+
+    #[tokio::test]
+    async fn api_key_quota_replaces_the_route_budget() {
+        let limiter = Arc::new(limiter(&[]));
+        let app = Router::new()
+            .route("/pokemon/{name}", get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(limiter, limit));
+        let mut request = Request::builder()
+            .uri("/pokemon/pikachu")
+            .body(Body::empty())
+            .unwrap();
+        request.extensions_mut().insert(Caller {
+            name: "team-a".to_string(),
+            quota: Some(5),
+            authenticated: true,
+        });
+
+        let response = app.oneshot(request).await.unwrap();
+
+        assert_eq!(response.headers()["ratelimit-limit"], "5");
+        assert_eq!(response.headers()["ratelimit-remaining"], "4");
+    }
+
+    #[tokio::test]
+    async fn charges_batches_one_token_per_name() {
+        let limiter = Arc::new(RateLimiter::new(vec![(BATCH_ROUTE, 3)], vec![]));
+        let app = Router::new()
+            .route(BATCH_ROUTE, post(|body: String| async move { body }))
+            .layer(middleware::from_fn_with_state(limiter, limit));
+        let request = |names: &str| {
+            Request::post(BATCH_ROUTE)
+                .header(CONTENT_TYPE, "application/json")
+                .body(Body::from(format!(r#"{{"names":[{}]}}"#, names)))
+                .unwrap()
+        };
+
+        let first = app
+            .clone()
+            .oneshot(request(r#""pikachu","eevee""#))
+            .await
+            .unwrap();
+        assert_eq!(first.status(), StatusCode::OK);
+        assert_eq!(first.headers()["ratelimit-remaining"], "1");
+        // the handler still gets the body
+        let body = axum::body::to_bytes(first.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body, r#"{"names":["pikachu","eevee"]}"#);
+
+        let rejected = app
+            .clone()
+            .oneshot(request(r#""mew","ditto""#))
+            .await
+            .unwrap();
+        assert_eq!(rejected.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(rejected.headers()["ratelimit-remaining"], "1");
+
+        let single = app.oneshot(request(r#""mew""#)).await.unwrap();
+        assert_eq!(single.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn charges_translated_evolutions_to_the_translation_budget() {
+        let limiter = Arc::new(RateLimiter::new(
+            vec![(EVOLUTION_ROUTE, 5), (TRANSLATION_ROUTE, 1)],
+            vec![],
+        ));
+        let app = Router::new()
+            .route(EVOLUTION_ROUTE, get(|| async { "ok" }))
+            .route(TRANSLATION_ROUTE, get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(limiter, limit));
+        let request = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let translated = app
+            .clone()
+            .oneshot(request("/pokemon/eevee/evolution?translate=%74rue"))
+            .await
+            .unwrap();
+        assert_eq!(translated.status(), StatusCode::OK);
+        assert_eq!(translated.headers()["ratelimit-limit"], "1");
+
+        let untranslated = app
+            .clone()
+            .oneshot(request("/pokemon/eevee/evolution?translate=false"))
+            .await
+            .unwrap();
+        assert_eq!(untranslated.status(), StatusCode::OK);
+        assert_eq!(untranslated.headers()["ratelimit-limit"], "5");
+
+        // the translation bucket is shared with the translation route
+        let translation = app
+            .oneshot(request("/pokemon/pikachu/translation/"))
+            .await
+            .unwrap();
+        assert_eq!(translation.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+}
@@ -0,0 +1,471 @@
+//! # Readiness
+//!
+//! Readiness reporting for orchestration systems (e.g., Kubernetes readiness probes),
+//! as opposed to the `/health` liveness check.
+//!
+//! Every upstream dependency is checked with a lightweight probe call going through the
+//! [`PokemonApi`]/[`Translator`] traits (bypassing caches and circuit breakers):
+//! - Probes run concurrently, each bounded by a timeout
+//! - Probe results are cached for a short interval, so that frequent readiness checks
+//!   do not hammer the upstreams
+//! - A dependency is down if its last probe failed or its circuit breaker is open
+//!
+//! The service is not ready while a *required* dependency is down; optional ones are
+//! reported but do not affect the overall status. Once shutting down, the service is
+//! never ready again and dependencies are no longer probed.
+
+use crate::{
+    http::{
+        circuit_breaker::{CircuitBreaker, CircuitState},
+        client::{ErrorKind, HttpClientError},
+    },
+    pokemon_api::client::PokemonApi,
+    translator::client::Translator,
+};
+use async_trait::async_trait;
+use serde::Serialize;
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+use tokio::{sync::Mutex, task::JoinSet};
+use tracing::warn;
+use utoipa::ToSchema;
+
+/// Overall readiness status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReadinessStatus {
+    Ready,
+    NotReady,
+    ShuttingDown,
+}
+
+/// Status of a single dependency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum DependencyStatus {
+    Up,
+    Down,
+}
+
+/// Readiness of a single upstream dependency.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DependencyReport {
+    /// Dependency name (e.g., pokeapi)
+    pub name: String,
+    /// Whether the dependency being down makes the service not ready
+    pub required: bool,
+    /// Dependency status
+    pub status: DependencyStatus,
+    /// State of the dependency circuit breaker
+    pub circuit: CircuitState,
+    /// Duration of the last probe, in milliseconds
+    pub latency_ms: u64,
+    /// Last probe error, kept after the dependency recovers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+/// Readiness report returned by the `/ready` endpoint.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReadinessReport {
+    /// Overall readiness status
+    pub status: ReadinessStatus,
+    /// Per dependency details
+    pub dependencies: Vec<DependencyReport>,
+}
+
+impl ReadinessReport {
+    pub fn is_ready(&self) -> bool {
+        self.status == ReadinessStatus::Ready
+    }
+}
+
+/// Lightweight reachability check of a dependency.
+#[async_trait]
+pub trait Probe: Send + Sync {
+    async fn probe(&self) -> Result<(), HttpClientError>;
+}
+
+#[async_trait]
+impl Probe for Arc<dyn PokemonApi> {
+    async fn probe(&self) -> Result<(), HttpClientError> {
+        PokemonApi::probe(self.as_ref()).await
+    }
+}
+
+#[async_trait]
+impl Probe for Arc<dyn Translator> {
+    async fn probe(&self) -> Result<(), HttpClientError> {
+        Translator::probe(self.as_ref()).await
+    }
+}
+
+/// An upstream dependency checked by the readiness endpoint.
+pub struct Dependency {
+    name: &'static str,
+    required: bool,
+    probe: Box<dyn Probe>,
+    breaker: Arc<CircuitBreaker>,
+}
+
+impl Dependency {
+    /// Creates a new dependency.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Dependency name (e.g., pokeapi)
+    /// * `required` - Whether the dependency being down makes the service not ready
+    /// * `probe` - Reachability check of the dependency
+    /// * `breaker` - Circuit breaker guarding the dependency
+    pub fn new(
+        name: &'static str,
+        required: bool,
+        probe: Box<dyn Probe>,
+        breaker: Arc<CircuitBreaker>,
+    ) -> Self {
+        Self {
+            name,
+            required,
+            probe,
+            breaker,
+        }
+    }
+}
+
+/// Outcome of the last probe of a dependency.
+#[derive(Clone, Default)]
+struct ProbeResult {
+    latency: Duration,
+    error: Option<HttpClientError>,
+    last_error: Option<HttpClientError>,
+}
+
+/// Probe results, together with the time they were collected.
+#[derive(Default)]
+struct ProbeCache {
+    probed_at: Option<Instant>,
+    results: Vec<ProbeResult>,
+}
+
+/// Computes readiness by probing the upstream dependencies.
+pub struct Readiness {
+    dependencies: Vec<Arc<Dependency>>,
+    cache_ttl: Duration,
+    probe_timeout: Duration,
+    cache: Mutex<ProbeCache>,
+    shutting_down: AtomicBool,
+}
+
+impl Readiness {
+    /// Creates a new readiness reporter.
+    ///
+    /// # Arguments
+    ///
+    /// * `dependencies` - Upstream dependencies to probe
+    /// * `cache_ttl` - How long probe results are reused
+    /// * `probe_timeout` - Maximum duration of a single probe
+    pub fn new(
+        dependencies: Vec<Dependency>,
+        cache_ttl: Duration,
+        probe_timeout: Duration,
+    ) -> Self {
+        Self {
+            dependencies: dependencies.into_iter().map(Arc::new).collect(),
+            cache_ttl,
+            probe_timeout,
+            cache: Mutex::new(ProbeCache::default()),
+            shutting_down: AtomicBool::new(false),
+        }
+    }
+
+    /// Flips readiness to failing for good, as the service is shutting down.
+    pub fn shut_down(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+    }
+
+    /// Reports the current readiness of the service, probing the dependencies if the
+    /// cached results are stale.
+    ///
+    /// Concurrent callers wait for a single round of probes.
+    pub async fn report(&self) -> ReadinessReport {
+        if self.shutting_down.load(Ordering::SeqCst) {
+            return ReadinessReport {
+                status: ReadinessStatus::ShuttingDown,
+                dependencies: vec![],
+            };
+        }
+        let results = {
+            let mut cache = self.cache.lock().await;
+            if cache
+                .probed_at
+                .is_none_or(|at| at.elapsed() >= self.cache_ttl)
+            {
+                cache.results = self.probe_all(&cache.results).await;
+                cache.probed_at = Some(Instant::now());
+            }
+            cache.results.clone()
+        };
+
+        let dependencies: Vec<DependencyReport> = self
+            .dependencies
+            .iter()
+            .zip(results)
+            .map(|(dependency, result)| {
+                let circuit = dependency.breaker.state();
+                let status = match (&result.error, circuit) {
+                    (None, CircuitState::Closed | CircuitState::HalfOpen) => DependencyStatus::Up,
+                    _ => DependencyStatus::Down,
+                };
+                DependencyReport {
+                    name: dependency.name.to_string(),
+                    required: dependency.required,
+                    status,
+                    circuit,
+                    latency_ms: result.latency.as_millis() as u64,
+                    last_error: result.last_error.map(|e| e.to_string()),
+                }
+            })
+            .collect();
+        let status = match dependencies
+            .iter()
+            .any(|d| d.required && d.status == DependencyStatus::Down)
+        {
+            true => ReadinessStatus::NotReady,
+            false => ReadinessStatus::Ready,
+        };
+        ReadinessReport {
+            status,
+            dependencies,
+        }
+    }
+
+    /// Probes every dependency concurrently, carrying over the last errors of `previous`.
+    async fn probe_all(&self, previous: &[ProbeResult]) -> Vec<ProbeResult> {
+        let mut probes = JoinSet::new();
+        for (index, dependency) in self.dependencies.iter().enumerate() {
+            let (dependency, timeout) = (dependency.clone(), self.probe_timeout);
+            probes.spawn(async move {
+                let started = Instant::now();
+                let outcome = tokio::time::timeout(timeout, dependency.probe.probe())
+                    .await
+                    .unwrap_or_else(|_| Err(ErrorKind::Timeout.into()));
+                if let Err(e) = &outcome {
+                    warn!(dependency = dependency.name, error = %e, "Readiness probe failed");
+                }
+                (index, started.elapsed(), outcome.err())
+            });
+        }
+
+        let mut results = vec![ProbeResult::default(); self.dependencies.len()];
+        while let Some(joined) = probes.join_next().await {
+            let Ok((index, latency, error)) = joined else {
+                continue;
+            };
+            let last_error = error
+                .clone()
+                .or_else(|| previous.get(index).and_then(|p| p.last_error.clone()));
+            results[index] = ProbeResult {
+                latency,
+                error,
+                last_error,
+            };
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::circuit_breaker::CircuitBreakerConfig;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    /// Counts probes, failing them while `fail` is set.
+    #[derive(Default)]
+    struct FakeProbe {
+        calls: AtomicUsize,
+        fail: AtomicBool,
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl Probe for Arc<FakeProbe> {
+        async fn probe(&self) -> Result<(), HttpClientError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(self.delay).await;
+            match self.fail.load(Ordering::SeqCst) {
+                true => Err(ErrorKind::ServiceUnavailable.into()),
+                false => Ok(()),
+            }
+        }
+    }
+
+    fn breaker(upstream: &'static str) -> Arc<CircuitBreaker> {
+        Arc::new(CircuitBreaker::new(
+            upstream,
+            CircuitBreakerConfig {
+                failure_threshold: 1,
+                window: Duration::from_secs(60),
+                cool_down: Duration::from_secs(60),
+            },
+        ))
+    }
+
+    fn dependency(name: &'static str, required: bool, probe: &Arc<FakeProbe>) -> Dependency {
+        Dependency::new(name, required, Box::new(probe.clone()), breaker(name))
+    }
+
+    fn readiness(dependencies: Vec<Dependency>) -> Readiness {
+        Readiness::new(dependencies, Duration::ZERO, Duration::from_secs(1))
+    }
+
+    #[tokio::test]
+    async fn is_ready_when_all_dependencies_are_up() {
+        let probe = Arc::new(FakeProbe::default());
+        let readiness = readiness(vec![
+            dependency("pokeapi", true, &probe),
+            dependency("funtranslations", true, &probe),
+        ]);
+
+        let report = readiness.report().await;
+
+        assert!(report.is_ready());
+        assert_eq!(report.dependencies.len(), 2);
+        assert!(report.dependencies.iter().all(|d| {
+            d.status == DependencyStatus::Up
+                && d.circuit == CircuitState::Closed
+                && d.last_error.is_none()
+        }));
+        assert_eq!(probe.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn is_not_ready_when_a_required_dependency_is_down() {
+        let (pokeapi, funtranslations) = (Arc::new(FakeProbe::default()), Arc::default());
+        pokeapi.fail.store(true, Ordering::SeqCst);
+        let readiness = readiness(vec![
+            dependency("pokeapi", true, &pokeapi),
+            dependency("funtranslations", true, &funtranslations),
+        ]);
+
+        let report = readiness.report().await;
+
+        assert!(!report.is_ready());
+        let down: Vec<_> = report
+            .dependencies
+            .iter()
+            .filter(|d| d.status == DependencyStatus::Down)
+            .collect();
+        assert_eq!(down.len(), 1);
+        assert_eq!(down[0].name, "pokeapi");
+        assert_eq!(down[0].last_error.as_deref(), Some("service unavailable"));
+    }
+
+    #[tokio::test]
+    async fn is_ready_when_only_an_optional_dependency_is_down() {
+        let (pokeapi, funtranslations) = (Arc::default(), Arc::new(FakeProbe::default()));
+        funtranslations.fail.store(true, Ordering::SeqCst);
+        let readiness = readiness(vec![
+            dependency("pokeapi", true, &pokeapi),
+            dependency("funtranslations", false, &funtranslations),
+        ]);
+
+        let report = readiness.report().await;
+
+        assert!(report.is_ready());
+        assert_eq!(report.dependencies[1].status, DependencyStatus::Down);
+    }
+
+    #[tokio::test]
+    async fn is_not_ready_while_a_required_circuit_is_open() {
+        let probe = Arc::new(FakeProbe::default());
+        let pokeapi = breaker("pokeapi");
+        let readiness = readiness(vec![Dependency::new(
+            "pokeapi",
+            true,
+            Box::new(probe.clone()),
+            pokeapi.clone(),
+        )]);
+
+        let _ = pokeapi
+            .call(|| async { Err::<(), HttpClientError>(ErrorKind::RequestFailed.into()) })
+            .await;
+        let report = readiness.report().await;
+
+        assert!(!report.is_ready());
+        assert_eq!(report.dependencies[0].circuit, CircuitState::Open);
+        assert_eq!(report.dependencies[0].status, DependencyStatus::Down);
+    }
+
+    #[tokio::test]
+    async fn reuses_probe_results_within_the_cache_ttl() {
+        let probe = Arc::new(FakeProbe::default());
+        let readiness = Readiness::new(
+            vec![dependency("pokeapi", true, &probe)],
+            Duration::from_secs(60),
+            Duration::from_secs(1),
+        );
+
+        for _ in 0..3 {
+            assert!(readiness.report().await.is_ready());
+        }
+
+        assert_eq!(probe.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn keeps_the_last_error_after_recovery() {
+        let probe = Arc::new(FakeProbe::default());
+        probe.fail.store(true, Ordering::SeqCst);
+        let readiness = readiness(vec![dependency("pokeapi", true, &probe)]);
+
+        assert!(!readiness.report().await.is_ready());
+        probe.fail.store(false, Ordering::SeqCst);
+        let report = readiness.report().await;
+
+        assert!(report.is_ready());
+        assert_eq!(
+            report.dependencies[0].last_error.as_deref(),
+            Some("service unavailable")
+        );
+    }
+
+    #[tokio::test]
+    async fn is_not_ready_once_shutting_down() {
+        let probe = Arc::new(FakeProbe::default());
+        let readiness = readiness(vec![dependency("pokeapi", true, &probe)]);
+
+        readiness.shut_down();
+        let report = readiness.report().await;
+
+        assert!(!report.is_ready());
+        assert_eq!(report.status, ReadinessStatus::ShuttingDown);
+        assert_eq!(probe.calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn times_out_slow_probes() {
+        let probe = Arc::new(FakeProbe {
+            delay: Duration::from_secs(5),
+            ..Default::default()
+        });
+        let readiness = Readiness::new(
+            vec![dependency("pokeapi", true, &probe)],
+            Duration::ZERO,
+            Duration::from_millis(20),
+        );
+
+        let report = readiness.report().await;
+
+        assert!(!report.is_ready());
+        assert_eq!(
+            report.dependencies[0].last_error.as_deref(),
+            Some("request timed out")
+        );
+    }
+}
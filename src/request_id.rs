@@ -0,0 +1,119 @@
+//! # Request IDs
+//!
+//! Identification of every request, as an axum middleware:
+//! - The `X-Request-Id` header of the request is reused when it is a plausible identifier
+//!   (1 to 128 ASCII letters, digits, `-`, `_` or `.`), otherwise a random one is generated
+//! - The ID is echoed in the `X-Request-Id` response header, recorded on a `request`
+//!   tracing span, and available to the request handlers through [`current`] (e.g., to
+//!   report it in problem details)
+
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use tracing::{Instrument, info_span};
+
+/// Header carrying the request ID.
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+/// Longest request ID accepted from clients.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// ID of the request being served, if any.
+pub fn current() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Whether a client supplied request ID can be reused as is.
+fn is_valid(id: &str) -> bool {
+    (1..=MAX_REQUEST_ID_LEN).contains(&id.len())
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+}
+
+/// Generates a random 128-bit request ID, hex encoded.
+fn generate() -> String {
+    format!("{:032x}", fastrand::u128(..))
+}
+
+/// Middleware assigning an ID to every request.
+pub async fn propagate(req: Request, next: Next) -> Response {
+    let id = req
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| is_valid(id))
+        .map_or_else(generate, str::to_string);
+    let span = info_span!("request", request_id = %id);
+
+    let mut response = REQUEST_ID
+        .scope(id.clone(), next.run(req))
+        .instrument(span)
+        .await;
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, body::Body, middleware, routing::get};
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        Router::new()
+            .route("/", get(|| async { current().unwrap_or_default() }))
+            .layer(middleware::from_fn(propagate))
+    }
+
+    async fn call(request_id: Option<&str>) -> (String, String) {
+        let mut request = axum::http::Request::builder().uri("/");
+        if let Some(id) = request_id {
+            request = request.header(REQUEST_ID_HEADER, id);
+        }
+        let response = app()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let header = response.headers()[REQUEST_ID_HEADER]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (header, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn reuses_valid_client_request_ids() {
+        let (header, current) = call(Some("req-42.a_b")).await;
+
+        assert_eq!(header, "req-42.a_b");
+        assert_eq!(current, "req-42.a_b");
+    }
+
+    #[tokio::test]
+    async fn generates_request_ids_when_missing_or_invalid() {
+        let (generated, current) = call(None).await;
+        assert_eq!(generated.len(), 32);
+        assert_eq!(generated, current);
+
+        let (replaced, _) = call(Some("not valid!")).await;
+        assert_ne!(replaced, "not valid!");
+        assert_eq!(replaced.len(), 32);
+    }
+
+    #[test]
+    fn has_no_current_id_outside_requests() {
+        assert_eq!(current(), None);
+    }
+}
@@ -0,0 +1,303 @@
+//! # Graceful Shutdown
+//!
+//! Serves the application until SIGTERM or SIGINT is received, then:
+//! 1. Runs the `on_signal` hook (readiness is flipped to failing, so that load balancers
+//!    stop routing traffic to the instance)
+//! 2. Stops accepting new connections
+//! 3. Drains in-flight requests for up to the configured grace period; requests still
+//!    running when it elapses are given up on, and cut off when the process exits
+//!
+//! Flushing caches and logging the returned [`ShutdownSummary`] is left to the caller.
+
+use axum::{
+    Router,
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+use std::{
+    future::{Future, IntoFuture},
+    net::SocketAddr,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
+};
+use tokio::{net::TcpListener, sync::watch};
+use tracing::{info, warn};
+
+/// Counter of the requests being served, fed by the [`track_in_flight`] middleware.
+#[derive(Clone, Default)]
+pub struct InFlightRequests {
+    count: Arc<AtomicUsize>,
+}
+
+impl InFlightRequests {
+    /// Returns the number of requests being served.
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+}
+
+/// Decrements the in-flight counter when the request completes or is aborted.
+struct InFlightGuard(Arc<AtomicUsize>);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Middleware counting in-flight requests.
+pub async fn track_in_flight(
+    State(in_flight): State<InFlightRequests>,
+    req: Request,
+    next: Next,
+) -> Response {
+    in_flight.count.fetch_add(1, Ordering::SeqCst);
+    let _guard = InFlightGuard(in_flight.count.clone());
+    next.run(req).await
+}
+
+/// What happened while shutting down.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShutdownSummary {
+    /// Requests in flight when the signal was received
+    pub in_flight_at_signal: usize,
+    /// Requests still running when the grace period elapsed
+    pub abandoned: usize,
+    /// Time spent draining in-flight requests
+    pub drain_duration: Duration,
+}
+
+/// Resolves once SIGTERM or SIGINT (Ctrl+C) is received.
+pub async fn signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            warn!(error = %e, "Failed to listen for SIGINT");
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                sigterm.recv().await;
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to listen for SIGTERM");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => info!(signal = "SIGINT", "Shutdown signal received"),
+        _ = terminate => info!(signal = "SIGTERM", "Shutdown signal received"),
+    }
+}
+
+/// Serves `app` until `shutdown` resolves, then drains in-flight requests.
+///
+/// # Arguments
+///
+/// * `listener` - Bound listener accepting connections
+/// * `app` - Application router, with the [`track_in_flight`] middleware fed by `in_flight`;
+///   the peer address is available to handlers and middlewares as `ConnectInfo<SocketAddr>`
+/// * `in_flight` - In-flight requests counter
+/// * `shutdown` - Resolves when the server must shut down (e.g., [`signal`])
+/// * `grace_period` - Maximum time given to in-flight requests to complete
+/// * `on_signal` - Called as soon as `shutdown` resolves, before connections are drained
+///
+/// # Errors
+///
+/// Returns an error if the server fails.
+pub async fn serve<S, F>(
+    listener: TcpListener,
+    app: Router,
+    in_flight: InFlightRequests,
+    shutdown: S,
+    grace_period: Duration,
+    on_signal: F,
+) -> std::io::Result<ShutdownSummary>
+where
+    S: Future<Output = ()>,
+    F: FnOnce(),
+{
+    let (stop, mut stopped) = watch::channel(false);
+    let server = axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(async move {
+        let _ = stopped.wait_for(|stop| *stop).await;
+    })
+    .into_future();
+    tokio::pin!(server);
+
+    tokio::select! {
+        result = &mut server => {
+            result?;
+            return Ok(ShutdownSummary {
+                in_flight_at_signal: 0,
+                abandoned: 0,
+                drain_duration: Duration::ZERO,
+            });
+        }
+        _ = shutdown => {}
+    }
+
+    on_signal();
+    let in_flight_at_signal = in_flight.count();
+    info!(
+        in_flight = in_flight_at_signal,
+        grace_period_secs = grace_period.as_secs_f64(),
+        "Draining in-flight requests"
+    );
+    let started = Instant::now();
+    let _ = stop.send(true);
+
+    let abandoned = match tokio::time::timeout(grace_period, &mut server).await {
+        Ok(result) => {
+            result?;
+            0
+        }
+        Err(_) => {
+            let abandoned = in_flight.count();
+            warn!(
+                abandoned,
+                "Grace period elapsed, giving up on in-flight requests"
+            );
+            abandoned
+        }
+    };
+    Ok(ShutdownSummary {
+        in_flight_at_signal,
+        abandoned,
+        drain_duration: started.elapsed(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{middleware, routing::get};
+    use tokio::sync::oneshot;
+
+    /// Starts a server whose `/slow` route answers after `delay`, returning its address,
+    /// the shutdown trigger and the server task.
+    async fn start(
+        delay: Duration,
+        grace_period: Duration,
+    ) -> (
+        SocketAddr,
+        oneshot::Sender<()>,
+        tokio::task::JoinHandle<std::io::Result<ShutdownSummary>>,
+    ) {
+        let in_flight = InFlightRequests::default();
+        let app = Router::new()
+            .route(
+                "/slow",
+                get(move || async move {
+                    tokio::time::sleep(delay).await;
+                    "done"
+                }),
+            )
+            .layer(middleware::from_fn_with_state(
+                in_flight.clone(),
+                track_in_flight,
+            ));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (trigger, triggered) = oneshot::channel::<()>();
+        let server = tokio::spawn(serve(
+            listener,
+            app,
+            in_flight,
+            async move {
+                let _ = triggered.await;
+            },
+            grace_period,
+            || {},
+        ));
+        (address, trigger, server)
+    }
+
+    /// Gives the server time to accept the requests sent beforehand.
+    async fn settle() {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    #[tokio::test]
+    async fn completes_in_flight_requests_after_the_signal() {
+        let (address, trigger, server) =
+            start(Duration::from_millis(300), Duration::from_secs(5)).await;
+        let request = tokio::spawn(reqwest::get(format!("http://{address}/slow")));
+        settle().await;
+
+        trigger.send(()).unwrap();
+        let response = request.await.unwrap().unwrap();
+
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+        assert_eq!(response.text().await.unwrap(), "done");
+        let summary = server.await.unwrap().unwrap();
+        assert_eq!(summary.in_flight_at_signal, 1);
+        assert_eq!(summary.abandoned, 0);
+    }
+
+    #[tokio::test]
+    async fn refuses_new_connections_while_draining() {
+        let (address, trigger, server) =
+            start(Duration::from_millis(300), Duration::from_secs(5)).await;
+        let request = tokio::spawn(reqwest::get(format!("http://{address}/slow")));
+        settle().await;
+
+        trigger.send(()).unwrap();
+        settle().await;
+        let late = reqwest::get(format!("http://{address}/slow")).await;
+
+        assert!(late.is_err());
+        assert!(request.await.unwrap().is_ok());
+        assert!(server.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn aborts_requests_outliving_the_grace_period() {
+        let (address, trigger, server) =
+            start(Duration::from_secs(10), Duration::from_millis(100)).await;
+        let request = tokio::spawn(reqwest::get(format!("http://{address}/slow")));
+        settle().await;
+
+        trigger.send(()).unwrap();
+        let summary = server.await.unwrap().unwrap();
+
+        assert_eq!(summary.in_flight_at_signal, 1);
+        assert_eq!(summary.abandoned, 1);
+        assert!(summary.drain_duration < Duration::from_secs(1));
+        request.abort();
+    }
+
+    #[tokio::test]
+    async fn calls_the_signal_hook_before_draining() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let called = std::cell::Cell::new(false);
+
+        let summary = serve(
+            listener,
+            Router::new(),
+            InFlightRequests::default(),
+            async {},
+            Duration::from_secs(1),
+            || called.set(true),
+        )
+        .await
+        .unwrap();
+
+        assert!(called.get());
+        assert_eq!(summary.in_flight_at_signal, 0);
+    }
+}
@@ -0,0 +1,456 @@
+//! # Persistent Translation Cache
+//!
+//! This module provides a [`Translator`] decorator backed by an embedded SQLite database:
+//! - Translations are keyed by (translator type, SHA-256 of the source text)
+//! - The store is a local file, so cached translations survive restarts
+//! - The cache is consulted before the remote call, saving the Fun Translations quota
+//!   (5 requests per hour on the free tier)
+//!
+//! ## Eviction
+//!
+//! The number of stored translations is bounded: once the limit is exceeded the least
+//! recently used entries are deleted.
+//!
+//! Storage failures never fail a translation: they are logged and treated as cache misses.
+
+use crate::{
+    http::client::{HttpClientError, TranslatorType},
+    metrics,
+    translator::client::{TranslationContents, TranslationEngine, TranslationResponse, Translator},
+};
+use rusqlite::{Connection, OptionalExtension, params};
+use sha2::{Digest, Sha256};
+use std::{
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tracing::{debug, warn};
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS translations (
+        translator   TEXT    NOT NULL,
+        text_hash    TEXT    NOT NULL,
+        translated   TEXT    NOT NULL,
+        created_at   INTEGER NOT NULL,
+        last_used_at INTEGER NOT NULL,
+        PRIMARY KEY (translator, text_hash)
+    );
+    CREATE INDEX IF NOT EXISTS translations_last_used_at ON translations (last_used_at);
+";
+
+/// SQLite-backed store of translated texts.
+pub struct TranslationStore {
+    connection: Mutex<Connection>,
+    max_entries: usize,
+}
+
+impl TranslationStore {
+    /// Opens (or creates) the translation store at `path`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Database file path (`:memory:` for a non persistent store)
+    /// * `max_entries` - Maximum number of stored translations
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be opened or initialized.
+    pub fn open(path: impl AsRef<Path>, max_entries: usize) -> Result<Self, rusqlite::Error> {
+        let connection = Connection::open(path)?;
+        connection.pragma_update(None, "journal_mode", "WAL")?;
+        connection.execute_batch(SCHEMA)?;
+        let store = Self {
+            connection: Mutex::new(connection),
+            max_entries,
+        };
+        metrics::TRANSLATION_CACHE_ENTRIES.set(store.len()? as i64);
+        Ok(store)
+    }
+
+    /// Checkpoints the write-ahead log into the database file, so that no write is
+    /// left pending in the WAL when the service stops.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the checkpoint fails.
+    pub fn flush(&self) -> Result<(), rusqlite::Error> {
+        let connection = self
+            .connection
+            .lock()
+            .expect("translation store lock poisoned");
+        connection.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))
+    }
+
+    /// Returns the number of stored translations.
+    pub fn len(&self) -> Result<usize, rusqlite::Error> {
+        let connection = self
+            .connection
+            .lock()
+            .expect("translation store lock poisoned");
+        connection.query_row("SELECT COUNT(*) FROM translations", [], |row| {
+            row.get::<_, i64>(0).map(|count| count as usize)
+        })
+    }
+
+    /// Looks up a translation, refreshing its last usage time on hit.
+    fn get(&self, translator: &str, text_hash: &str) -> Result<Option<String>, rusqlite::Error> {
+        let connection = self
+            .connection
+            .lock()
+            .expect("translation store lock poisoned");
+        let translated = connection
+            .query_row(
+                "SELECT translated FROM translations WHERE translator = ?1 AND text_hash = ?2",
+                params![translator, text_hash],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()?;
+        if translated.is_some() {
+            connection.execute(
+                "UPDATE translations SET last_used_at = ?3 WHERE translator = ?1 AND text_hash = ?2",
+                params![translator, text_hash, now_millis()],
+            )?;
+        }
+        Ok(translated)
+    }
+
+    /// Stores a translation, then evicts the least recently used entries over the limit.
+    fn put(
+        &self,
+        translator: &str,
+        text_hash: &str,
+        translated: &str,
+    ) -> Result<(), rusqlite::Error> {
+        let connection = self
+            .connection
+            .lock()
+            .expect("translation store lock poisoned");
+        let now = now_millis();
+        connection.execute(
+            "INSERT OR REPLACE INTO translations (translator, text_hash, translated, created_at, last_used_at)
+             VALUES (?1, ?2, ?3, ?4, ?4)",
+            params![translator, text_hash, translated, now],
+        )?;
+        let evicted = connection.execute(
+            "DELETE FROM translations WHERE rowid IN (
+                SELECT rowid FROM translations ORDER BY last_used_at ASC, rowid ASC
+                LIMIT MAX(0, (SELECT COUNT(*) FROM translations) - ?1)
+            )",
+            params![self.max_entries as i64],
+        )?;
+        if evicted > 0 {
+            debug!(evicted, "Evicted least recently used translations");
+        }
+        let entries: i64 =
+            connection.query_row("SELECT COUNT(*) FROM translations", [], |row| row.get(0))?;
+        metrics::TRANSLATION_CACHE_ENTRIES.set(entries);
+        Ok(())
+    }
+}
+
+/// Caching decorator for any [`Translator`] implementation.
+///
+/// Only successful translations are stored: errors (e.g. `RateLimited`) are passed
+/// through untouched so the caller can apply its own fallback.
+pub struct CachingTranslator {
+    inner: Box<dyn Translator>,
+    store: Arc<TranslationStore>,
+}
+
+impl CachingTranslator {
+    /// Creates a new caching translator.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - Translator used on cache misses
+    /// * `store` - Persistent translation store
+    pub fn new(inner: Box<dyn Translator>, store: Arc<TranslationStore>) -> Self {
+        Self { inner, store }
+    }
+
+    /// Runs a blocking store operation off the async runtime, logging failures.
+    async fn with_store<T, F>(&self, operation: &'static str, f: F) -> Option<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&TranslationStore) -> Result<T, rusqlite::Error> + Send + 'static,
+    {
+        let store = self.store.clone();
+        match tokio::task::spawn_blocking(move || f(&store)).await {
+            Ok(Ok(value)) => Some(value),
+            Ok(Err(e)) => {
+                warn!(operation, error = %e, "Translation cache operation failed");
+                None
+            }
+            Err(e) => {
+                warn!(operation, error = %e, "Translation cache task failed");
+                None
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Translator for CachingTranslator {
+    async fn translate(
+        &self,
+        text: &str,
+        translator_type: TranslatorType,
+    ) -> Result<TranslationResponse, HttpClientError> {
+        let translator = translator_type.to_string();
+        let text_hash = hash_text(text);
+
+        let cached = {
+            let (translator, text_hash) = (translator.clone(), text_hash.clone());
+            self.with_store("get", move |store| store.get(&translator, &text_hash))
+                .await
+                .flatten()
+        };
+        if let Some(translated) = cached {
+            metrics::TRANSLATION_CACHE_HITS
+                .with_label_values(&[&translator])
+                .inc();
+            debug!(translator, "Translation cache hit");
+            return Ok(TranslationResponse {
+                contents: TranslationContents { translated },
+                engine: TranslationEngine::FunTranslations,
+                cached: true,
+            });
+        }
+
+        metrics::TRANSLATION_CACHE_MISSES
+            .with_label_values(&[&translator])
+            .inc();
+        debug!(translator, "Translation cache miss");
+        let response = self.inner.translate(text, translator_type).await?;
+        let translated = response.contents.translated.clone();
+        self.with_store("put", move |store| {
+            store.put(&translator, &text_hash, &translated)
+        })
+        .await;
+        Ok(response)
+    }
+
+    async fn probe(&self) -> Result<(), HttpClientError> {
+        self.inner.probe().await
+    }
+}
+
+/// Hashes the source text: a stable digest keeps the key size bounded and independent
+/// of the Rust version (unlike `std::hash`).
+fn hash_text(text: &str) -> String {
+    format!("{:x}", Sha256::digest(text.as_bytes()))
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::client::ErrorKind;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Upper-cases the text, counting calls; fails with `RateLimited` when asked to.
+    #[derive(Default)]
+    struct CountingTranslator {
+        calls: Arc<AtomicUsize>,
+        rate_limited: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl Translator for CountingTranslator {
+        async fn translate(
+            &self,
+            text: &str,
+            _translator_type: TranslatorType,
+        ) -> Result<TranslationResponse, HttpClientError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.rate_limited {
+                return Err(ErrorKind::RateLimited.into());
+            }
+            Ok(TranslationResponse {
+                contents: TranslationContents {
+                    translated: text.to_uppercase(),
+                },
+                engine: TranslationEngine::FunTranslations,
+                cached: false,
+            })
+        }
+    }
+
+    fn make_translator(store: Arc<TranslationStore>) -> (CachingTranslator, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let inner = CountingTranslator {
+            calls: calls.clone(),
+            ..Default::default()
+        };
+        (CachingTranslator::new(Box::new(inner), store), calls)
+    }
+
+    fn memory_store(max_entries: usize) -> Arc<TranslationStore> {
+        Arc::new(TranslationStore::open(":memory:", max_entries).unwrap())
+    }
+
+    #[tokio::test]
+    async fn serves_repeated_translations_from_store() {
+        let (translator, calls) = make_translator(memory_store(10));
+
+        for i in 0..3 {
+            let response = translator
+                .translate("hello", TranslatorType::YODA)
+                .await
+                .unwrap();
+            assert_eq!(response.contents.translated, "HELLO");
+            assert_eq!(response.cached, i > 0);
+        }
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn keys_translations_by_translator_type() {
+        let (translator, calls) = make_translator(memory_store(10));
+
+        assert!(
+            translator
+                .translate("hello", TranslatorType::YODA)
+                .await
+                .is_ok()
+        );
+        assert!(
+            translator
+                .translate("hello", TranslatorType::SHAKESPEARE)
+                .await
+                .is_ok()
+        );
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_store_failed_translations() {
+        let store = memory_store(10);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let inner = CountingTranslator {
+            calls: calls.clone(),
+            rate_limited: true,
+        };
+        let translator = CachingTranslator::new(Box::new(inner), store.clone());
+
+        let result = translator.translate("hello", TranslatorType::YODA).await;
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::RateLimited);
+        let result = translator.translate("hello", TranslatorType::YODA).await;
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::RateLimited);
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(store.len().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn evicts_least_recently_used_entries() {
+        let store = memory_store(2);
+        let (translator, calls) = make_translator(store.clone());
+
+        for text in ["one", "two"] {
+            assert!(
+                translator
+                    .translate(text, TranslatorType::YODA)
+                    .await
+                    .is_ok()
+            );
+        }
+        // "one" becomes the most recently used entry
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        assert!(
+            translator
+                .translate("one", TranslatorType::YODA)
+                .await
+                .is_ok()
+        );
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        assert!(
+            translator
+                .translate("three", TranslatorType::YODA)
+                .await
+                .is_ok()
+        );
+        assert_eq!(store.len().unwrap(), 2);
+
+        // "one" is still cached, "two" was evicted
+        assert!(
+            translator
+                .translate("one", TranslatorType::YODA)
+                .await
+                .is_ok()
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert!(
+            translator
+                .translate("two", TranslatorType::YODA)
+                .await
+                .is_ok()
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn survives_reopening_the_store() {
+        let path = std::env::temp_dir().join(format!(
+            "pokedex-translation-cache-{}.db",
+            std::process::id()
+        ));
+        {
+            let (translator, _) =
+                make_translator(Arc::new(TranslationStore::open(&path, 10).unwrap()));
+            assert!(
+                translator
+                    .translate("hello", TranslatorType::YODA)
+                    .await
+                    .is_ok()
+            );
+        }
+
+        let (translator, calls) =
+            make_translator(Arc::new(TranslationStore::open(&path, 10).unwrap()));
+        let response = translator
+            .translate("hello", TranslatorType::YODA)
+            .await
+            .unwrap();
+
+        assert_eq!(response.contents.translated, "HELLO");
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{}", path.display(), suffix));
+        }
+    }
+
+    #[tokio::test]
+    async fn flush_empties_the_write_ahead_log() {
+        let path = std::env::temp_dir().join(format!(
+            "pokedex-translation-cache-flush-{}.db",
+            std::process::id()
+        ));
+        let store = Arc::new(TranslationStore::open(&path, 10).unwrap());
+        let (translator, _) = make_translator(store.clone());
+        assert!(
+            translator
+                .translate("hello", TranslatorType::YODA)
+                .await
+                .is_ok()
+        );
+
+        store.flush().unwrap();
+
+        let wal = std::fs::metadata(format!("{}-wal", path.display())).unwrap();
+        assert_eq!(wal.len(), 0);
+        drop((translator, store));
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{}", path.display(), suffix));
+        }
+    }
+}
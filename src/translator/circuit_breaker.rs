@@ -0,0 +1,52 @@
+//! # Fun Translations Circuit Breaker
+//!
+//! [`Translator`] decorator failing fast with `ErrorKind::ServiceUnavailable`
+//! while the Fun Translations circuit is open.
+//!
+//! Rate limiting (429) is not a failure: the quota being exhausted says nothing about
+//! the upstream health, and the caller already falls back on it.
+
+use crate::{
+    http::{
+        circuit_breaker::CircuitBreaker,
+        client::{HttpClientError, TranslatorType},
+    },
+    translator::client::{TranslationResponse, Translator},
+};
+use std::sync::Arc;
+
+/// Circuit breaking decorator for any [`Translator`] implementation.
+pub struct CircuitBreakingTranslator {
+    inner: Box<dyn Translator>,
+    breaker: Arc<CircuitBreaker>,
+}
+
+impl CircuitBreakingTranslator {
+    /// Creates a new circuit breaking translator.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - Translator guarded by the breaker
+    /// * `breaker` - Circuit breaker shared with the readiness endpoint
+    pub fn new(inner: Box<dyn Translator>, breaker: Arc<CircuitBreaker>) -> Self {
+        Self { inner, breaker }
+    }
+}
+
+#[async_trait::async_trait]
+impl Translator for CircuitBreakingTranslator {
+    async fn translate(
+        &self,
+        text: &str,
+        translator_type: TranslatorType,
+    ) -> Result<TranslationResponse, HttpClientError> {
+        self.breaker
+            .call(|| self.inner.translate(text, translator_type))
+            .await
+    }
+
+    /// Probes bypass the breaker, so that readiness reflects the actual upstream health.
+    async fn probe(&self) -> Result<(), HttpClientError> {
+        self.inner.probe().await
+    }
+}
@@ -1,25 +1,65 @@
 //! # Fun Translations API Client
 //!
 //! This module provides integration with the [Fun Translations API](https://funtranslations.com/api/)
-//! for translating Pokémon descriptions into various fun styles:
+//! for translating Pokémon descriptions into various fun styles, each served by its own
+//! endpoint (e.g., `/translate/yoda.json`):
 //! - **Shakespeare**: Elizabethan English translation
 //! - **Yoda**: Star Wars Yoda speak translation
+//! - Any other style of the catalogue (pirate, minion, klingon, ...), as configured in the
+//!   [`TranslatorRegistry`](crate::translator::registry::TranslatorRegistry)
 //!
 //! ## Rate Limiting
 //!
 //! The Fun Translations API has rate limits. The client handles rate limiting errors
-//! gracefully by returning `HttpClientError::RateLimited`.
-
-use crate::http::client::{HttpClientError, TranslatorType};
+//! gracefully by returning `ErrorKind::RateLimited`.
+
+use crate::{
+    constants::FUN_TRANSLATIONS_UPSTREAM,
+    http::{
+        client::{ErrorKind, HttpClientError, TranslatorType},
+        retry::RetryPolicy,
+    },
+};
+use axum::http::HeaderName;
 use reqwest::StatusCode;
 use serde::Deserialize;
 
+/// Header naming the engine that produced a translated description.
+pub const TRANSLATION_ENGINE_HEADER: HeaderName = HeaderName::from_static("x-translation-engine");
+/// [`TRANSLATION_ENGINE_HEADER`] value of descriptions returned untranslated.
+pub const UNTRANSLATED_ENGINE: &str = "none";
+
+/// Engine producing translations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TranslationEngine {
+    /// The Fun Translations API
+    #[default]
+    FunTranslations,
+    /// The built-in translators (see [`crate::translator::local`])
+    Local,
+}
+
+impl TranslationEngine {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::FunTranslations => FUN_TRANSLATIONS_UPSTREAM,
+            Self::Local => "local",
+        }
+    }
+}
+
 /// Response from Fun Translations API.
 ///
 /// Contains metadata and the translated text.
 #[derive(Debug, Deserialize)]
 pub struct TranslationResponse {
     pub contents: TranslationContents,
+    /// Engine that produced the translation
+    #[serde(skip)]
+    pub engine: TranslationEngine,
+    /// Whether the translation was served from the translation cache
+    #[serde(skip)]
+    pub cached: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -36,7 +76,7 @@ pub trait Translator: Send + Sync {
     /// # Arguments
     ///
     /// * `text` - Text to translate
-    /// * `translator_type` - Style to use (Shakespeare or Yoda)
+    /// * `translator_type` - Style to use (e.g., yoda)
     ///
     /// # Returns
     ///
@@ -45,14 +85,23 @@ pub trait Translator: Send + Sync {
     /// # Errors
     ///
     /// - `NotFound` if the translator type endpoint doesn't exist (404)
-    /// - `RateLimited` if API rate limit is exceeded (429)
-    /// - `RequestFailed` on network errors
-    /// - `ParseError` on JSON parsing or server errors
+    /// - `RateLimited` if the API rate limit is still exceeded (429) once retries are spent
+    /// - `ServiceUnavailable` if the API is unavailable (503), or while its circuit is open
+    /// - `ServerError` on internal server errors (500)
+    /// - `RequestFailed`, `ParseError` or `Timeout` on API communication issues
     async fn translate(
         &self,
         text: &str,
         translator_type: TranslatorType,
     ) -> Result<TranslationResponse, HttpClientError>;
+
+    /// Checks that the translation upstream is reachable without spending any quota.
+    ///
+    /// Decorators forward the probe to the translator they wrap; implementations not
+    /// talking to an upstream are always reachable.
+    async fn probe(&self) -> Result<(), HttpClientError> {
+        Ok(())
+    }
 }
 /// HTTP client for the Fun Translations API.
 ///
@@ -60,6 +109,7 @@ pub trait Translator: Send + Sync {
 pub struct FunTranslator {
     client: reqwest::Client,
     base_url: String,
+    retry_policy: RetryPolicy,
 }
 
 impl FunTranslator {
@@ -70,7 +120,17 @@ impl FunTranslator {
     /// * `client` - Configured reqwest client
     /// * `base_url` - Base URL for Fun Translations API (e.g., `https://api.funtranslations.com/translate`)
     pub fn new(client: reqwest::Client, base_url: String) -> Self {
-        FunTranslator { client, base_url }
+        FunTranslator {
+            client,
+            base_url,
+            retry_policy: RetryPolicy::none(),
+        }
+    }
+
+    /// Sets the retry policy applied to every translation request (no retries by default).
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
     }
 }
 
@@ -90,23 +150,40 @@ impl Translator for FunTranslator {
         text: &str,
         translator_type: TranslatorType,
     ) -> Result<TranslationResponse, HttpClientError> {
-        self.client
-            .post(format!("{}/{}.json", self.base_url, translator_type,))
-            .form(&[("text", text)])
+        let url = format!("{}/{}.json", self.base_url, translator_type);
+        let response = self
+            .retry_policy
+            .send(FUN_TRANSLATIONS_UPSTREAM, || {
+                self.client.post(&url).form(&[("text", text)])
+            })
+            .await?;
+        let kind = match response.status() {
+            StatusCode::NOT_FOUND => ErrorKind::NotFound,
+            StatusCode::SERVICE_UNAVAILABLE => ErrorKind::ServiceUnavailable,
+            StatusCode::TOO_MANY_REQUESTS => ErrorKind::RateLimited,
+            StatusCode::INTERNAL_SERVER_ERROR => ErrorKind::ServerError,
+            // NOTE: by default redirects followed automatically by reqwest::Client: https://docs.rs/reqwest/latest/reqwest/#redirect-policies
+            _ => return HttpClientError::read_json(FUN_TRANSLATIONS_UPSTREAM, response).await,
+        };
+        Err(HttpClientError::from_response(FUN_TRANSLATIONS_UPSTREAM, kind, response).await)
+    }
+
+    /// Probes the API with a plain `GET` of the translation base URL: translating
+    /// anything would count against the hourly quota. Any answer but a server error
+    /// proves the API reachable.
+    async fn probe(&self) -> Result<(), HttpClientError> {
+        let response = self
+            .client
+            .get(&self.base_url)
             .send()
             .await
-            .map_err(|_| HttpClientError::RequestFailed)
-            .and_then(|r| match r.status() {
-                StatusCode::NOT_FOUND => Err(HttpClientError::NotFound),
-                StatusCode::SERVICE_UNAVAILABLE => Err(HttpClientError::ServiceUnavailable),
-                StatusCode::TOO_MANY_REQUESTS => Err(HttpClientError::RateLimited),
-                StatusCode::INTERNAL_SERVER_ERROR => Err(HttpClientError::ServerError),
-                // NOTE: by default redirects followed automatically by reqwest::Client: https://docs.rs/reqwest/latest/reqwest/#redirect-policies
-                _ => Ok(r),
-            })?
-            .json::<TranslationResponse>()
-            .await
-            .map_err(|_| HttpClientError::ParseError)
+            .map_err(|e| HttpClientError::from_transport(FUN_TRANSLATIONS_UPSTREAM, e))?;
+        let kind = match response.status() {
+            StatusCode::SERVICE_UNAVAILABLE => ErrorKind::ServiceUnavailable,
+            s if s.is_server_error() => ErrorKind::ServerError,
+            _ => return Ok(()),
+        };
+        Err(HttpClientError::from_response(FUN_TRANSLATIONS_UPSTREAM, kind, response).await)
     }
 }
 
@@ -131,7 +208,7 @@ mod tests {
         let translator = FunTranslator::new(reqwest::Client::new(), server.url());
 
         let result = translator
-            .translate("Hello", TranslatorType::Shakespeare)
+            .translate("Hello", TranslatorType::SHAKESPEARE)
             .await;
 
         assert!(result.is_ok());
@@ -155,7 +232,7 @@ mod tests {
 
         let translator = FunTranslator::new(reqwest::Client::new(), server.url());
 
-        let result = translator.translate("Hello", TranslatorType::Yoda).await;
+        let result = translator.translate("Hello", TranslatorType::YODA).await;
 
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -177,10 +254,10 @@ mod tests {
         let translator = FunTranslator::new(reqwest::Client::new(), server.url());
 
         let result = translator
-            .translate("Unknown", TranslatorType::Shakespeare)
+            .translate("Unknown", TranslatorType::SHAKESPEARE)
             .await;
 
-        assert!(matches!(result, Err(HttpClientError::NotFound)));
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::NotFound);
         mock.assert_async().await;
     }
 
@@ -198,10 +275,10 @@ mod tests {
         let translator = FunTranslator::new(reqwest::Client::new(), server.url());
 
         let result = translator
-            .translate("Unknown", TranslatorType::Shakespeare)
+            .translate("Unknown", TranslatorType::SHAKESPEARE)
             .await;
 
-        assert!(matches!(result, Err(HttpClientError::RateLimited)));
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::RateLimited);
         mock.assert_async().await;
     }
 
@@ -219,10 +296,10 @@ mod tests {
         let translator = FunTranslator::new(reqwest::Client::new(), server.url());
 
         let result = translator
-            .translate("Hello", TranslatorType::Shakespeare)
+            .translate("Hello", TranslatorType::SHAKESPEARE)
             .await;
 
-        assert!(matches!(result, Err(HttpClientError::ServiceUnavailable)));
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::ServiceUnavailable);
         mock.assert_async().await;
     }
 
@@ -242,10 +319,10 @@ mod tests {
         let translator = FunTranslator::new(reqwest::Client::new(), server.url());
 
         let result = translator
-            .translate("Hello", TranslatorType::Shakespeare)
+            .translate("Hello", TranslatorType::SHAKESPEARE)
             .await;
 
-        assert!(matches!(result, Err(HttpClientError::ParseError)));
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::ParseError);
         mock.assert_async().await;
     }
 
@@ -264,14 +341,79 @@ mod tests {
         let translator = FunTranslator::new(reqwest::Client::new(), server.url());
 
         let result = translator
-            .translate("Hello", TranslatorType::Shakespeare)
+            .translate("Hello", TranslatorType::SHAKESPEARE)
             .await;
 
         assert!(result.is_err());
-        assert!(result.is_err_and(|e| e == HttpClientError::ServerError));
+        assert!(result.is_err_and(|e| e.kind() == &ErrorKind::ServerError));
         mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn retries_service_unavailable_with_retry_policy() {
+        let mut server = mockito::Server::new_async().await;
+        let unavailable = server
+            .mock("POST", "/yoda.json")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("POST", "/yoda.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":{"total":1},"contents":{"translation":"yoda","text":"Hello","translated":"Hello, you say must"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let translator = FunTranslator::new(reqwest::Client::new(), server.url())
+            .with_retry_policy(RetryPolicy {
+                max_attempts: 2,
+                base_delay: std::time::Duration::from_millis(1),
+                max_delay: std::time::Duration::from_millis(1),
+                jitter: false,
+                retryable: vec![ErrorKind::ServiceUnavailable],
+            });
+
+        let result = translator.translate("Hello", TranslatorType::YODA).await;
+
+        assert!(result.is_ok());
+        unavailable.assert_async().await;
+        ok.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn probe_treats_any_non_server_error_as_reachable() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/")
+            .with_status(404)
+            .expect(1)
+            .create_async()
+            .await;
+        let translator = FunTranslator::new(reqwest::Client::new(), server.url());
+
+        assert!(translator.probe().await.is_ok());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn probe_fails_on_server_errors() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/")
+            .with_status(503)
+            .create_async()
+            .await;
+        let translator = FunTranslator::new(reqwest::Client::new(), server.url());
+
+        assert_eq!(
+            translator.probe().await.unwrap_err().kind(),
+            &ErrorKind::ServiceUnavailable
+        );
+    }
+
     #[tokio::test]
     #[ignore] // Run with: cargo test -- --ignored test_translate_with_real_api
     async fn test_translate_with_real_api_shakespeare() {
@@ -281,7 +423,7 @@ mod tests {
         );
 
         let result = translator
-            .translate("Hello, how are you?", TranslatorType::Shakespeare)
+            .translate("Hello, how are you?", TranslatorType::SHAKESPEARE)
             .await;
 
         // This test requires internet connectivity to the real API
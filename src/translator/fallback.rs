@@ -0,0 +1,139 @@
+//! # Translation Fallback
+//!
+//! [`Translator`] chaining a remote translator (the Fun Translations API) with a local one
+//! (see [`crate::translator::local`]): the remote translator is tried first and, when it
+//! fails (rate limit, outage, timeout, ...), the text is translated locally instead.
+//!
+//! Styles without local implementation keep failing with the remote error. The engine that
+//! produced a translation is reported in [`TranslationResponse::engine`].
+
+use crate::{
+    http::client::{ErrorKind, HttpClientError, TranslatorType},
+    metrics,
+    translator::client::{TranslationResponse, Translator},
+};
+use tracing::warn;
+
+/// Translator falling back on a local translator when the remote one fails.
+pub struct FallbackTranslator {
+    remote: Box<dyn Translator>,
+    local: Box<dyn Translator>,
+}
+
+impl FallbackTranslator {
+    /// Creates a new fallback translator.
+    ///
+    /// # Arguments
+    ///
+    /// * `remote` - Translator tried first
+    /// * `local` - Translator used when the remote one fails
+    pub fn new(remote: Box<dyn Translator>, local: Box<dyn Translator>) -> Self {
+        Self { remote, local }
+    }
+}
+
+#[async_trait::async_trait]
+impl Translator for FallbackTranslator {
+    async fn translate(
+        &self,
+        text: &str,
+        translator_type: TranslatorType,
+    ) -> Result<TranslationResponse, HttpClientError> {
+        let error = match self.remote.translate(text, translator_type.clone()).await {
+            Ok(response) => return Ok(response),
+            Err(e) => e,
+        };
+        let Ok(response) = self.local.translate(text, translator_type.clone()).await else {
+            return Err(error);
+        };
+        if error.kind() == &ErrorKind::RateLimited {
+            metrics::RATE_LIMITED_ERRORS.inc();
+        }
+        metrics::LOCAL_TRANSLATIONS
+            .with_label_values(&[translator_type.as_str()])
+            .inc();
+        warn!(translator = %translator_type, error = %error, "Translated locally after remote failure");
+        Ok(response)
+    }
+
+    /// Probes the remote translator: the local one is always available.
+    async fn probe(&self) -> Result<(), HttpClientError> {
+        self.remote.probe().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::translator::{
+        client::{TranslationContents, TranslationEngine},
+        local::LocalTranslator,
+    };
+
+    struct RemoteTranslator {
+        error: Option<ErrorKind>,
+    }
+
+    #[async_trait::async_trait]
+    impl Translator for RemoteTranslator {
+        async fn translate(
+            &self,
+            text: &str,
+            _translator_type: TranslatorType,
+        ) -> Result<TranslationResponse, HttpClientError> {
+            match &self.error {
+                Some(kind) => Err(kind.clone().into()),
+                None => Ok(TranslationResponse {
+                    contents: TranslationContents {
+                        translated: text.to_uppercase(),
+                    },
+                    engine: TranslationEngine::FunTranslations,
+                    cached: false,
+                }),
+            }
+        }
+    }
+
+    fn translator(error: Option<ErrorKind>) -> FallbackTranslator {
+        FallbackTranslator::new(
+            Box::new(RemoteTranslator { error }),
+            Box::new(LocalTranslator::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn prefers_the_remote_translation() {
+        let response = translator(None)
+            .translate("It is a mouse.", TranslatorType::YODA)
+            .await
+            .unwrap();
+
+        assert_eq!(response.contents.translated, "IT IS A MOUSE.");
+        assert_eq!(response.engine, TranslationEngine::FunTranslations);
+    }
+
+    #[tokio::test]
+    async fn translates_locally_when_the_remote_translator_fails() {
+        for kind in [ErrorKind::RateLimited, ErrorKind::ServiceUnavailable] {
+            let response = translator(Some(kind))
+                .translate("It is a mouse.", TranslatorType::YODA)
+                .await
+                .unwrap();
+
+            assert_eq!(response.contents.translated, "A mouse, it is.");
+            assert_eq!(response.engine, TranslationEngine::Local);
+        }
+    }
+
+    #[tokio::test]
+    async fn keeps_the_remote_error_of_styles_without_local_translator() {
+        let result = translator(Some(ErrorKind::RateLimited))
+            .translate(
+                "It is a mouse.",
+                TranslatorType::try_from("klingon".to_string()).unwrap(),
+            )
+            .await;
+
+        assert!(result.is_err_and(|e| e.kind() == &ErrorKind::RateLimited));
+    }
+}
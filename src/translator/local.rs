@@ -0,0 +1,257 @@
+//! # Local Translators
+//!
+//! Built-in, deterministic [`Translator`] implementations, used as a fallback while the
+//! Fun Translations API is unavailable (see [`crate::translator::fallback`]):
+//! - **Yoda**: clause reordering. A sentence with an auxiliary verb is turned around it
+//!   ("It is a mouse." becomes "A mouse, it is."), otherwise a trailing prepositional
+//!   clause is moved first ("It stores electricity in its cheeks." becomes "In its cheeks,
+//!   it stores electricity.")
+//! - **Shakespeare**: word substitution from an embedded lexicon (`shakespeare_lexicon.txt`),
+//!   preserving the case of the replaced words
+//!
+//! Other styles are not supported and fail with `ErrorKind::NotFound`, as unknown styles of
+//! the Fun Translations API.
+
+use crate::{
+    http::client::{ErrorKind, HttpClientError, TranslatorType},
+    translator::client::{TranslationContents, TranslationEngine, TranslationResponse, Translator},
+};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+/// Auxiliary verbs Yoda sentences are turned around.
+const AUXILIARIES: [&str; 18] = [
+    "am", "is", "are", "was", "were", "be", "been", "has", "have", "had", "can", "could", "will",
+    "would", "shall", "should", "may", "must",
+];
+/// Words opening the clauses Yoda moves first.
+const CLAUSE_OPENERS: [&str; 16] = [
+    "in", "on", "at", "with", "when", "while", "from", "into", "by", "if", "because", "after",
+    "before", "during", "through", "under",
+];
+
+/// Shakespearean replacements, by lowercase word.
+static SHAKESPEARE_LEXICON: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
+    include_str!("shakespeare_lexicon.txt")
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once(' '))
+        .map(|(word, replacement)| (word, replacement.trim()))
+        .collect()
+});
+
+/// Translator producing Yoda and Shakespeare translations without any remote call.
+#[derive(Debug, Default)]
+pub struct LocalTranslator;
+
+impl LocalTranslator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl Translator for LocalTranslator {
+    async fn translate(
+        &self,
+        text: &str,
+        translator_type: TranslatorType,
+    ) -> Result<TranslationResponse, HttpClientError> {
+        let translated = match translator_type {
+            t if t == TranslatorType::YODA => yoda(text),
+            t if t == TranslatorType::SHAKESPEARE => shakespeare(text),
+            _ => return Err(ErrorKind::NotFound.into()),
+        };
+        Ok(TranslationResponse {
+            contents: TranslationContents { translated },
+            engine: TranslationEngine::Local,
+            cached: false,
+        })
+    }
+}
+
+/// Translates `text` into Yoda speak, sentence by sentence.
+fn yoda(text: &str) -> String {
+    sentences(text)
+        .iter()
+        .map(|sentence| yoda_sentence(sentence))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Splits `text` into sentences of words, each ending with `.`, `!` or `?` if any.
+fn sentences(text: &str) -> Vec<Vec<&str>> {
+    let mut sentences = vec![];
+    let mut sentence = vec![];
+    for word in text.split_whitespace() {
+        sentence.push(word);
+        if word.ends_with(['.', '!', '?']) {
+            sentences.push(std::mem::take(&mut sentence));
+        }
+    }
+    if !sentence.is_empty() {
+        sentences.push(sentence);
+    }
+    sentences
+}
+
+/// Reorders the clauses of a sentence, leaving it as is when no clause can be moved.
+fn yoda_sentence(words: &[&str]) -> String {
+    let last = words.len() - 1;
+    let terminator = words[last]
+        .find(['.', '!', '?'])
+        .map_or("", |i| &words[last][i..]);
+    let mut words: Vec<&str> = words.to_vec();
+    words[last] = &words[last][..words[last].len() - terminator.len()];
+
+    let is = |word: &str, list: &[&str]| list.iter().any(|w| w.eq_ignore_ascii_case(word));
+    let (head, tail) = match (1..last).find(|&i| is(words[i], &AUXILIARIES)) {
+        // "it is a mouse" -> "a mouse, it is"
+        Some(i) => (&words[..=i], &words[i + 1..]),
+        None => match (2..=last).find(|&i| is(words[i], &CLAUSE_OPENERS)) {
+            // "it stores electricity in its cheeks" -> "in its cheeks, it stores electricity"
+            Some(i) => (&words[..i], &words[i..]),
+            None => return format!("{}{}", words.join(" "), terminator),
+        },
+    };
+    let head: Vec<String> = head
+        .iter()
+        .enumerate()
+        .map(|(i, word)| match i {
+            0 => decapitalize(word),
+            _ => word.to_string(),
+        })
+        .collect();
+    let tail = tail.join(" ");
+    format!(
+        "{}, {}{}",
+        capitalize(tail.trim_end_matches(',')),
+        head.join(" ").trim_end_matches(','),
+        terminator
+    )
+}
+
+/// Translates `text` into Shakespearean English, word by word.
+fn shakespeare(text: &str) -> String {
+    let mut translated = String::with_capacity(text.len());
+    let mut word = String::new();
+    for c in text.chars().chain(std::iter::once(' ')) {
+        if c.is_alphabetic() || (c == '\'' && !word.is_empty()) {
+            word.push(c);
+            continue;
+        }
+        if !word.is_empty() {
+            translated.push_str(&shakespeare_word(&word));
+            word.clear();
+        }
+        translated.push(c);
+    }
+    translated.pop();
+    translated
+}
+
+/// Replaces a word from the lexicon, with the case of the original word.
+fn shakespeare_word(word: &str) -> String {
+    match SHAKESPEARE_LEXICON.get(word.to_lowercase().as_str()) {
+        Some(replacement) if word.chars().count() > 1 && !word.chars().any(char::is_lowercase) => {
+            replacement.to_uppercase()
+        }
+        Some(replacement) if word.starts_with(char::is_uppercase) => capitalize(replacement),
+        Some(replacement) => replacement.to_string(),
+        None => word.to_string(),
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Lowercases the first letter of a capitalized word, keeping `I` and acronyms (e.g., POKéMON).
+fn decapitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first)
+            if word != "I" && !word.starts_with("I'") && !chars.clone().any(char::is_uppercase) =>
+        {
+            first.to_lowercase().chain(chars).collect()
+        }
+        _ => word.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn translate(text: &str, translator_type: TranslatorType) -> String {
+        LocalTranslator::new()
+            .translate(text, translator_type)
+            .await
+            .unwrap()
+            .contents
+            .translated
+    }
+
+    #[tokio::test]
+    async fn yoda_turns_sentences_around_their_auxiliary() {
+        assert_eq!(
+            translate("It is a mouse. You must   be strong!", TranslatorType::YODA).await,
+            "A mouse, it is. Be strong, you must!"
+        );
+    }
+
+    #[tokio::test]
+    async fn yoda_moves_trailing_clauses_first() {
+        assert_eq!(
+            translate(
+                "POKéMON store electricity in their cheeks.",
+                TranslatorType::YODA
+            )
+            .await,
+            "In their cheeks, POKéMON store electricity."
+        );
+        assert_eq!(
+            translate("Hello there", TranslatorType::YODA).await,
+            "Hello there"
+        );
+    }
+
+    #[tokio::test]
+    async fn shakespeare_substitutes_words_keeping_their_case() {
+        assert_eq!(
+            translate(
+                "You are my friend, YES? Never fight thy enemies.",
+                TranslatorType::SHAKESPEARE
+            )
+            .await,
+            "Thou art my fellow, AYE? Ne'er fight thy foes."
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_styles_without_local_implementation() {
+        let result = LocalTranslator::new()
+            .translate(
+                "Hello",
+                TranslatorType::try_from("klingon".to_string()).unwrap(),
+            )
+            .await;
+
+        assert!(result.is_err_and(|e| e.kind() == &ErrorKind::NotFound));
+    }
+
+    #[test]
+    fn lexicon_entries_are_lowercase_pairs() {
+        assert!(SHAKESPEARE_LEXICON.len() > 50);
+        assert!(
+            SHAKESPEARE_LEXICON
+                .keys()
+                .all(|word| *word == word.to_lowercase())
+        );
+    }
+}
@@ -1 +1,8 @@
+pub mod cache;
+pub mod circuit_breaker;
 pub mod client;
+pub mod fallback;
+pub mod local;
+pub mod registry;
+pub mod rules;
+pub mod status;
@@ -0,0 +1,128 @@
+//! # Translator Registry
+//!
+//! Translation styles the API serves (`TRANSLATOR_STYLES`), out of the Fun Translations
+//! catalogue: the styles callers may request with `?style=`, that translator rules may
+//! select, and that `GET /translators` lists.
+
+use crate::http::client::TranslatorType;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Known translation styles, in configuration order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranslatorRegistry {
+    styles: Vec<TranslatorType>,
+}
+
+impl TranslatorRegistry {
+    /// Creates a new registry of `styles`, ignoring duplicates.
+    pub fn new(styles: Vec<TranslatorType>) -> Self {
+        let mut known = Vec::with_capacity(styles.len());
+        for style in styles {
+            if !known.contains(&style) {
+                known.push(style);
+            }
+        }
+        Self { styles: known }
+    }
+
+    /// Known styles, in configuration order.
+    pub fn styles(&self) -> &[TranslatorType] {
+        &self.styles
+    }
+
+    pub fn contains(&self, style: &TranslatorType) -> bool {
+        self.styles.contains(style)
+    }
+
+    /// Resolves a style requested by a client (case-insensitive).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message, listing the known styles, if the style is invalid or unknown.
+    pub fn resolve(&self, style: &str) -> Result<TranslatorType, String> {
+        TranslatorType::try_from(style.to_string())
+            .ok()
+            .filter(|style| self.contains(style))
+            .ok_or_else(|| {
+                format!(
+                    "unknown translator style '{}' (expected any of {})",
+                    style,
+                    self.styles
+                        .iter()
+                        .map(TranslatorType::as_str)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })
+    }
+
+    /// Lists the known styles, as returned by `GET /translators`.
+    pub fn list(&self) -> TranslatorList {
+        TranslatorList {
+            translators: self
+                .styles
+                .iter()
+                .map(|style| TranslatorInfo {
+                    name: style.to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Translation styles served by the API.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct TranslatorList {
+    pub translators: Vec<TranslatorInfo>,
+}
+
+/// Translation style served by the API.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct TranslatorInfo {
+    /// Style name, as accepted by `?style=` (e.g., pirate)
+    pub name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> TranslatorRegistry {
+        TranslatorRegistry::new(vec![
+            TranslatorType::YODA,
+            TranslatorType::try_from("pig-latin".to_string()).unwrap(),
+            TranslatorType::YODA,
+        ])
+    }
+
+    #[test]
+    fn resolves_known_styles_case_insensitively() {
+        assert_eq!(registry().resolve("Yoda"), Ok(TranslatorType::YODA));
+        assert_eq!(
+            registry().resolve("pig-latin").unwrap().as_str(),
+            "pig-latin"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_or_invalid_styles() {
+        let error = registry().resolve("klingon").unwrap_err();
+        assert!(error.contains("klingon"));
+        assert!(error.contains("yoda, pig-latin"));
+        assert!(registry().resolve("../yoda").is_err());
+        assert!(registry().resolve("").is_err());
+    }
+
+    #[test]
+    fn lists_styles_once_in_configuration_order() {
+        let names: Vec<_> = registry()
+            .list()
+            .translators
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+
+        assert_eq!(names, vec!["yoda", "pig-latin"]);
+    }
+}
@@ -0,0 +1,559 @@
+//! # Translator Selection Rules
+//!
+//! Selection of the translator of a Pokémon description by an ordered list of rules: the
+//! first rule whose conditions all match the Pokémon wins, otherwise the default
+//! translator is used. A rule may match on:
+//! - `name`, `habitat`, `type`, `generation`: lists of accepted values (case-insensitive)
+//! - `legendary`, `mythical`: booleans
+//!
+//! The rules are loaded at startup from a JSON file (`TRANSLATOR_RULES_FILE`):
+//!
+//! ```json
+//! {
+//!   "default": "shakespeare",
+//!   "rules": [
+//!     { "name": "ditto-override", "when": { "name": ["ditto"] }, "translator": "yoda" },
+//!     { "name": "mythical", "when": { "mythical": true }, "translator": "pirate" },
+//!     { "name": "sea", "when": { "habitat": ["sea"] }, "translator": "pirate" }
+//!   ]
+//! }
+//! ```
+//!
+//! Rules may only select the styles of the [`TranslatorRegistry`]. Without rules file,
+//! legendary Pokémon and cave dwellers get Yoda, all others Shakespeare.
+//! The name of the matching rule (or `default`) is logged and returned in the
+//! `X-Translator-Rule` response header.
+
+use crate::{
+    http::client::TranslatorType,
+    pokemon_api::{client::Pokemon, evolution::EvolutionStage},
+    translator::registry::TranslatorRegistry,
+};
+use axum::http::{HeaderName, HeaderValue};
+use serde::Deserialize;
+use std::{collections::HashSet, path::Path};
+
+/// Header carrying the name of the rule that selected the translator.
+pub const TRANSLATOR_RULE_HEADER: HeaderName = HeaderName::from_static("x-translator-rule");
+/// Rule name reported when no rule matches.
+pub const DEFAULT_RULE: &str = "default";
+
+/// PokéAPI habitats (`/pokemon-habitat`).
+const HABITATS: [&str; 9] = [
+    "cave",
+    "forest",
+    "grassland",
+    "mountain",
+    "rare",
+    "rough-terrain",
+    "sea",
+    "urban",
+    "waters-edge",
+];
+/// PokéAPI types (`/type`).
+const TYPES: [&str; 19] = [
+    "normal", "fighting", "flying", "poison", "ground", "rock", "bug", "ghost", "steel", "fire",
+    "water", "grass", "electric", "psychic", "ice", "dragon", "dark", "fairy", "stellar",
+];
+
+/// Characteristics of a Pokémon the rules match on.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RuleSubject<'a> {
+    pub name: &'a str,
+    pub habitat: Option<&'a str>,
+    pub is_legendary: bool,
+    pub is_mythical: bool,
+    pub generation: Option<&'a str>,
+    pub types: Vec<&'a str>,
+}
+
+impl<'a> From<&'a Pokemon> for RuleSubject<'a> {
+    fn from(pokemon: &'a Pokemon) -> Self {
+        Self {
+            name: &pokemon.name,
+            habitat: pokemon.habitat.as_deref(),
+            is_legendary: pokemon.is_legendary,
+            is_mythical: pokemon.is_mythical,
+            generation: pokemon.generation.as_deref(),
+            types: pokemon.types.iter().map(|t| t.name.as_str()).collect(),
+        }
+    }
+}
+
+/// Evolution stages have no types: rules matching on types never match them.
+impl<'a> From<&'a EvolutionStage> for RuleSubject<'a> {
+    fn from(stage: &'a EvolutionStage) -> Self {
+        Self {
+            name: &stage.name,
+            habitat: stage.habitat.as_deref(),
+            is_legendary: stage.is_legendary,
+            is_mythical: stage.is_mythical,
+            generation: stage.generation.as_deref(),
+            types: Vec::new(),
+        }
+    }
+}
+
+/// Conditions of a rule, all of which must match (a missing condition matches anything).
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleConditions {
+    /// Pokémon names
+    pub name: Option<Vec<String>>,
+    /// Habitats (e.g., cave)
+    pub habitat: Option<Vec<String>>,
+    /// Types, any of which the Pokémon has (e.g., water)
+    #[serde(rename = "type")]
+    pub types: Option<Vec<String>>,
+    /// Generations (e.g., generation-i)
+    pub generation: Option<Vec<String>>,
+    pub legendary: Option<bool>,
+    pub mythical: Option<bool>,
+}
+
+impl RuleConditions {
+    fn matches(&self, subject: &RuleSubject) -> bool {
+        any_of(&self.name, &[subject.name])
+            && any_of(&self.habitat, subject.habitat.as_slice())
+            && any_of(&self.types, &subject.types)
+            && any_of(&self.generation, subject.generation.as_slice())
+            && self.legendary.is_none_or(|l| l == subject.is_legendary)
+            && self.mythical.is_none_or(|m| m == subject.is_mythical)
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Checks the condition values, returning an error message if one is invalid.
+    fn validate(&self) -> Result<(), String> {
+        let lists = [
+            ("name", &self.name),
+            ("habitat", &self.habitat),
+            ("type", &self.types),
+            ("generation", &self.generation),
+        ];
+        for (condition, values) in lists {
+            let Some(values) = values else { continue };
+            if values.is_empty() {
+                return Err(format!("empty '{}' condition", condition));
+            }
+            for value in values {
+                let known = match condition {
+                    "habitat" => HABITATS.iter().any(|h| h.eq_ignore_ascii_case(value)),
+                    "type" => TYPES.iter().any(|t| t.eq_ignore_ascii_case(value)),
+                    "generation" => is_generation(value),
+                    _ => !value.trim().is_empty(),
+                };
+                if !known {
+                    return Err(format!("invalid {} '{}'", condition, value));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether `values`, if any, contain one of `candidates`.
+fn any_of(values: &Option<Vec<String>>, candidates: &[&str]) -> bool {
+    values.as_ref().is_none_or(|values| {
+        values
+            .iter()
+            .any(|value| candidates.iter().any(|c| value.eq_ignore_ascii_case(c)))
+    })
+}
+
+/// Whether `value` names a generation (`generation-` followed by a roman numeral).
+fn is_generation(value: &str) -> bool {
+    value
+        .to_ascii_lowercase()
+        .strip_prefix("generation-")
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| matches!(c, 'i' | 'v' | 'x')))
+}
+
+/// Rule selecting a translator for the Pokémon matching its conditions.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TranslatorRule {
+    /// Rule name, logged and returned in the `X-Translator-Rule` header
+    pub name: String,
+    /// Conditions of the rule (none: matches every Pokémon)
+    #[serde(default)]
+    pub when: RuleConditions,
+    pub translator: TranslatorType,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RulesFile {
+    default: TranslatorType,
+    rules: Vec<TranslatorRule>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RulesError {
+    #[error("failed to read translator rules: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("invalid translator rules: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    #[error("invalid translator rules: {0}")]
+    Invalid(String),
+}
+
+/// Translator selected for a Pokémon.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TranslatorSelection<'a> {
+    pub translator: &'a TranslatorType,
+    /// Name of the matching rule, or `default`
+    pub rule: &'a str,
+}
+
+/// Ordered translator selection rules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranslatorRules {
+    rules: Vec<TranslatorRule>,
+    default: TranslatorType,
+}
+
+impl TranslatorRules {
+    /// Creates a new rule set.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - Rules, in evaluation order
+    /// * `default` - Translator of the Pokémon matching no rule
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a rule name is empty, reserved, duplicate or not a valid header
+    /// value (names are returned in `X-Translator-Rule`), if a condition
+    /// value is invalid, or if a rule without conditions shadows the following rules.
+    pub fn new(rules: Vec<TranslatorRule>, default: TranslatorType) -> Result<Self, RulesError> {
+        let mut names = HashSet::new();
+        for (index, rule) in rules.iter().enumerate() {
+            if rule.name.trim().is_empty() {
+                return Err(RulesError::Invalid(format!(
+                    "rule #{} has no name",
+                    index + 1
+                )));
+            }
+            if HeaderValue::from_str(&rule.name).is_err() {
+                return Err(RulesError::Invalid(format!(
+                    "rule name {:?} is not a valid header value",
+                    rule.name
+                )));
+            }
+            if rule.name == DEFAULT_RULE {
+                return Err(RulesError::Invalid(format!(
+                    "rule name '{}' is reserved",
+                    DEFAULT_RULE
+                )));
+            }
+            if !names.insert(rule.name.as_str()) {
+                return Err(RulesError::Invalid(format!(
+                    "duplicate rule name '{}'",
+                    rule.name
+                )));
+            }
+            rule.when
+                .validate()
+                .map_err(|e| RulesError::Invalid(format!("rule '{}': {}", rule.name, e)))?;
+            if rule.when.is_empty() && index + 1 < rules.len() {
+                return Err(RulesError::Invalid(format!(
+                    "rule '{}' has no conditions and shadows the following rules",
+                    rule.name
+                )));
+            }
+        }
+        Ok(Self { rules, default })
+    }
+
+    /// Creates a new rule set from a JSON rules file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or does not hold valid rules.
+    pub fn load(path: &Path) -> Result<Self, RulesError> {
+        let file: RulesFile = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        Self::new(file.rules, file.default)
+    }
+
+    /// Checks that every translator the rules select is served by `registry`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first rule selecting an unknown style.
+    pub fn check_styles(&self, registry: &TranslatorRegistry) -> Result<(), RulesError> {
+        let translators = self
+            .rules
+            .iter()
+            .map(|rule| (rule.name.as_str(), &rule.translator))
+            .chain([(DEFAULT_RULE, &self.default)]);
+        for (rule, translator) in translators {
+            if !registry.contains(translator) {
+                return Err(RulesError::Invalid(format!(
+                    "rule '{}' selects unknown translator style '{}'",
+                    rule, translator
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Number of rules, the default excluded.
+    pub fn rule_count(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Selects the translator of `subject`: the one of the first matching rule, or the default.
+    pub fn select(&self, subject: &RuleSubject) -> TranslatorSelection<'_> {
+        self.rules
+            .iter()
+            .find(|rule| rule.when.matches(subject))
+            .map_or(
+                TranslatorSelection {
+                    translator: &self.default,
+                    rule: DEFAULT_RULE,
+                },
+                |rule| TranslatorSelection {
+                    translator: &rule.translator,
+                    rule: &rule.name,
+                },
+            )
+    }
+}
+
+/// Legendary Pokémon and cave dwellers get Yoda, all others Shakespeare.
+impl Default for TranslatorRules {
+    fn default() -> Self {
+        let yoda = |name: &str, when| TranslatorRule {
+            name: name.to_string(),
+            when,
+            translator: TranslatorType::YODA,
+        };
+        Self {
+            rules: vec![
+                yoda(
+                    "cave",
+                    RuleConditions {
+                        habitat: Some(vec!["cave".to_string()]),
+                        ..Default::default()
+                    },
+                ),
+                yoda(
+                    "legendary",
+                    RuleConditions {
+                        legendary: Some(true),
+                        ..Default::default()
+                    },
+                ),
+            ],
+            default: TranslatorType::SHAKESPEARE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subject<'a>(name: &'a str, habitat: Option<&'a str>, is_legendary: bool) -> RuleSubject<'a> {
+        RuleSubject {
+            name,
+            habitat,
+            is_legendary,
+            ..Default::default()
+        }
+    }
+
+    fn pirate() -> TranslatorType {
+        TranslatorType::try_from("pirate".to_string()).unwrap()
+    }
+
+    fn parse(rules: serde_json::Value) -> Result<TranslatorRules, RulesError> {
+        let file: RulesFile = serde_json::from_value(rules)?;
+        TranslatorRules::new(file.rules, file.default)
+    }
+
+    #[test]
+    fn default_rules_pick_yoda_for_cave_or_legendary_pokemon() {
+        let rules = TranslatorRules::default();
+        let select = |subject| {
+            let selection = rules.select(&subject);
+            (selection.translator.clone(), selection.rule.to_string())
+        };
+
+        assert_eq!(
+            select(subject("zubat", Some("cave"), false)),
+            (TranslatorType::YODA, "cave".to_string())
+        );
+        assert_eq!(
+            select(subject("articuno", Some("rare"), true)),
+            (TranslatorType::YODA, "legendary".to_string())
+        );
+        assert_eq!(
+            select(subject("mewtwo", Some("cave"), true)),
+            (TranslatorType::YODA, "cave".to_string())
+        );
+        assert_eq!(
+            select(subject("pikachu", Some("forest"), false)),
+            (TranslatorType::SHAKESPEARE, DEFAULT_RULE.to_string())
+        );
+        assert_eq!(
+            select(subject("ditto", None, false)),
+            (TranslatorType::SHAKESPEARE, DEFAULT_RULE.to_string())
+        );
+    }
+
+    #[test]
+    fn first_rule_matching_every_condition_wins() {
+        let rules = parse(serde_json::json!({
+            "default": "shakespeare",
+            "rules": [
+                { "name": "mew-override", "when": { "name": ["Mew"] }, "translator": "yoda" },
+                { "name": "mythical", "when": { "mythical": true }, "translator": "pirate" },
+                {
+                    "name": "old-water",
+                    "when": { "type": ["water", "ice"], "generation": ["generation-i"] },
+                    "translator": "pirate"
+                }
+            ]
+        }))
+        .unwrap();
+        let mew = RuleSubject {
+            name: "mew",
+            is_mythical: true,
+            ..Default::default()
+        };
+        let celebi = RuleSubject {
+            name: "celebi",
+            is_mythical: true,
+            ..Default::default()
+        };
+        let lapras = RuleSubject {
+            name: "lapras",
+            types: vec!["water", "ice"],
+            generation: Some("generation-i"),
+            ..Default::default()
+        };
+        let wailmer = RuleSubject {
+            name: "wailmer",
+            types: vec!["water"],
+            generation: Some("generation-iii"),
+            ..Default::default()
+        };
+
+        assert_eq!(rules.select(&mew).rule, "mew-override");
+        assert_eq!(rules.select(&celebi).translator, &pirate());
+        assert_eq!(rules.select(&lapras).rule, "old-water");
+        assert_eq!(
+            rules.select(&wailmer),
+            TranslatorSelection {
+                translator: &TranslatorType::SHAKESPEARE,
+                rule: DEFAULT_RULE
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_rules() {
+        let invalid = |rules: serde_json::Value| {
+            parse(serde_json::json!({ "default": "yoda", "rules": rules })).unwrap_err()
+        };
+
+        assert!(matches!(
+            invalid(serde_json::json!([
+                { "name": "sea", "when": { "habitat": ["sea"] }, "translator": "pirate" },
+                { "name": "sea", "when": { "habitat": ["cave"] }, "translator": "yoda" }
+            ])),
+            RulesError::Invalid(_)
+        ));
+        assert!(matches!(
+            invalid(serde_json::json!([
+                { "name": "ocean", "when": { "habitat": ["ocean"] }, "translator": "pirate" }
+            ])),
+            RulesError::Invalid(_)
+        ));
+        assert!(matches!(
+            invalid(serde_json::json!([
+                { "name": "new", "when": { "generation": ["gen-9"] }, "translator": "pirate" }
+            ])),
+            RulesError::Invalid(_)
+        ));
+        assert!(matches!(
+            invalid(serde_json::json!([
+                { "name": "all", "translator": "pirate" },
+                { "name": "cave", "when": { "habitat": ["cave"] }, "translator": "yoda" }
+            ])),
+            RulesError::Invalid(_)
+        ));
+        assert!(matches!(
+            invalid(serde_json::json!([
+                { "name": "default", "when": { "legendary": true }, "translator": "yoda" }
+            ])),
+            RulesError::Invalid(_)
+        ));
+        assert!(matches!(
+            invalid(serde_json::json!([
+                { "name": "sea\nside", "when": { "habitat": ["sea"] }, "translator": "pirate" }
+            ])),
+            RulesError::Invalid(_)
+        ));
+        assert!(matches!(
+            invalid(serde_json::json!([
+                { "name": "klingon", "when": { "legendary": true }, "translator": "Klingon!" }
+            ])),
+            RulesError::Parse(_)
+        ));
+        assert!(matches!(
+            invalid(serde_json::json!([
+                { "name": "color", "when": { "color": ["red"] }, "translator": "yoda" }
+            ])),
+            RulesError::Parse(_)
+        ));
+    }
+
+    #[test]
+    fn checks_the_selected_styles_against_the_registry() {
+        let rules = parse(serde_json::json!({
+            "default": "shakespeare",
+            "rules": [
+                { "name": "mythical", "when": { "mythical": true }, "translator": "pirate" }
+            ]
+        }))
+        .unwrap();
+
+        assert!(
+            rules
+                .check_styles(&TranslatorRegistry::new(vec![
+                    TranslatorType::SHAKESPEARE,
+                    pirate()
+                ]))
+                .is_ok()
+        );
+        let error = rules
+            .check_styles(&TranslatorRegistry::new(vec![pirate()]))
+            .unwrap_err();
+        assert!(error.to_string().contains("'default'"));
+    }
+
+    #[test]
+    fn matches_evolution_stages_without_types() {
+        let rules = parse(serde_json::json!({
+            "default": "shakespeare",
+            "rules": [
+                { "name": "fire", "when": { "type": ["fire"] }, "translator": "pirate" },
+                { "name": "catch-all", "translator": "yoda" }
+            ]
+        }))
+        .unwrap();
+        let stage = EvolutionStage {
+            name: "charmander".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(rules.select(&RuleSubject::from(&stage)).rule, "catch-all");
+    }
+}
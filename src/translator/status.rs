@@ -0,0 +1,142 @@
+//! # Translation Status
+//!
+//! Outcome of the translation of a Pokémon description, so that callers can tell translated
+//! text from a fallback:
+//! - `translated`: translated by the Fun Translations API
+//! - `cached`: translated by the Fun Translations API earlier, served from the translation cache
+//! - `fallback-local`: translated by the local translators (see [`crate::translator::local`])
+//! - `fallback-original`: not translated, the original description is returned
+//!
+//! The status is returned in the `X-Translation-Status` header and, when the client accepts
+//! JSON rather than plain text, in a [`TranslatedDescription`] body.
+
+use crate::translator::client::{TranslationEngine, TranslationResponse};
+use axum::http::HeaderName;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Header carrying the [`TranslationStatus`] of a translated description.
+pub const TRANSLATION_STATUS_HEADER: HeaderName = HeaderName::from_static("x-translation-status");
+
+/// Outcome of the translation of a description.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum TranslationStatus {
+    /// Translated by the Fun Translations API
+    Translated,
+    /// Served from the translation cache
+    Cached,
+    /// Translated by the local translators after a Fun Translations failure
+    FallbackLocal,
+    /// Not translated: the original description is returned
+    FallbackOriginal,
+}
+
+impl TranslationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Translated => "translated",
+            Self::Cached => "cached",
+            Self::FallbackLocal => "fallback-local",
+            Self::FallbackOriginal => "fallback-original",
+        }
+    }
+}
+
+impl From<&TranslationResponse> for TranslationStatus {
+    fn from(response: &TranslationResponse) -> Self {
+        match (response.engine, response.cached) {
+            (TranslationEngine::Local, _) => Self::FallbackLocal,
+            (_, true) => Self::Cached,
+            (_, false) => Self::Translated,
+        }
+    }
+}
+
+/// Translated Pokemon description, as returned to clients accepting JSON.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TranslatedDescription {
+    /// Translation outcome
+    pub status: TranslationStatus,
+    /// Translator style (e.g., yoda)
+    pub translator: String,
+    /// Engine that produced the text: `funtranslations`, `local`, or `none` when untranslated
+    pub engine: String,
+    /// Language of the description
+    pub language: String,
+    /// Original description
+    pub source: String,
+    /// Translated description, or the original one when untranslated
+    pub translated: String,
+}
+
+/// Whether an `Accept` header prefers `application/json` over `text/plain`.
+///
+/// Media ranges are compared by quality, then by order; `*/*` prefers neither, so plain
+/// text remains the default.
+pub fn prefers_json(accept: Option<&str>) -> bool {
+    let mut json = None;
+    let mut text = None;
+    for (index, range) in accept.unwrap_or_default().split(',').enumerate() {
+        let mut params = range.split(';').map(str::trim);
+        let media_type = params.next().unwrap_or_default().to_ascii_lowercase();
+        let quality = params
+            .filter_map(|param| param.strip_prefix("q="))
+            .find_map(|q| q.parse::<f32>().ok())
+            .unwrap_or(1.0);
+        let preference = Some((quality, usize::MAX - index));
+        match media_type.as_str() {
+            "application/json" | "application/*" if preference > json => json = preference,
+            "text/plain" | "text/*" if preference > text => text = preference,
+            _ => {}
+        }
+    }
+    json.is_some_and(|(quality, _)| quality > 0.0) && json > text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::translator::client::TranslationContents;
+
+    fn response(engine: TranslationEngine, cached: bool) -> TranslationResponse {
+        TranslationResponse {
+            contents: TranslationContents {
+                translated: "Hello".to_string(),
+            },
+            engine,
+            cached,
+        }
+    }
+
+    #[test]
+    fn derives_the_status_of_translations() {
+        assert_eq!(
+            TranslationStatus::from(&response(TranslationEngine::FunTranslations, false)),
+            TranslationStatus::Translated
+        );
+        assert_eq!(
+            TranslationStatus::from(&response(TranslationEngine::FunTranslations, true)),
+            TranslationStatus::Cached
+        );
+        assert_eq!(
+            TranslationStatus::from(&response(TranslationEngine::Local, false)),
+            TranslationStatus::FallbackLocal
+        );
+        assert_eq!(
+            serde_json::to_value(TranslationStatus::FallbackOriginal).unwrap(),
+            TranslationStatus::FallbackOriginal.as_str()
+        );
+    }
+
+    #[test]
+    fn prefers_json_only_when_asked_for() {
+        assert!(prefers_json(Some("application/json")));
+        assert!(prefers_json(Some("text/plain;q=0.5, application/json")));
+        assert!(prefers_json(Some("application/json, text/plain")));
+        assert!(!prefers_json(Some("text/plain, application/json")));
+        assert!(!prefers_json(Some("application/json;q=0")));
+        assert!(!prefers_json(Some("*/*")));
+        assert!(!prefers_json(None));
+    }
+}